@@ -5,10 +5,11 @@
 
 use bevy::asset::AssetPlugin;
 use bevy::prelude::*;
-use shared::colors::LIGHT_BACKGROUND;
+use shared::colors::YELLOW_ACCENT;
 use shared::input::InputPlugin;
-use shared::level::LevelPlugin;
+use shared::level::{LevelPlugin, LevelsResource};
 use shared::rendering::RenderingPlugin;
+use shared::rendering::background::{RenderingConfig, SceneBackground};
 use tracing::info;
 
 #[cfg(target_arch = "wasm32")]
@@ -42,26 +43,47 @@ fn main() {
     {
         info!("Embedding assets for WASM build");
         embedded_asset!(app, "assets/uv_checker.png");
-        embedded_asset!(app, "assets/levels/default.toml");
-        embedded_asset!(app, "assets/levels/test_small.toml");
-        embedded_asset!(app, "assets/levels/test_large.toml");
     }
 
-    app.insert_resource(ClearColor(LIGHT_BACKGROUND))
-        .add_plugins(InputPlugin)
-        .add_plugins(RenderingPlugin)
-        .add_plugins(LevelPlugin)
-        .add_systems(Update, placeholder_system)
+    app.add_plugins(InputPlugin)
+        .add_plugins(RenderingPlugin::new(RenderingConfig {
+            background: scene_background(),
+        }))
+        .add_plugins(LevelPlugin::from_launch_options())
+        .add_systems(Update, (placeholder_system, spawn_point_marker_system))
         .run();
 
     info!("SystemTactics game application shutting down");
 }
 
+/// Backdrop behind levels that do not set their own background color
+fn scene_background() -> SceneBackground {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use shared::colors::{BACKGROUND_COLOR, GRAY_SECONDARY};
+
+        // Blend into the website around the embedded canvas
+        SceneBackground::Gradient {
+            top: GRAY_SECONDARY,
+            bottom: BACKGROUND_COLOR,
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        SceneBackground::Sky
+    }
+}
+
 fn configure_asset_plugin() -> AssetPlugin {
     #[cfg(target_arch = "wasm32")]
     {
-        info!("Configuring AssetPlugin for WASM (embedded assets)");
-        AssetPlugin::default()
+        info!("Configuring AssetPlugin for WASM (embedded and hosted assets)");
+        // Hosted level files have no .meta files; skip the extra 404 requests
+        AssetPlugin {
+            meta_check: bevy::asset::AssetMetaCheck::Never,
+            ..default()
+        }
     }
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -79,3 +101,55 @@ fn placeholder_system() {
     // This runs every frame - using trace level to avoid spam
     // TODO: Implement actual game systems
 }
+
+/// Marker entity placed on a level spawn point until real units exist
+#[derive(Component)]
+struct SpawnPointMarker;
+
+/// Place placeholder markers on the current level's spawn points
+///
+/// Runs whenever the levels resource changes so markers follow level switches.
+fn spawn_point_marker_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    levels_resource: Res<LevelsResource>,
+    markers: Query<Entity, With<SpawnPointMarker>>,
+) {
+    if !levels_resource.is_changed() {
+        return;
+    }
+
+    for entity in &markers {
+        commands.entity(entity).despawn();
+    }
+
+    let level = levels_resource.current_level();
+    if level.spawn_points.is_empty() {
+        return;
+    }
+
+    let hex_layout = level.hex_layout();
+    let marker_mesh = meshes.add(Cylinder::new(0.3, 0.6));
+    let marker_material = materials.add(StandardMaterial {
+        base_color: YELLOW_ACCENT,
+        ..default()
+    });
+
+    for spawn in &level.spawn_points {
+        let hex = spawn.coord();
+        let world_pos = hex_layout.hex_to_world_pos(hex);
+        commands.spawn((
+            Mesh3d(marker_mesh.clone()),
+            MeshMaterial3d(marker_material.clone()),
+            Transform::from_xyz(world_pos.x, level.get_height(hex) + 0.3, world_pos.y),
+            SpawnPointMarker,
+        ));
+    }
+
+    info!(
+        "Placed {count} spawn point markers for level '{level_name}'",
+        count = level.spawn_points.len(),
+        level_name = level.name
+    );
+}
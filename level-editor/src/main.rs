@@ -5,11 +5,23 @@
 
 use bevy::asset::AssetPlugin;
 use bevy::prelude::*;
-use shared::input::InputPlugin;
+use shared::input::{InputPlugin, regenerate_level_input_system};
 use shared::level::LevelPlugin;
+use shared::level::generation::HeightGenerator;
+use shared::level::management::{DefaultLevelConfig, level_switching_system};
+use shared::level::mesh::HexMeshOptions;
+use shared::level::render_config::void_toggle_system;
+use shared::level::sorting::LevelSortMode;
 use shared::rendering::RenderingPlugin;
+use shared::rendering::background::{RenderingConfig, SceneBackground};
 use tracing::info;
 
+mod reload_prompt;
+mod save_status;
+
+use reload_prompt::{PendingReloads, reload_prompt_system, spawn_reload_prompt};
+use save_status::{save_status_system, spawn_save_status};
+
 fn main() {
     info!("Starting SystemTactics Level Editor application");
 
@@ -29,9 +41,44 @@ fn main() {
                 }),
         )
         .add_plugins(InputPlugin)
-        .add_plugins(RenderingPlugin)
-        .add_plugins(LevelPlugin)
-        .add_systems(Update, placeholder_editor_system)
+        // Designers preview each level's own background color
+        .add_plugins(RenderingPlugin::new(RenderingConfig {
+            background: SceneBackground::LevelColor,
+        }))
+        // Designers think in files, so list levels by path rather than manifest order
+        .add_plugins(
+            LevelPlugin::from_launch_options()
+                .sort_mode(LevelSortMode::FileName)
+                // Start from a blank canvas when there are no levels to edit yet
+                .default_level(DefaultLevelConfig {
+                    name: "Untitled Level".to_string(),
+                    width: 20,
+                    height: 20,
+                    generator: HeightGenerator::Flat(1.0),
+                })
+                // Catch files edited in other programs before a save overwrites them
+                .watch_external_changes()
+                .save_shortcut(),
+        )
+        // Closed columns, so what the editor shows matches exported geometry
+        .insert_resource(HexMeshOptions {
+            bottom_face: true,
+            ..default()
+        })
+        .init_resource::<PendingReloads>()
+        .add_systems(Startup, (spawn_reload_prompt, spawn_save_status))
+        .add_systems(
+            Update,
+            (
+                placeholder_editor_system,
+                reload_prompt_system,
+                save_status_system,
+                // Lets designers try out noise terrain on the current level
+                regenerate_level_input_system,
+                // Lets designers check the raw heights under void hexes
+                void_toggle_system.before(level_switching_system),
+            ),
+        )
         .run();
 
     info!("SystemTactics Level Editor application shutting down");
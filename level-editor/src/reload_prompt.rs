@@ -0,0 +1,95 @@
+//! Reload Prompt
+//!
+//! Asks whether to reload a level whose file was edited outside the editor.
+//! Press R to reload it from disk or K to keep the editor's version.
+
+use bevy::prelude::*;
+use shared::colors::HEX_IMPASSABLE_RED;
+use shared::level::external::LevelFileChangedOnDisk;
+use shared::level::{LevelDirectoryOverride, LevelsResource, levels_directory};
+use tracing::{info, warn};
+
+/// Level file changes waiting for a reload/keep answer, oldest first
+#[derive(Resource, Debug, Default)]
+pub struct PendingReloads(Vec<LevelFileChangedOnDisk>);
+
+/// Component to mark the reload prompt text
+#[derive(Component)]
+pub struct ReloadPromptText;
+
+/// System to spawn the (initially empty) reload prompt in the top-center of the screen
+pub fn spawn_reload_prompt(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 22.0,
+            ..default()
+        },
+        TextColor(HEX_IMPASSABLE_RED),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(60.0),
+            left: Val::Percent(30.0),
+            ..default()
+        },
+        ReloadPromptText,
+    ));
+}
+
+/// System queueing external file changes and answering the oldest on R or K
+pub fn reload_prompt_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut changes: EventReader<LevelFileChangedOnDisk>,
+    mut pending: ResMut<PendingReloads>,
+    mut levels_resource: ResMut<LevelsResource>,
+    directory_override: Option<Res<LevelDirectoryOverride>>,
+    mut text_query: Query<&mut Text, With<ReloadPromptText>>,
+) {
+    for change in changes.read() {
+        pending
+            .0
+            .retain(|queued| queued.source_path != change.source_path);
+        pending.0.push(change.clone());
+    }
+
+    if let Some(change) = pending.0.first() {
+        if keyboard_input.just_pressed(KeyCode::KeyR) {
+            let levels_dir = levels_directory(directory_override.as_deref());
+            match levels_resource
+                .reload_level_file(&levels_dir.to_string_lossy(), &change.source_path)
+            {
+                Ok(index) => info!(
+                    "Reloaded level '{level_name}' from {path}",
+                    level_name = levels_resource.levels[index].name,
+                    path = change.source_path.display()
+                ),
+                Err(err) => warn!("Failed to reload level: {err:#}"),
+            }
+            pending.0.remove(0);
+        } else if keyboard_input.just_pressed(KeyCode::KeyK) {
+            info!(
+                "Keeping the editor's version of {path}",
+                path = change.source_path.display()
+            );
+            pending.0.remove(0);
+        }
+    }
+
+    if !pending.is_changed() {
+        return;
+    }
+    let prompt = pending.0.first().map_or_else(String::new, |change| {
+        let unsaved = if change.has_unsaved_edits {
+            " Reloading discards your unsaved edits."
+        } else {
+            ""
+        };
+        format!(
+            "{path} changed on disk.{unsaved} R: reload, K: keep",
+            path = change.source_path.display()
+        )
+    });
+    for mut text in text_query.iter_mut() {
+        **text = prompt.clone();
+    }
+}
@@ -0,0 +1,81 @@
+//! Save Status
+//!
+//! Shows the outcome of the last Ctrl+S save for a few seconds.
+
+use bevy::prelude::*;
+use shared::colors::{GREEN_PRIMARY, HEX_IMPASSABLE_RED};
+use shared::level::saving::{LevelSaveFailed, LevelSaved};
+
+/// Seconds a save message stays on screen
+const SAVE_STATUS_SECONDS: f32 = 4.0;
+
+/// Component to mark the save status text
+#[derive(Component)]
+pub struct SaveStatusText;
+
+/// System to spawn the (initially empty) save status text in the bottom-left corner
+pub fn spawn_save_status(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(GREEN_PRIMARY),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            left: Val::Px(20.0),
+            ..default()
+        },
+        SaveStatusText,
+    ));
+}
+
+/// System to show save results and hide them after [`SAVE_STATUS_SECONDS`]
+pub fn save_status_system(
+    time: Res<Time>,
+    mut saved: EventReader<LevelSaved>,
+    mut failed: EventReader<LevelSaveFailed>,
+    mut hide_timer: Local<Option<Timer>>,
+    mut text_query: Query<(&mut Text, &mut TextColor), With<SaveStatusText>>,
+) {
+    let status = saved
+        .read()
+        .map(|event| {
+            let message = format!(
+                "Saved '{level_name}' to {path}",
+                level_name = event.level_name,
+                path = event.path.display()
+            );
+            (message, GREEN_PRIMARY)
+        })
+        .chain(failed.read().map(|event| {
+            let message = format!(
+                "Could not save '{level_name}': {message}",
+                level_name = event.level_name,
+                message = event.message
+            );
+            (message, HEX_IMPASSABLE_RED)
+        }))
+        .last();
+
+    if let Some((message, color)) = status {
+        for (mut text, mut text_color) in text_query.iter_mut() {
+            **text = message.clone();
+            text_color.0 = color;
+        }
+        *hide_timer = Some(Timer::from_seconds(SAVE_STATUS_SECONDS, TimerMode::Once));
+        return;
+    }
+
+    let Some(timer) = hide_timer.as_mut() else {
+        return;
+    };
+    if timer.tick(time.delta()).just_finished() {
+        for (mut text, _) in text_query.iter_mut() {
+            text.clear();
+        }
+        *hide_timer = None;
+    }
+}
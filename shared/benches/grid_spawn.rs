@@ -0,0 +1,100 @@
+//! Hex Grid Spawn Benchmark
+//!
+//! Compares spawning a 50x50 level as one entity and mesh per hex, as the grid
+//! used to be spawned, against the merged column meshes spawned today.
+//!
+//! Run with `cargo bench -p shared --bench grid_spawn`.
+
+use std::time::{Duration, Instant};
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use shared::level::mesh::{
+    HexEdgeStyle, HexGridEntity, HexMaterials, HexMeshOptions, TerrainColoring,
+    create_hex_column_mesh, spawn_hex_grid,
+};
+use shared::level::render_config::HexGridRenderConfig;
+use shared::level::{Level, LevelsResource};
+
+const GRID_SIZE: i32 = 50;
+const ITERATIONS: u32 = 20;
+
+fn bench_level() -> Level {
+    let mut level = Level::new("Bench".to_string(), GRID_SIZE, GRID_SIZE);
+    for hex in level.hex_iter().collect::<Vec<_>>() {
+        let height = 1.0 + ((hex.x * 7 + hex.y * 13).rem_euclid(5)) as f32 * 0.5;
+        level
+            .set_height(hex, height)
+            .expect("Hex is inside the level");
+    }
+    level
+}
+
+fn bench_world(level: &Level) -> World {
+    let mut world = World::new();
+    world.init_resource::<Assets<Mesh>>();
+    world.init_resource::<Assets<StandardMaterial>>();
+    world.init_resource::<HexMaterials>();
+    world.init_resource::<TerrainColoring>();
+    world.init_resource::<HexMeshOptions>();
+    world.init_resource::<HexGridRenderConfig>();
+    world.init_resource::<HexEdgeStyle>();
+    world.insert_resource(LevelsResource::new(vec![level.clone()]));
+    world
+}
+
+/// The former spawn path: one column mesh asset and entity per hex
+fn spawn_per_hex(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    levels_resource: Res<LevelsResource>,
+) {
+    let level = levels_resource.current_level();
+    let hex_layout = level.hex_layout();
+    let material = materials.add(StandardMaterial::default());
+    let column_base = level.column_base();
+    let options = HexMeshOptions::default();
+    for hex in level.hex_iter() {
+        let mesh =
+            create_hex_column_mesh(&hex_layout, level.get_height(hex) - column_base, &options);
+        let world_pos = hex_layout.hex_to_world_pos(hex);
+        commands.spawn((
+            Mesh3d(meshes.add(mesh)),
+            MeshMaterial3d(material.clone()),
+            Transform::from_xyz(world_pos.x, column_base, world_pos.y),
+            HexGridEntity,
+        ));
+    }
+}
+
+/// Average time to spawn the grid into a fresh world, and the entities spawned
+fn measure<M>(level: &Level, system: impl IntoSystem<(), (), M> + Copy) -> (Duration, usize) {
+    let mut total = Duration::ZERO;
+    let mut entities = 0;
+    for _ in 0..ITERATIONS {
+        let mut world = bench_world(level);
+        let start = Instant::now();
+        world.run_system_once(system).expect("Grid spawning runs");
+        total += start.elapsed();
+        entities = world
+            .query_filtered::<(), With<HexGridEntity>>()
+            .iter(&world)
+            .count();
+    }
+    (total / ITERATIONS, entities)
+}
+
+fn main() {
+    let level = bench_level();
+    let (per_hex, per_hex_entities) = measure(&level, spawn_per_hex);
+    let (merged, merged_entities) = measure(&level, spawn_hex_grid);
+
+    println!("{GRID_SIZE}x{GRID_SIZE} grid spawn, average of {ITERATIONS} runs:");
+    println!("  per hex: {per_hex:>10.2?} ({per_hex_entities} entities)");
+    println!("  merged:  {merged:>10.2?} ({merged_entities} entities)");
+    println!(
+        "  speedup: {speedup:.1}x",
+        speedup = per_hex.as_secs_f64() / merged.as_secs_f64()
+    );
+}
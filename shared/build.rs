@@ -0,0 +1,115 @@
+//! Generates the list of level files embedded into WASM builds
+//!
+//! Scans `assets/levels` and writes `embedded_levels.rs` to `OUT_DIR`, holding
+//! an `EMBEDDED_LEVELS` array of (relative path, contents) pairs and the
+//! optional `EMBEDDED_LEVEL_MANIFEST`. Paths are sorted so the generated code is
+//! identical across machines and builds.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Level directory relative to this crate
+const LEVELS_DIR: &str = "../assets/levels";
+
+/// Must match `LevelFormat` and the manifest and depth rules in `level.rs`
+const LEVEL_EXTENSIONS: [&str; 3] = ["toml", "ron", "json"];
+const LEVEL_MANIFEST_FILE: &str = "manifest.toml";
+const MANIFEST_FILES: [&str; 2] = [LEVEL_MANIFEST_FILE, "manifest.json"];
+const MAX_DEPTH: usize = 4;
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("Set by cargo"));
+    let levels_dir = manifest_dir.join(LEVELS_DIR);
+
+    let mut files = Vec::new();
+    if levels_dir.is_dir() {
+        collect(&levels_dir, Path::new(""), 0, &mut files);
+    }
+    files.sort();
+
+    let mut code = String::from(
+        "/// Level files compiled into WASM builds as (path relative to the levels\n\
+         /// directory, file contents), generated by `build.rs`\n",
+    );
+    writeln!(
+        code,
+        "pub(crate) const EMBEDDED_LEVELS: [(&str, &[u8]); {count}] = [",
+        count = files.len()
+    )
+    .unwrap();
+    for file in &files {
+        let include_path = format!("/{LEVELS_DIR}/{file}");
+        writeln!(
+            code,
+            "    ({file:?}, include_bytes!(concat!(env!(\"CARGO_MANIFEST_DIR\"), {include_path:?}))),"
+        )
+        .unwrap();
+    }
+    code.push_str("];\n");
+
+    let manifest_path = levels_dir.join(LEVEL_MANIFEST_FILE);
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+    code.push_str(
+        "\n/// The levels directory's manifest compiled into WASM builds, if it has one\n",
+    );
+    if manifest_path.is_file() {
+        let include_path = format!("/{LEVELS_DIR}/{LEVEL_MANIFEST_FILE}");
+        writeln!(
+            code,
+            "pub(crate) const EMBEDDED_LEVEL_MANIFEST: Option<&str> = \
+             Some(include_str!(concat!(env!(\"CARGO_MANIFEST_DIR\"), {include_path:?})));"
+        )
+        .unwrap();
+    } else {
+        code.push_str("pub(crate) const EMBEDDED_LEVEL_MANIFEST: Option<&str> = None;\n");
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("Set by cargo"));
+    fs::write(out_dir.join("embedded_levels.rs"), code)
+        .expect("Failed to write embedded level list");
+}
+
+/// Collect level files as `/`-separated paths relative to the levels directory
+fn collect(directory: &Path, relative_dir: &Path, depth: usize, files: &mut Vec<String>) {
+    // Re-run when files are added to or removed from any scanned directory
+    println!("cargo:rerun-if-changed={}", directory.display());
+
+    let Ok(entries) = fs::read_dir(directory) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        if file_name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        let relative_path = relative_dir.join(&file_name);
+
+        if path.is_dir() {
+            if depth < MAX_DEPTH {
+                collect(&path, &relative_path, depth + 1, files);
+            }
+        } else if !MANIFEST_FILES
+            .iter()
+            .any(|manifest| relative_path == Path::new(manifest))
+            && is_level_file(&file_name)
+        {
+            println!("cargo:rerun-if-changed={}", path.display());
+            let components: Vec<_> = relative_path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            files.push(components.join("/"));
+        }
+    }
+}
+
+fn is_level_file(file_name: &str) -> bool {
+    let lower = file_name.to_ascii_lowercase();
+    let name = lower.strip_suffix(".gz").unwrap_or(&lower);
+    LEVEL_EXTENSIONS
+        .iter()
+        .any(|extension| name.ends_with(&format!(".{extension}")))
+}
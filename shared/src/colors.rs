@@ -17,10 +17,32 @@ pub const BACKGROUND_COLOR: Color = Color::srgb(0.216, 0.255, 0.318); // rgb(55,
 /// Alternative light background for tactical grid view
 pub const LIGHT_BACKGROUND: Color = Color::WHITE;
 
+// Procedural sky behind the tactical scene
+/// Sky color at the top of the view
+pub const SKY_ZENITH: Color = Color::srgb(0.376, 0.647, 0.980); // blue-400: #60a5fa
+
+/// Pale band where the sky meets the ground
+pub const SKY_HORIZON: Color = Color::srgb(0.859, 0.918, 0.996); // blue-100: #dbeafe
+
+/// Hazy bottom of the view below the horizon
+pub const SKY_HAZE: Color = Color::srgb(0.796, 0.835, 0.882); // slate-300: #cbd5e1
+
 // Hex grid colors for tactical battlefield
 /// Main hex column surface color - neutral gray for terrain
 pub const HEX_SURFACE_GRAY: Color = Color::srgb(0.294, 0.333, 0.388); // Medium gray for hex surfaces
 
+/// Impassable hex surface color - dark red for pits, walls, and destroyed tiles
+pub const HEX_IMPASSABLE_RED: Color = Color::srgb(0.498, 0.114, 0.114); // red-900: #7f1d1d
+
+/// Submerged hex surface color - muted sand for lake and river beds
+pub const HEX_SUBMERGED_SAND: Color = Color::srgb(0.471, 0.443, 0.361);
+
+/// Water plane color - translucent blue for flooded trenches and lakes
+pub const WATER_BLUE: Color = Color::srgba(0.231, 0.510, 0.965, 0.45); // blue-500: #3b82f6
+
+/// Void tile color - translucent slate for hexes below the void threshold
+pub const HEX_VOID_SLATE: Color = Color::srgba(0.118, 0.161, 0.231, 0.5); // slate-800: #1e293b
+
 /// Hex edge highlighting color - tactical green
 pub const HEX_EDGE_GREEN: Color = Color::srgb(0.133, 0.698, 0.298); // green-500: #22c55e
 
@@ -65,3 +87,36 @@ pub const TERRAIN_LOW: Color = Color::srgb(0.4, 0.4, 0.4); // Dark gray
 
 /// Neutral terrain height gradient end (high)
 pub const TERRAIN_HIGH: Color = Color::srgb(0.7, 0.7, 0.7); // Light gray
+
+/// Terrain color for `height` on a gradient from [`TERRAIN_LOW`] at `min` to
+/// [`TERRAIN_HIGH`] at `max`
+///
+/// Heights outside the range are clamped; a level with no height variation
+/// (`max <= min`) is colored [`TERRAIN_LOW`].
+pub fn terrain_color(height: f32, min: f32, max: f32) -> Color {
+    if max <= min {
+        return TERRAIN_LOW;
+    }
+    let t = ((height - min) / (max - min)).clamp(0.0, 1.0);
+    Color::Srgba(TERRAIN_LOW.to_srgba().mix(&TERRAIN_HIGH.to_srgba(), t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terrain_color_endpoints_and_midpoint() {
+        assert_eq!(terrain_color(1.0, 1.0, 5.0), TERRAIN_LOW);
+        assert_eq!(terrain_color(5.0, 1.0, 5.0), TERRAIN_HIGH);
+
+        let mid = terrain_color(3.0, 1.0, 5.0).to_srgba();
+        assert!((mid.red - 0.55).abs() < 1e-6, "Midpoint {mid:?}");
+        assert!((mid.green - 0.55).abs() < 1e-6 && (mid.blue - 0.55).abs() < 1e-6);
+
+        // Out-of-range and flat levels clamp to the ends
+        assert_eq!(terrain_color(-3.0, 1.0, 5.0), TERRAIN_LOW);
+        assert_eq!(terrain_color(9.0, 1.0, 5.0), TERRAIN_HIGH);
+        assert_eq!(terrain_color(2.0, 2.0, 2.0), TERRAIN_LOW);
+    }
+}
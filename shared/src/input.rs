@@ -1,75 +1,64 @@
 //! Input Handling Systems
 //!
-//! Input handling for camera controls, level cycling, and debug commands
-//! for the tactical RPG.
+//! Input handling for camera controls and debug commands for the tactical RPG.
+//! Level cycling input lives with the level systems in `LevelPlugin`.
 
+use bevy::ecs::system::SystemParam;
 use bevy::input::ButtonState;
+use bevy::input::common_conditions::input_just_pressed;
 use bevy::input::mouse::{MouseButtonInput, MouseMotion, MouseWheel};
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
 use tracing::info;
 
 use crate::level::LevelsResource;
+use crate::level::chunks::HexChunk;
+use crate::level::generation::NoiseParams;
+use crate::level::render_config::HexGridRenderConfig;
 use crate::rendering::camera::{
-    CameraLimits, CameraRotationState, RotationMode, TacticalCamera, calculate_camera_focus_point,
+    CameraLimits, CameraRotationState, CameraZoomState, RotationMode, TacticalCamera,
+    calculate_camera_focus_point, ease_zoom_scale, orbit_camera_around_point, pull_within_radius,
+    reset_camera_framing, scroll_zoom_scale, soft_clamp_pan, zoom_to_cursor_shift,
 };
 use crate::rendering::debug_aids::DebugAidVisibility;
 
-/// System to handle left/right arrow key input for level cycling
-pub fn level_cycling_input_system(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut levels_resource: ResMut<LevelsResource>,
-) {
-    let level_count = levels_resource.level_count();
-
-    // Only process input if we have multiple levels
-    if level_count <= 1 {
-        return;
-    }
-
-    if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
-        // Cycle to previous level (with wraparound)
-        let new_index = if levels_resource.current_level_index == 0 {
-            level_count - 1
-        } else {
-            levels_resource.current_level_index - 1
-        };
-
-        let old_level_name = levels_resource.current_level().name.clone();
-        levels_resource.current_level_index = new_index;
-        let new_level_name = &levels_resource.current_level().name;
-
-        info!(
-            "Level cycling: Previous (←) - switched from '{old_name}' to '{new_name}' (index {new_index})",
-            old_name = old_level_name,
-            new_name = new_level_name
-        );
-    }
-
-    if keyboard_input.just_pressed(KeyCode::ArrowRight) {
-        // Cycle to next level (with wraparound)
-        let new_index = (levels_resource.current_level_index + 1) % level_count;
-
-        let old_level_name = levels_resource.current_level().name.clone();
-        levels_resource.current_level_index = new_index;
-        let new_level_name = &levels_resource.current_level().name;
-
-        info!(
-            "Level cycling: Next (→) - switched from '{old_name}' to '{new_name}' (index {new_index})",
-            old_name = old_level_name,
-            new_name = new_level_name
-        );
-    }
+/// Modifier keys that turn letter keys into shortcuts such as Ctrl+S
+pub const SHORTCUT_MODIFIERS: [KeyCode; 4] = [
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::SuperLeft,
+    KeyCode::SuperRight,
+];
+
+/// `movement` of the camera at `translation`, slowed near the edge of the
+/// movement radius around the optimal camera position
+fn limited_camera_movement(
+    camera_limits: &CameraLimits,
+    translation: Vec3,
+    movement: Vec3,
+) -> Vec3 {
+    let offset = (translation - camera_limits.optimal_camera_position).xz();
+    let allowed = soft_clamp_pan(offset, movement.xz(), camera_limits.current_movement_radius);
+    Vec3::new(allowed.x, movement.y, allowed.y)
 }
 
 /// System for WASD camera movement
+///
+/// Ignored while a [`SHORTCUT_MODIFIERS`] key is held, so Ctrl+S saves without
+/// moving the camera.
 pub fn camera_movement_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     time: Res<Time>,
     rotation_state: Res<CameraRotationState>,
+    orbit_state: Res<MouseOrbitState>,
+    camera_limits: Res<CameraLimits>,
     mut camera_query: Query<&mut Transform, With<TacticalCamera>>,
 ) {
-    // Block movement during camera rotation to maintain consistent focus point
-    if !matches!(rotation_state.rotation_mode, RotationMode::Stable) {
+    // Block movement during camera rotation or orbiting to maintain consistent focus point
+    if !matches!(rotation_state.rotation_mode, RotationMode::Stable) || orbit_state.is_orbiting {
+        return;
+    }
+    if keyboard_input.any_pressed(SHORTCUT_MODIFIERS) {
         return;
     }
 
@@ -103,27 +92,142 @@ pub fn camera_movement_system(
             proposed_position += right * movement_speed * delta_time;
         }
 
-        // Apply the proposed position (clamping will be handled by dedicated system)
-        transform.translation = proposed_position;
+        // Slow down near the edge of the movement radius
+        let movement = proposed_position - transform.translation;
+        let allowed = limited_camera_movement(&camera_limits, transform.translation, movement);
+        transform.translation += allowed;
     }
 }
 
-/// System for mouse wheel and trackpad zoom
+/// System for mouse wheel and trackpad zoom, setting the scale
+/// [`camera_zoom_animation_system`] eases toward
 pub fn camera_zoom_system(
     mut mouse_wheel_events: EventReader<MouseWheel>,
     camera_limits: Res<CameraLimits>,
-    mut camera_query: Query<&mut Projection, With<TacticalCamera>>,
+    mut zoom_state: ResMut<CameraZoomState>,
+    rotation_state: Res<CameraRotationState>,
+    camera_query: Query<&Projection, With<TacticalCamera>>,
 ) {
-    if let Ok(mut projection) = camera_query.single_mut() {
-        for event in mouse_wheel_events.read() {
-            let zoom_speed = 0.0001; // Adjust orthographic scale
-
-            // Adjust orthographic scale for zoom (smaller scale = more zoomed in)
-            if let Projection::Orthographic(ortho) = projection.as_mut() {
-                ortho.scale = (ortho.scale - event.y * zoom_speed)
-                    .clamp(camera_limits.min_zoom_scale, camera_limits.max_zoom_scale);
-            }
-        }
+    // Framing moves animate the zoom themselves
+    if matches!(rotation_state.rotation_mode, RotationMode::Framing(_)) {
+        mouse_wheel_events.clear();
+        return;
+    }
+    let Ok(Projection::Orthographic(ortho)) = camera_query.single() else {
+        return;
+    };
+
+    // Keep zooming from the pending target so fast scrolling accumulates
+    let start = zoom_state.target.unwrap_or(ortho.scale);
+    let mut target = start;
+    for event in mouse_wheel_events.read() {
+        // Mouse wheels report notches, trackpads report pixels
+        target = scroll_zoom_scale(target, event.unit, event.y, &camera_limits);
+    }
+    if target != start {
+        zoom_state.target = Some(target);
+    }
+}
+
+/// Level lookups for finding where the camera looks at the hex surfaces
+#[derive(SystemParam)]
+pub struct CameraFocus<'w> {
+    levels_resource: Res<'w, LevelsResource>,
+    render_config: Res<'w, HexGridRenderConfig>,
+}
+
+impl CameraFocus<'_> {
+    /// Point of the current level seen at the center of a camera at `transform`
+    pub fn point(&self, transform: &Transform) -> Vec3 {
+        calculate_camera_focus_point(
+            transform,
+            self.levels_resource.current_level(),
+            self.render_config.active_void(),
+        )
+    }
+}
+
+/// Window and level lookups for finding the point under the cursor
+#[derive(SystemParam)]
+pub struct ZoomCursor<'w, 's> {
+    windows: Query<'w, 's, &'static Window, With<PrimaryWindow>>,
+    focus: CameraFocus<'w>,
+}
+
+impl ZoomCursor<'_, '_> {
+    /// Camera shift keeping the point under the cursor in place while the
+    /// scale changes by `scale_ratio`; `None` with the cursor outside the window
+    pub fn shift(
+        &self,
+        transform: &Transform,
+        camera: &Camera,
+        global_transform: &GlobalTransform,
+        scale_ratio: f32,
+    ) -> Option<Vec3> {
+        let cursor = self.windows.single().ok()?.cursor_position()?;
+        let cursor_ray = camera.viewport_to_world(global_transform, cursor).ok()?;
+
+        // Offset of the cursor's ray from the center ray, at the old scale
+        let forward = *transform.forward();
+        let lateral = cursor_ray.origin - global_transform.translation();
+        let cursor_offset = lateral - forward * lateral.dot(forward);
+
+        let anchor = self
+            .focus
+            .point(&transform.with_translation(transform.translation + cursor_offset));
+        Some(zoom_to_cursor_shift(
+            transform,
+            cursor_offset,
+            anchor,
+            scale_ratio,
+        ))
+    }
+}
+
+/// System easing the orthographic scale toward [`CameraZoomState::target`]
+///
+/// Zooms toward the point under the cursor, keeping it in place on screen; with
+/// the cursor outside the window, or while rotating, zooms toward the center.
+pub fn camera_zoom_animation_system(
+    time: Res<Time>,
+    mut zoom_state: ResMut<CameraZoomState>,
+    camera_limits: Res<CameraLimits>,
+    rotation_state: Res<CameraRotationState>,
+    zoom_cursor: ZoomCursor,
+    mut camera_query: Query<
+        (&mut Transform, &mut Projection, &Camera, &GlobalTransform),
+        With<TacticalCamera>,
+    >,
+) {
+    let Some(target) = zoom_state.target else {
+        return;
+    };
+    let Ok((mut transform, mut projection, camera, global_transform)) = camera_query.single_mut()
+    else {
+        return;
+    };
+    let Projection::Orthographic(ortho) = projection.as_mut() else {
+        return;
+    };
+
+    // Limits may have moved since the target was set, e.g. on window resize
+    let target = target.clamp(camera_limits.min_zoom_scale, camera_limits.max_zoom_scale);
+    let old_scale = ortho.scale;
+    ortho.scale = ease_zoom_scale(old_scale, target, zoom_state.speed, time.delta_secs());
+    if ortho.scale == target {
+        zoom_state.target = None;
+    }
+
+    if matches!(rotation_state.rotation_mode, RotationMode::Stable)
+        && let Some(shift) = zoom_cursor.shift(
+            &transform,
+            camera,
+            global_transform,
+            ortho.scale / old_scale,
+        )
+    {
+        let allowed = limited_camera_movement(&camera_limits, transform.translation, shift);
+        transform.translation += allowed;
     }
 }
 
@@ -133,22 +237,25 @@ pub fn camera_rotation_input_system(
     mut rotation_state: ResMut<CameraRotationState>,
     camera_query: Query<&Transform, With<TacticalCamera>>,
     levels_resource: Res<LevelsResource>,
+    render_config: Res<HexGridRenderConfig>,
+    orbit_state: Res<MouseOrbitState>,
 ) {
-    // Only accept input when camera is stable (not currently rotating)
-    if matches!(rotation_state.rotation_mode, RotationMode::Stable) {
-        if let Ok(transform) = camera_query.single() {
-            let level = levels_resource.current_level();
-            // Q rotates counter-clockwise (90 degrees)
-            if keyboard_input.just_pressed(KeyCode::KeyQ) {
-                rotation_state.focus_point = calculate_camera_focus_point(transform, level);
-                rotation_state.rotation_mode =
-                    RotationMode::CounterClockwise(90.0_f32.to_radians());
-            }
-            // E rotates clockwise (90 degrees)
-            if keyboard_input.just_pressed(KeyCode::KeyE) {
-                rotation_state.focus_point = calculate_camera_focus_point(transform, level);
-                rotation_state.rotation_mode = RotationMode::Clockwise(90.0_f32.to_radians());
-            }
+    // Only accept input when camera is stable (not rotating or being orbited)
+    if matches!(rotation_state.rotation_mode, RotationMode::Stable)
+        && !orbit_state.is_orbiting
+        && let Ok(transform) = camera_query.single()
+    {
+        let level = levels_resource.current_level();
+        let void = render_config.active_void();
+        // Q rotates counter-clockwise (90 degrees)
+        if keyboard_input.just_pressed(KeyCode::KeyQ) {
+            rotation_state.focus_point = calculate_camera_focus_point(transform, level, void);
+            rotation_state.rotation_mode = RotationMode::CounterClockwise(90.0_f32.to_radians());
+        }
+        // E rotates clockwise (90 degrees)
+        if keyboard_input.just_pressed(KeyCode::KeyE) {
+            rotation_state.focus_point = calculate_camera_focus_point(transform, level, void);
+            rotation_state.rotation_mode = RotationMode::Clockwise(90.0_f32.to_radians());
         }
     }
 }
@@ -158,39 +265,50 @@ pub fn debug_camera_logging_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     camera_query: Query<(&Transform, &Projection), With<TacticalCamera>>,
     levels_resource: Res<LevelsResource>,
+    chunk_query: Query<&ViewVisibility, With<HexChunk>>,
 ) {
     // Only trigger on 'C' key press (not hold)
-    if keyboard_input.just_pressed(KeyCode::KeyC) {
-        if let Ok((transform, projection)) = camera_query.single() {
-            let level = levels_resource.current_level();
-
-            // Get orthographic scale
-            let scale = match projection {
-                Projection::Orthographic(ortho) => ortho.scale,
-                _ => 0.0,
-            };
-
-            // Convert rotation quaternion to readable angles (in degrees)
-            // EulerRot::YXZ order: Y(yaw), X(pitch), Z(roll)
-            let (yaw, pitch, roll) = transform.rotation.to_euler(EulerRot::YXZ);
-            let yaw_deg = yaw.to_degrees();
-            let pitch_deg = pitch.to_degrees();
-            let roll_deg = roll.to_degrees();
-
-            // Log detailed camera debug information
-            info!(
-                "CAMERA_DEBUG: Level='{level_name}' ({width}x{height}) | Pos=({pos_x:.3}, {pos_y:.3}, {pos_z:.3}) | Scale={scale:.6} | Rotation=({yaw:.1}°, {pitch:.1}°, {roll:.1}°)",
-                level_name = level.name,
-                width = level.width,
-                height = level.height,
-                pos_x = transform.translation.x,
-                pos_y = transform.translation.y,
-                pos_z = transform.translation.z,
-                pitch = pitch_deg,
-                yaw = yaw_deg,
-                roll = roll_deg
-            );
-        }
+    if keyboard_input.just_pressed(KeyCode::KeyC)
+        && let Ok((transform, projection)) = camera_query.single()
+    {
+        let level = levels_resource.current_level();
+
+        // Get orthographic scale
+        let scale = match projection {
+            Projection::Orthographic(ortho) => ortho.scale,
+            _ => 0.0,
+        };
+
+        // Convert rotation quaternion to readable angles (in degrees)
+        // EulerRot::YXZ order: Y(yaw), X(pitch), Z(roll)
+        let (yaw, pitch, roll) = transform.rotation.to_euler(EulerRot::YXZ);
+        let yaw_deg = yaw.to_degrees();
+        let pitch_deg = pitch.to_degrees();
+        let roll_deg = roll.to_degrees();
+
+        // Log detailed camera debug information
+        info!(
+            "CAMERA_DEBUG: Level='{level_name}' ({width}x{height}) | Pos=({pos_x:.3}, {pos_y:.3}, {pos_z:.3}) | Scale={scale:.6} | Rotation=({yaw:.1}°, {pitch:.1}°, {roll:.1}°)",
+            level_name = level.name,
+            width = level.width,
+            height = level.height,
+            pos_x = transform.translation.x,
+            pos_y = transform.translation.y,
+            pos_z = transform.translation.z,
+            pitch = pitch_deg,
+            yaw = yaw_deg,
+            roll = roll_deg
+        );
+
+        // Chunks outside the view frustum are culled by their bounds
+        let visible_chunks = chunk_query
+            .iter()
+            .filter(|visibility| visibility.get())
+            .count();
+        info!(
+            "CAMERA_DEBUG: {visible_chunks}/{total} grid chunk entities visible",
+            total = chunk_query.iter().len()
+        );
     }
 }
 
@@ -200,12 +318,104 @@ pub struct MousePanState {
     pub is_panning: bool,
 }
 
+/// Resource holding player-adjustable camera input settings
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct CameraInputSettings {
+    /// Pan when the cursor rests near the window edge
+    pub edge_pan: bool,
+    /// Distance from the window edge, in logical pixels, that starts edge panning
+    pub edge_pan_margin: f32,
+    /// Edge panning speed in logical pixels per second, so it feels the same
+    /// at any zoom
+    pub edge_pan_speed: f32,
+}
+
+impl Default for CameraInputSettings {
+    fn default() -> Self {
+        Self {
+            // The cursor often leaves an embedded browser canvas, which would
+            // start unwanted pans
+            edge_pan: !cfg!(target_arch = "wasm32"),
+            edge_pan_margin: 12.0,
+            edge_pan_speed: 600.0,
+        }
+    }
+}
+
+/// Screen direction to edge-pan with the cursor at `cursor` in a window of
+/// `window_size`: +X toward the right edge, +Y toward the top edge
+///
+/// Corners combine both directions; the result has unit length or is zero.
+pub fn edge_pan_direction(cursor: Vec2, window_size: Vec2, margin: f32) -> Vec2 {
+    let axis = |position: f32, size: f32| {
+        if position < margin {
+            -1.0
+        } else if position > size - margin {
+            1.0
+        } else {
+            0.0
+        }
+    };
+    // Window coordinates grow downward, so the top edge pans forward
+    Vec2::new(
+        axis(cursor.x, window_size.x),
+        -axis(cursor.y, window_size.y),
+    )
+    .normalize_or_zero()
+}
+
+/// System for panning while the cursor rests near the window edge
+pub fn camera_edge_pan_system(
+    settings: Res<CameraInputSettings>,
+    time: Res<Time>,
+    rotation_state: Res<CameraRotationState>,
+    orbit_state: Res<MouseOrbitState>,
+    camera_limits: Res<CameraLimits>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut camera_query: Query<(&mut Transform, &Projection), With<TacticalCamera>>,
+) {
+    // Block panning during camera rotation or orbiting to maintain consistent behavior
+    if !settings.edge_pan
+        || !matches!(rotation_state.rotation_mode, RotationMode::Stable)
+        || orbit_state.is_orbiting
+    {
+        return;
+    }
+    let Some(direction) = windows.single().ok().and_then(|window| {
+        let cursor = window.cursor_position().filter(|_| window.focused)?;
+        Some(edge_pan_direction(
+            cursor,
+            window.size(),
+            settings.edge_pan_margin,
+        ))
+    }) else {
+        return;
+    };
+    if direction == Vec2::ZERO {
+        return;
+    }
+
+    if let Ok((mut transform, Projection::Orthographic(ortho))) = camera_query.single_mut() {
+        // Same ground-plane vectors as WASD movement
+        let forward = Vec3::new(transform.forward().x, 0.0, transform.forward().z).normalize();
+        let right = transform.right();
+
+        // Orthographic scale is world units per pixel
+        let speed = settings.edge_pan_speed * ortho.scale;
+        let movement = (right * direction.x + forward * direction.y) * speed * time.delta_secs();
+        let allowed = limited_camera_movement(&camera_limits, transform.translation, movement);
+        transform.translation += allowed;
+    }
+}
+
 /// System for mouse panning input (right mouse button + drag)
 pub fn camera_mouse_pan_system(
     mut mouse_button_events: EventReader<MouseButtonInput>,
     mut mouse_motion_events: EventReader<MouseMotion>,
     mut pan_state: ResMut<MousePanState>,
     rotation_state: Res<CameraRotationState>,
+    orbit_state: Res<MouseOrbitState>,
+    camera_limits: Res<CameraLimits>,
     mut camera_query: Query<&mut Transform, With<TacticalCamera>>,
 ) {
     // Block panning during camera rotation to maintain consistent behavior
@@ -227,39 +437,113 @@ pub fn camera_mouse_pan_system(
         }
     }
 
-    // Handle mouse motion for actual panning
-    if pan_state.is_panning {
-        if let Ok(mut transform) = camera_query.single_mut() {
-            for event in mouse_motion_events.read() {
-                let pan_sensitivity = 0.01; // Adjust sensitivity for comfortable panning
+    // Handle mouse motion for actual panning; an orbit owns the camera, so its
+    // drags are dropped
+    if orbit_state.is_orbiting {
+        mouse_motion_events.clear();
+    } else if pan_state.is_panning
+        && let Ok(mut transform) = camera_query.single_mut()
+    {
+        for event in mouse_motion_events.read() {
+            let pan_sensitivity = 0.01; // Adjust sensitivity for comfortable panning
+
+            // Convert mouse delta to world-space movement relative to camera orientation
+            // Similar to WASD movement but based on mouse motion
+            let forward = Vec3::new(transform.forward().x, 0.0, transform.forward().z).normalize();
+            let right = transform.right();
+
+            // Calculate proposed movement
+            let movement = right * (-event.delta.x * pan_sensitivity)
+                + forward * (event.delta.y * pan_sensitivity);
+
+            let allowed = limited_camera_movement(&camera_limits, transform.translation, movement);
+            transform.translation += allowed;
+        }
+    }
+}
 
-                // Convert mouse delta to world-space movement relative to camera orientation
-                // Similar to WASD movement but based on mouse motion
-                let forward =
-                    Vec3::new(transform.forward().x, 0.0, transform.forward().z).normalize();
-                let right = transform.right();
+/// Resource to track middle mouse orbiting state
+#[derive(Resource, Default)]
+pub struct MouseOrbitState {
+    pub is_orbiting: bool,
+    pub focus_point: Vec3, // Point to orbit around, captured when the button is pressed
+}
 
-                // Calculate proposed movement
-                let movement = right * (-event.delta.x * pan_sensitivity)
-                    + forward * (event.delta.y * pan_sensitivity);
+/// Run condition that is true unless the middle mouse button is orbiting the camera
+pub fn not_orbiting(orbit_state: Res<MouseOrbitState>) -> bool {
+    !orbit_state.is_orbiting
+}
 
-                let proposed_position = transform.translation + movement;
+/// System for free orbiting input (middle mouse button + drag)
+///
+/// Yaws the camera around the focus point captured on press. Releasing the
+/// button has limits recalculated for the new orientation, as after Q/E, and is
+/// handled even while a rotation or framing move is in flight.
+pub fn camera_mouse_orbit_system(
+    mut mouse_button_events: EventReader<MouseButtonInput>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mut orbit_state: ResMut<MouseOrbitState>,
+    rotation_state: Res<CameraRotationState>,
+    mut camera_limits: ResMut<CameraLimits>,
+    camera_focus: CameraFocus,
+    mut camera_query: Query<&mut Transform, With<TacticalCamera>>,
+) {
+    // Block orbiting during Q/E rotation so the two never fight over the camera
+    let is_stable = matches!(rotation_state.rotation_mode, RotationMode::Stable);
 
-                // Apply the proposed position (clamping will be handled by dedicated system)
-                transform.translation = proposed_position;
+    // Handle mouse button events for starting/stopping orbiting
+    for event in mouse_button_events.read() {
+        if event.button == MouseButton::Middle {
+            match event.state {
+                ButtonState::Pressed => {
+                    if is_stable && let Ok(transform) = camera_query.single() {
+                        orbit_state.focus_point = camera_focus.point(transform);
+                        orbit_state.is_orbiting = true;
+                    }
+                }
+                ButtonState::Released => {
+                    if orbit_state.is_orbiting {
+                        orbit_state.is_orbiting = false;
+                        camera_limits.rotation_processed = false; // Mark as needing processing
+                    }
+                }
             }
         }
     }
+
+    // Handle mouse motion for actual orbiting; motion while blocked is dropped
+    if !is_stable {
+        mouse_motion_events.clear();
+    } else if orbit_state.is_orbiting
+        && let Ok(mut transform) = camera_query.single_mut()
+    {
+        for event in mouse_motion_events.read() {
+            let orbit_sensitivity = 0.005; // Radians per pixel dragged
+
+            // Dragging right swings the camera left around the focus point
+            orbit_camera_around_point(
+                &mut transform,
+                orbit_state.focus_point,
+                -event.delta.x * orbit_sensitivity,
+            );
+        }
+    }
 }
 
-/// System to clamp camera position to movement bounds
+/// System to pull the camera back inside the movement radius
+///
+/// Zooming out shrinks the radius, so the camera eases back toward the optimal
+/// position instead of jumping; at maximum zoom it returns there entirely.
 pub fn clamp_camera_position_system(
+    time: Res<Time>,
     camera_limits: Res<CameraLimits>,
     mut camera_query: Query<&mut Transform, With<TacticalCamera>>,
     rotation_state: Res<CameraRotationState>,
+    orbit_state: Res<MouseOrbitState>,
 ) {
-    // Don't clamp if rotation is not processed
-    if !camera_limits.rotation_processed {
+    // Don't clamp if rotation is not processed or while orbiting, since the
+    // optimal position is only known for settled orientations
+    if !camera_limits.rotation_processed || orbit_state.is_orbiting {
         return;
     }
     // Block panning during camera rotation to maintain consistent behavior
@@ -267,26 +551,17 @@ pub fn clamp_camera_position_system(
         return;
     }
     if let Ok(mut transform) = camera_query.single_mut() {
-        let current_movement_radius = camera_limits.current_movement_radius;
-
-        let optimal_x = camera_limits.optimal_camera_position.x;
-        let optimal_z = camera_limits.optimal_camera_position.z;
-
-        if current_movement_radius > 0.0 {
-            // Clamp X and Z coordinates to optimal position +- radius
-            // Y coordinate is not clamped (camera height stays the same)
-            transform.translation.x = transform.translation.x.clamp(
-                optimal_x - current_movement_radius,
-                optimal_x + current_movement_radius,
-            );
-            transform.translation.z = transform.translation.z.clamp(
-                optimal_z - current_movement_radius,
-                optimal_z + current_movement_radius,
-            );
-        } else {
-            // When radius is 0, force camera to stay exactly at optimal position
-            transform.translation.x = optimal_x;
-            transform.translation.z = optimal_z;
+        // Y coordinate is not clamped (camera height stays the same)
+        let optimal = camera_limits.optimal_camera_position.xz();
+        let offset = transform.translation.xz() - optimal;
+        let pulled = pull_within_radius(
+            offset,
+            camera_limits.current_movement_radius,
+            time.delta_secs(),
+        );
+        if pulled != offset {
+            transform.translation.x = optimal.x + pulled.x;
+            transform.translation.z = optimal.y + pulled.y;
         }
     }
 }
@@ -310,25 +585,317 @@ pub fn debug_aid_toggle_system(
     }
 }
 
-/// Plugin for input handling (camera controls, level cycling, debug commands)
+/// System to handle N key input for regenerating the current level from noise
+///
+/// Editor aid for eyeballing procedural terrain: replaces the current level's
+/// heights in place with noise terrain from a fresh seed, keeping everything
+/// else about the level. Not part of [`InputPlugin`].
+pub fn regenerate_level_input_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut levels_resource: ResMut<LevelsResource>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::KeyN) {
+        return;
+    }
+
+    // Derive a seed from elapsed time so each press yields new terrain (works on WASM)
+    let seed = time.elapsed().as_nanos() as u64;
+    let level = levels_resource.current_level_mut();
+    level.fill_heights_from_noise(NoiseParams {
+        seed,
+        ..Default::default()
+    });
+
+    info!(
+        "Level regenerated: '{level_name}' from noise seed {seed}",
+        level_name = level.name
+    );
+}
+
+/// Plugin for input handling (camera controls, debug commands)
 pub struct InputPlugin;
 
 impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<MousePanState>().add_systems(
-            Update,
-            (
-                level_cycling_input_system,
-                camera_movement_system,
-                camera_zoom_system,
-                camera_rotation_input_system,
-                camera_mouse_pan_system,
-                debug_camera_logging_system,
-                debug_aid_toggle_system,
-                clamp_camera_position_system
-                    .after(camera_movement_system)
-                    .after(camera_mouse_pan_system),
-            ),
+        app.init_resource::<MousePanState>()
+            .init_resource::<MouseOrbitState>()
+            .init_resource::<CameraInputSettings>()
+            .add_systems(
+                Update,
+                (
+                    camera_movement_system,
+                    camera_edge_pan_system,
+                    camera_zoom_system,
+                    camera_zoom_animation_system.after(camera_zoom_system),
+                    camera_rotation_input_system,
+                    reset_camera_framing
+                        .run_if(input_just_pressed(KeyCode::Home).and(not_orbiting)),
+                    camera_mouse_pan_system,
+                    camera_mouse_orbit_system,
+                    debug_camera_logging_system,
+                    debug_aid_toggle_system,
+                    clamp_camera_position_system
+                        .after(camera_movement_system)
+                        .after(camera_mouse_pan_system)
+                        .after(camera_edge_pan_system)
+                        .after(camera_mouse_orbit_system)
+                        .after(camera_zoom_animation_system),
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::Level;
+
+    fn orbit_app() -> App {
+        let mut app = App::new();
+        app.add_event::<MouseButtonInput>()
+            .add_event::<MouseMotion>()
+            .init_resource::<MouseOrbitState>()
+            .init_resource::<CameraRotationState>()
+            .insert_resource(CameraLimits {
+                rotation_processed: true,
+                ..default()
+            })
+            .init_resource::<HexGridRenderConfig>()
+            .insert_resource(LevelsResource::new(vec![Level::new(
+                "Flat".to_string(),
+                10,
+                10,
+            )]))
+            .add_systems(Update, camera_mouse_orbit_system);
+        app.world_mut().spawn((
+            Transform::from_xyz(4.5, 20.0, -4.5)
+                .with_rotation(crate::rendering::camera::default_camera_rotation()),
+            TacticalCamera,
+        ));
+        app
+    }
+
+    fn send_middle_button(app: &mut App, state: ButtonState) {
+        app.world_mut().send_event(MouseButtonInput {
+            button: MouseButton::Middle,
+            state,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    fn camera_transform(app: &mut App) -> Transform {
+        let world = app.world_mut();
+        *world
+            .query_filtered::<&Transform, With<TacticalCamera>>()
+            .single(world)
+            .expect("Camera should exist")
+    }
+
+    #[test]
+    fn test_middle_drag_orbits_around_focus() {
+        let mut app = orbit_app();
+        let start = camera_transform(&mut app);
+
+        send_middle_button(&mut app, ButtonState::Pressed);
+        app.update();
+        let focus = app.world().resource::<MouseOrbitState>().focus_point;
+        assert!(app.world().resource::<MouseOrbitState>().is_orbiting);
+
+        app.world_mut().send_event(MouseMotion {
+            delta: Vec2::new(100.0, 40.0),
+        });
+        app.update();
+        let orbited = camera_transform(&mut app);
+        assert_ne!(orbited.rotation, start.rotation);
+        assert_eq!(orbited.translation.y, start.translation.y, "Yaw only");
+        let focus_distance = |transform: Transform| (transform.translation - focus).length();
+        assert!((focus_distance(orbited) - focus_distance(start)).abs() < 1e-4);
+        // The camera still looks at the captured focus point
+        let to_focus = (focus - orbited.translation).normalize();
+        assert!(to_focus.dot(*orbited.forward()) > 0.9999);
+        assert!(app.world().resource::<CameraLimits>().rotation_processed);
+
+        send_middle_button(&mut app, ButtonState::Released);
+        app.update();
+        assert!(!app.world().resource::<MouseOrbitState>().is_orbiting);
+        assert!(
+            !app.world().resource::<CameraLimits>().rotation_processed,
+            "Releasing refreshes limits for the new orientation"
+        );
+    }
+
+    #[test]
+    fn test_orbit_is_blocked_during_q_e_rotation() {
+        let mut app = orbit_app();
+        app.world_mut()
+            .resource_mut::<CameraRotationState>()
+            .rotation_mode = RotationMode::Clockwise(1.0);
+        let start = camera_transform(&mut app);
+
+        send_middle_button(&mut app, ButtonState::Pressed);
+        app.world_mut().send_event(MouseMotion {
+            delta: Vec2::new(100.0, 0.0),
+        });
+        app.update();
+        assert!(!app.world().resource::<MouseOrbitState>().is_orbiting);
+        assert_eq!(camera_transform(&mut app), start);
+    }
+
+    #[test]
+    fn test_wasd_is_ignored_while_a_shortcut_modifier_is_held() {
+        let mut app = orbit_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Time>()
+            .add_systems(Update, camera_movement_system);
+        app.world_mut()
+            .resource_mut::<CameraLimits>()
+            .current_movement_radius = 100.0;
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_millis(100));
+        let start = camera_transform(&mut app);
+
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::ControlLeft);
+        keys.press(KeyCode::KeyS);
+        app.update();
+        assert_eq!(camera_transform(&mut app), start, "Ctrl+S only saves");
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::ControlLeft);
+        app.update();
+        assert_ne!(camera_transform(&mut app), start, "S alone moves back");
+    }
+
+    #[test]
+    fn test_panning_and_movement_are_blocked_while_orbiting() {
+        let mut app = App::new();
+        app.add_event::<MouseButtonInput>()
+            .add_event::<MouseMotion>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<Time>()
+            .init_resource::<CameraRotationState>()
+            .insert_resource(MousePanState { is_panning: true })
+            .insert_resource(MouseOrbitState {
+                is_orbiting: true,
+                ..default()
+            })
+            .insert_resource(CameraLimits {
+                current_movement_radius: 100.0,
+                ..default()
+            })
+            .add_systems(Update, (camera_movement_system, camera_mouse_pan_system));
+        app.world_mut().spawn((
+            Transform::from_xyz(4.5, 20.0, -4.5)
+                .with_rotation(crate::rendering::camera::default_camera_rotation()),
+            TacticalCamera,
+        ));
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_millis(100));
+        let start = camera_transform(&mut app);
+        let drag = |app: &mut App| {
+            app.world_mut().send_event(MouseMotion {
+                delta: Vec2::new(50.0, 20.0),
+            });
+        };
+
+        // A right-drag and WASD leave the orbiting camera alone
+        drag(&mut app);
+        app.update();
+        assert_eq!(camera_transform(&mut app), start);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyW);
+        app.update();
+        assert_eq!(camera_transform(&mut app), start);
+
+        // Once the orbit ends each moves the camera again
+        app.world_mut()
+            .resource_mut::<MouseOrbitState>()
+            .is_orbiting = false;
+        app.update();
+        let moved = camera_transform(&mut app);
+        assert_ne!(moved, start, "WASD moves");
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::KeyW);
+        drag(&mut app);
+        app.update();
+        assert_ne!(camera_transform(&mut app), moved, "Dragging pans");
+    }
+
+    #[test]
+    fn test_release_during_rotation_ends_orbit() {
+        let mut app = orbit_app();
+        send_middle_button(&mut app, ButtonState::Pressed);
+        app.update();
+        assert!(app.world().resource::<MouseOrbitState>().is_orbiting);
+
+        // Another camera move starts while the button is held
+        app.world_mut()
+            .resource_mut::<CameraRotationState>()
+            .rotation_mode = RotationMode::Clockwise(1.0);
+        send_middle_button(&mut app, ButtonState::Released);
+        app.update();
+        assert!(!app.world().resource::<MouseOrbitState>().is_orbiting);
+
+        // Motion after the move settles no longer orbits
+        app.world_mut()
+            .resource_mut::<CameraRotationState>()
+            .rotation_mode = RotationMode::Stable;
+        let settled = camera_transform(&mut app);
+        app.world_mut().send_event(MouseMotion {
+            delta: Vec2::new(100.0, 0.0),
+        });
+        app.update();
+        assert_eq!(camera_transform(&mut app), settled);
+    }
+
+    #[test]
+    fn test_rotation_keys_and_home_are_blocked_while_orbiting() {
+        let mut app = orbit_app();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(Update, camera_rotation_input_system);
+        send_middle_button(&mut app, ButtonState::Pressed);
+        app.update();
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::KeyQ);
+        app.update();
+        assert!(matches!(
+            app.world().resource::<CameraRotationState>().rotation_mode,
+            RotationMode::Stable
+        ));
+        assert!(!app.world_mut().run_system_cached(not_orbiting).unwrap());
+
+        send_middle_button(&mut app, ButtonState::Released);
+        app.update();
+        assert!(app.world_mut().run_system_cached(not_orbiting).unwrap());
+    }
+
+    #[test]
+    fn test_edge_pan_follows_edges_and_combines_at_corners() {
+        let window = Vec2::new(800.0, 600.0);
+        let margin = 12.0;
+        let direction = |x, y| edge_pan_direction(Vec2::new(x, y), window, margin);
+
+        assert_eq!(direction(400.0, 300.0), Vec2::ZERO);
+        assert_eq!(
+            direction(12.0, 300.0),
+            Vec2::ZERO,
+            "The margin is exclusive"
         );
+        assert_eq!(direction(5.0, 300.0), Vec2::NEG_X);
+        assert_eq!(direction(795.0, 300.0), Vec2::X);
+        assert_eq!(direction(400.0, 2.0), Vec2::Y, "The top edge pans forward");
+        assert_eq!(direction(400.0, 599.0), Vec2::NEG_Y);
+
+        let corner = direction(799.0, 0.0);
+        assert!((corner - Vec2::new(1.0, 1.0).normalize()).length() < 1e-6);
+        assert!((direction(0.0, 600.0).length() - 1.0).abs() < 1e-6);
     }
 }
@@ -3,90 +3,428 @@
 //! Core level data structures, management, and mesh generation for tactical RPG
 //! hex-based level geometry in both the game and level editor applications.
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 #[cfg(not(target_arch = "wasm32"))]
 use bevy::pbr::wireframe::{WireframeConfig, WireframePlugin};
 use bevy::prelude::*;
 use hexx::{Hex, HexLayout};
 use ndarray::Array2;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-#[cfg(target_arch = "wasm32")]
-use toml;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use tracing::{info, warn};
 
 #[cfg(not(target_arch = "wasm32"))]
 use crate::colors::*;
-use crate::level::management::level_switching_system;
-use crate::level::mesh::spawn_hex_grid;
-
+use crate::level::asset::{
+    LevelAsset, LevelAssetLoader, level_asset_loading_system, request_level_assets,
+};
+use crate::level::backup::{DEFAULT_BACKUP_COUNT, rotate_backups};
+use crate::level::chunks::{RebuildChunk, chunk_rebuild_system};
+use crate::level::compression::{GZIP_EXTENSION, compress, decode_level_bytes, is_gzip_path};
+use crate::level::drop::{LevelFileRejected, dropped_level_file_system};
+use crate::level::format::LevelFormat;
+use crate::level::generation::HeightGenerator;
+use crate::level::geometry::LevelGeometry;
+use crate::level::lighting::LevelLighting;
+use crate::level::management::{DefaultLevelConfig, StartingLevel, level_switching_system};
+use crate::level::manifest::{LEVEL_MANIFEST_FILE, LevelManifest, REMOTE_LEVEL_MANIFEST_FILE};
+use crate::level::mesh::{
+    HexEdgeStyle, HexMaterials, HexMeshOptions, TerrainColoring, spawn_hex_grid,
+};
+use crate::level::naming::{LoadRename, make_names_unique};
+use crate::level::overlays::OverlayHex;
+use crate::level::ramps::Ramp;
+use crate::level::regions::Region;
+use crate::level::reload::level_hot_reload_system;
+use crate::level::render_config::{
+    HexGridRenderConfig, apply_surface_material_system, surface_texture_toggle_system,
+};
+use crate::level::report::{LevelLoadErrorKind, LevelLoadFailure, LevelLoadReport};
+use crate::level::sorting::{LevelSortMode, order_levels};
+use crate::level::spawn::SpawnPoint;
+use crate::level::triggers::{
+    FiredTriggers, HexEntered, HexTrigger, HexTriggerFired, hex_trigger_system,
+};
+
+pub mod asset;
+pub mod backup;
+pub mod builder;
+pub mod check;
+pub mod chunks;
+pub mod compression;
+pub mod diff;
+pub mod drop;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod export;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod external;
+pub mod format;
+pub mod generation;
+pub mod geometry;
+mod grid_serde;
+pub mod hash;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod heightmap;
+pub mod lighting;
 pub mod management;
+pub mod manifest;
 pub mod mesh;
+pub mod migration;
+pub mod naming;
+pub mod navigation;
+pub mod neighbors;
+pub mod overlays;
+pub mod pathfinding;
+pub mod persistence;
+pub mod presets;
+pub mod ramps;
+pub mod regions;
+pub mod reload;
+pub mod remote;
+pub mod render_config;
+pub mod report;
+pub mod sampling;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod saving;
+pub mod slope;
+pub mod smoothing;
+pub mod sorting;
+pub mod spawn;
+pub mod stats;
+pub mod tags;
+pub mod transform;
+pub mod triggers;
+pub mod validation;
+pub mod visibility;
+
+pub use visibility::line_of_sight;
+
+/// Newest level file format version understood by this crate
+///
+/// Version 0 is the original field set (name, dimensions, heights). Version 1 adds
+/// walkability, water level, and author/description metadata.
+pub const CURRENT_LEVEL_FORMAT: u32 = 1;
+
+/// Hex scale used by levels that do not specify one
+fn default_hex_scale() -> f32 {
+    1.0
+}
+
+/// Which way hexes point in the world layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HexOrientation {
+    /// Corners point along the Z axis (the original layout)
+    #[default]
+    Pointy,
+    /// Flat edges face along the Z axis
+    Flat,
+}
 
 /// Represents a tactical level with hex grid layout and height data
+///
+/// The derived serde code is exposed as inherent functions (`remote = "Self"`) so
+/// the trait impls in [`presets`] can resolve `heights_by_preset` grids.
 #[derive(Debug, Clone, Resource, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct Level {
     /// Human-readable name for this level
     pub name: String,
+    /// Level file format version (0 for files written before versioning existed)
+    #[serde(default)]
+    pub format_version: u32,
+    /// Optional level author credit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Optional designer-facing description of the level
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /// Width of the hex grid (number of columns)
     pub width: i32,
     /// Height of the hex grid (number of rows)
     pub height: i32,
+    /// Hex radius (center to corner) in world units; files without it use 1.0
+    #[serde(default = "default_hex_scale")]
+    pub hex_scale: f32,
+    /// Hex orientation; files without it use pointy-top hexes
+    #[serde(default)]
+    pub orientation: HexOrientation,
+    /// Named heights that a `heights_by_preset` grid may reference
+    ///
+    /// Ordered by name so saved files stay stable under version control.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub height_presets: BTreeMap<String, f32>,
     /// Height data for each hex position, stored as [row][col]
+    ///
+    /// Files may instead provide a `heights_by_preset` grid of preset names, which
+    /// is resolved into this array while loading.
+    #[serde(
+        default,
+        serialize_with = "grid_serde::serialize_rows",
+        deserialize_with = "grid_serde::deserialize_rows"
+    )]
     pub heights: Array2<f32>,
+    /// Walkability flag for each hex position, stored as [row][col]
+    ///
+    /// Level files that omit this grid deserialize to an empty array, which is
+    /// treated as "every hex is walkable".
+    #[serde(
+        default,
+        skip_serializing_if = "Array2::is_empty",
+        serialize_with = "grid_serde::serialize_rows",
+        deserialize_with = "grid_serde::deserialize_rows"
+    )]
+    pub walkable: Array2<bool>,
+    /// Optional water surface height; a translucent plane is rendered at this Y
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub water_level: Option<f32>,
+    /// Team starting positions; empty for levels without scenario data
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spawn_points: Vec<SpawnPoint>,
+    /// Designer tags mapping a tag name to the (q, r) hexes carrying it
+    ///
+    /// Ordered by tag name so saved files stay stable under version control.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, Vec<(i32, i32)>>,
+    /// Named multi-hex zones such as deployment areas and capture points
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub regions: Vec<Region>,
+    /// Adjacent hex pairs traversable regardless of their height difference
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ramps: Vec<Ramp>,
+    /// Named events fired when units enter specific hexes
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub triggers: Vec<HexTrigger>,
+    /// Floating hex slabs such as bridges, drawn above the base terrain
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overlays: Vec<OverlayHex>,
+    /// Sun and ambient light settings applied while this level is active
+    #[serde(default, skip_serializing_if = "LevelLighting::is_default")]
+    pub lighting: LevelLighting,
+    /// Optional sRGB clear color shown behind the level
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<[f32; 3]>,
+    /// File this level was loaded from, relative to the levels directory
+    #[serde(skip)]
+    pub source_path: Option<PathBuf>,
+    /// Name written in the level file, if loading renamed this level because
+    /// another level had the same name
+    #[serde(skip)]
+    pub load_rename: Option<LoadRename>,
+    /// Lazily computed world-space geometry, see [`Level::geometry`]
+    #[serde(skip)]
+    geometry_cache: OnceLock<LevelGeometry>,
 }
 
 impl Level {
     /// Create a new level with the specified dimensions and a height gradient
     /// that matches the current hardcoded behavior (low front-left to high back-right)
     pub fn new(name: String, width: i32, height: i32) -> Self {
-        let mut heights = Array2::zeros((height as usize, width as usize));
-
-        // Replicate the current gradient calculation
-        for r in 0..height {
-            for q in 0..width {
-                let q_norm = q as f32 / (width - 1) as f32;
-                let r_norm = r as f32 / (height - 1) as f32;
-                let height_factor = (q_norm + r_norm) / 2.0;
-                let hex_height = 1.0 + height_factor * 3.0;
-                heights[(r as usize, q as usize)] = hex_height;
-            }
-        }
+        Self::generate(name, width, height, HeightGenerator::default())
+    }
+
+    /// Create a level whose heights come from `generator`
+    pub fn generate(name: String, width: i32, height: i32, generator: HeightGenerator) -> Self {
+        let heights = Array2::from_shape_fn((height as usize, width as usize), |(r, q)| {
+            generator.height_at(q as i32, r as i32, width, height)
+        });
+        let walkable = Array2::from_elem(heights.raw_dim(), true);
 
         Self {
             name,
+            format_version: CURRENT_LEVEL_FORMAT,
+            author: None,
+            description: None,
             width,
             height,
+            hex_scale: default_hex_scale(),
+            orientation: HexOrientation::default(),
+            height_presets: BTreeMap::new(),
             heights,
+            walkable,
+            water_level: None,
+            spawn_points: Vec::new(),
+            tags: BTreeMap::new(),
+            regions: Vec::new(),
+            ramps: Vec::new(),
+            triggers: Vec::new(),
+            overlays: Vec::new(),
+            lighting: LevelLighting::default(),
+            background_color: None,
+            source_path: None,
+            load_rename: None,
+            geometry_cache: OnceLock::new(),
         }
     }
 
-    /// Get the height at a specific hex coordinate
-    pub fn get_height(&self, hex: Hex) -> f32 {
-        if hex.x >= 0 && hex.x < self.width && hex.y >= 0 && hex.y < self.height {
-            self.heights[(hex.y as usize, hex.x as usize)]
-        } else {
-            0.0 // Default height for out-of-bounds coordinates
+    /// Check whether units can stand on or move through a specific hex
+    ///
+    /// Out-of-bounds coordinates are never walkable. Levels without a walkability
+    /// grid treat every in-bounds hex as walkable.
+    pub fn is_walkable(&self, hex: Hex) -> bool {
+        if !self.contains(hex) {
+            return false;
         }
+        self.walkable
+            .get((hex.y as usize, hex.x as usize))
+            .copied()
+            .unwrap_or(true)
     }
 
-    /// Generate all hex coordinates for this level's grid
-    pub fn get_hex_grid(&self) -> Vec<Hex> {
-        let mut grid = Vec::new();
-        for q in 0..self.width {
-            for r in 0..self.height {
-                grid.push(Hex::new(q, r));
-            }
+    /// Mark a hex as walkable or impassable
+    pub fn set_walkable(&mut self, hex: Hex, walkable: bool) -> Result<()> {
+        self.ensure_in_bounds(hex)?;
+
+        // Materialize the grid for levels loaded without walkability data
+        if self.walkable.shape() != self.heights.shape() {
+            self.walkable = Array2::from_elem(self.heights.raw_dim(), true);
         }
-        grid
+        self.walkable[(hex.y as usize, hex.x as usize)] = walkable;
+        Ok(())
     }
 
-    /// Returns the standard hex layout configuration used throughout the tactical RPG
+    /// Set the height of a single hex
     ///
-    /// Uses pointy orientation with 1.0 scale for consistent hex positioning across
-    /// all level operations (bounds calculation, rendering, camera positioning, etc.)
-    pub fn hex_layout() -> HexLayout {
-        HexLayout::pointy().with_scale(Vec2::splat(1.0))
+    /// Errors on out-of-bounds coordinates and on NaN or infinite heights, leaving
+    /// the level unchanged.
+    pub fn set_height(&mut self, hex: Hex, height: f32) -> Result<()> {
+        self.ensure_in_bounds(hex)?;
+        Self::ensure_finite_height(hex, height)?;
+        self.heights[(hex.y as usize, hex.x as usize)] = height;
+        self.invalidate_geometry();
+        Ok(())
+    }
+
+    /// Set every hex in `hexes` to the same height
+    ///
+    /// All coordinates and the height are validated before any hex is modified, so
+    /// a failed bulk edit never leaves the level partially updated.
+    pub fn fill_region(&mut self, hexes: &[Hex], height: f32) -> Result<()> {
+        for &hex in hexes {
+            self.ensure_in_bounds(hex)?;
+            Self::ensure_finite_height(hex, height)?;
+        }
+        for &hex in hexes {
+            self.heights[(hex.y as usize, hex.x as usize)] = height;
+        }
+        self.invalidate_geometry();
+        Ok(())
+    }
+
+    /// Return an error if a hex lies outside this level's grid
+    fn ensure_in_bounds(&self, hex: Hex) -> Result<()> {
+        if !self.contains(hex) {
+            bail!(
+                "Hex ({q}, {r}) is outside level '{level_name}' ({width}x{height})",
+                q = hex.x,
+                r = hex.y,
+                level_name = self.name,
+                width = self.width,
+                height = self.height
+            );
+        }
+        Ok(())
+    }
+
+    /// Return an error if a height value is NaN or infinite
+    fn ensure_finite_height(hex: Hex, height: f32) -> Result<()> {
+        if !height.is_finite() {
+            bail!(
+                "Height {height} for hex ({q}, {r}) is not a finite number",
+                q = hex.x,
+                r = hex.y
+            );
+        }
+        Ok(())
+    }
+
+    /// Check whether a hex coordinate lies inside this level's grid
+    ///
+    /// Valid coordinates satisfy `0 <= q < width` and `0 <= r < height`.
+    pub fn contains(&self, hex: Hex) -> bool {
+        hex.x >= 0 && hex.x < self.width && hex.y >= 0 && hex.y < self.height
+    }
+
+    /// Get the height at a specific hex coordinate, or `None` if it is out of bounds
+    pub fn try_get_height(&self, hex: Hex) -> Option<f32> {
+        if !self.contains(hex) {
+            return None;
+        }
+        self.heights.get((hex.y as usize, hex.x as usize)).copied()
+    }
+
+    /// Get the height at a specific hex coordinate, returning 0.0 when out of bounds
+    ///
+    /// The 0.0 fallback is indistinguishable from a real hex at ground level. Prefer
+    /// [`Level::try_get_height`] whenever the coordinate may lie outside the grid.
+    pub fn get_height(&self, hex: Hex) -> f32 {
+        self.try_get_height(hex).unwrap_or(0.0)
+    }
+
+    /// Get the Y coordinate hex columns are extruded from
+    ///
+    /// Columns normally rise from the ground plane at Y = 0. Levels with trenches
+    /// (negative heights) lower the base to the deepest hex so neighboring columns
+    /// close the gap down to the trench floor.
+    pub fn column_base(&self) -> f32 {
+        self.heights.iter().copied().fold(0.0, f32::min)
+    }
+
+    /// Iterate over all hex coordinates in this level's grid without allocating
+    ///
+    /// Yields column by column (all rows of q = 0, then q = 1, ...). The iterator
+    /// does not borrow the level, so callers may mutate it while iterating.
+    pub fn hex_iter(&self) -> impl Iterator<Item = Hex> + use<> {
+        let height = self.height;
+        (0..self.width).flat_map(move |q| (0..height).map(move |r| Hex::new(q, r)))
+    }
+
+    /// Collect all hex coordinates for this level's grid, in [`Level::hex_iter`] order
+    pub fn get_hex_grid(&self) -> Vec<Hex> {
+        self.hex_iter().collect()
+    }
+
+    /// Returns the hex layout configuration for this level
+    ///
+    /// Uses [`Level::orientation`] scaled by [`Level::hex_scale`] for consistent hex
+    /// positioning across all level operations (bounds calculation, rendering,
+    /// camera positioning, etc.)
+    pub fn hex_layout(&self) -> HexLayout {
+        let layout = match self.orientation {
+            HexOrientation::Pointy => HexLayout::pointy(),
+            HexOrientation::Flat => HexLayout::flat(),
+        };
+        layout.with_scale(Vec2::splat(self.hex_scale))
+    }
+
+    /// Convert a world-space XZ position to the hex containing it
+    ///
+    /// Returns `None` when the position lies outside the level grid.
+    pub fn world_to_hex(&self, pos: Vec2) -> Option<Hex> {
+        let hex = self.hex_layout().world_pos_to_hex(pos);
+        self.contains(hex).then_some(hex)
+    }
+
+    /// Convert a world-space XZ position to the nearest hex inside the grid
+    ///
+    /// Positions inside the grid resolve exactly like [`Level::world_to_hex`];
+    /// positions beyond the edge snap to the in-bounds hex with the closest center.
+    pub fn world_to_hex_clamped(&self, pos: Vec2) -> Hex {
+        if let Some(hex) = self.world_to_hex(pos) {
+            return hex;
+        }
+        let hex_layout = self.hex_layout();
+        self.hex_iter()
+            .min_by(|a, b| {
+                let distance_a = hex_layout.hex_to_world_pos(*a).distance_squared(pos);
+                let distance_b = hex_layout.hex_to_world_pos(*b).distance_squared(pos);
+                distance_a.total_cmp(&distance_b)
+            })
+            .unwrap_or(Hex::ZERO)
     }
 
     /// Calculate the world-space bounding box of this level's hex grid
@@ -94,16 +432,26 @@ impl Level {
     /// Returns (min_bounds, max_bounds) where:
     /// - min_bounds: minimum X, Y, Z coordinates across all hexes
     /// - max_bounds: maximum X, Y, Z coordinates across all hexes
+    ///
+    /// Heights may be negative, and the water level (if any) is included in the Y range.
     pub fn get_world_bounds(&self) -> (Vec3, Vec3) {
-        let hex_layout = Self::hex_layout();
+        self.world_bounds_of(|hex| self.try_get_height(hex))
+    }
+
+    /// World bounds like [`Level::get_world_bounds`], using `surface_height`
+    /// for each hex's height and leaving out hexes it returns `None` for
+    pub fn world_bounds_of(&self, surface_height: impl Fn(Hex) -> Option<f32>) -> (Vec3, Vec3) {
+        let hex_layout = self.hex_layout();
 
         let mut min_bounds = Vec3::new(f32::MAX, f32::MAX, f32::MAX);
         let mut max_bounds = Vec3::new(f32::MIN, f32::MIN, f32::MIN);
 
         // Calculate bounds by checking all hex positions
-        for hex in self.get_hex_grid() {
+        for hex in self.hex_iter() {
+            let Some(height) = surface_height(hex) else {
+                continue;
+            };
             let world_pos = hex_layout.hex_to_world_pos(hex);
-            let height = self.get_height(hex);
 
             // Convert to 3D world position (hex uses XZ plane, height is Y)
             let hex_world_pos = Vec3::new(world_pos.x, height, world_pos.y);
@@ -112,6 +460,12 @@ impl Level {
             max_bounds = max_bounds.max(hex_world_pos);
         }
 
+        // The water plane is visible geometry, so it contributes to the vertical extent
+        if let Some(water_level) = self.water_level {
+            min_bounds.y = min_bounds.y.min(water_level);
+            max_bounds.y = max_bounds.y.max(water_level);
+        }
+
         (min_bounds, max_bounds)
     }
 
@@ -160,19 +514,30 @@ impl Level {
     /// Get the center world position (average of center hexes)
     pub fn get_center_world_pos(&self) -> Vec3 {
         let center_hexes = self.get_center_hexes();
-        let hex_layout = Self::hex_layout();
+        let hex_layout = self.hex_layout();
 
         let mut total_pos = Vec3::ZERO;
-        let count = center_hexes.len() as f32;
+        let mut count = 0;
 
-        // Average the 3D positions of all center hexes
+        // Average the 3D positions of all center hexes that exist in the grid
         for hex in center_hexes {
+            let Some(height) = self.try_get_height(hex) else {
+                continue;
+            };
             let world_pos_2d = hex_layout.hex_to_world_pos(hex);
-            let height = self.get_height(hex);
             total_pos += Vec3::new(world_pos_2d.x, height, world_pos_2d.y);
+            count += 1;
+        }
+
+        if count == 0 {
+            warn!(
+                "Level '{level_name}' has no center hexes inside its grid",
+                level_name = self.name
+            );
+            return Vec3::ZERO;
         }
 
-        total_pos / count
+        total_pos / count as f32
     }
 
     /// Get the 3D diagonal extent of this level for isometric camera calculations
@@ -187,28 +552,148 @@ impl Level {
     }
 
     /// Save this level to a TOML file in the assets/levels/ directory
-    pub fn save_to_file(&self, filename: &str) -> Result<()> {
-        self.save_to_directory("assets/levels", filename)
+    pub fn save_to_file(&self, filename: &str) -> Result<PathBuf> {
+        self.save_to_directory(LEVELS_DIRECTORY, filename)
+    }
+
+    /// Save this level to a file in the specified directory
+    ///
+    /// The format follows the file extension (`.toml`, `.ron`, or `.json`), defaulting
+    /// to TOML. Returns the path of the written file.
+    pub fn save_to_directory(&self, directory: &str, filename: &str) -> Result<PathBuf> {
+        self.save_to_directory_with(directory, filename, &SaveOptions::default())
     }
 
-    /// Save this level to a TOML file in the specified directory
-    pub fn save_to_directory(&self, directory: &str, filename: &str) -> Result<()> {
-        // Create the directory if it doesn't exist
-        std::fs::create_dir_all(directory)
-            .with_context(|| format!("Failed to create directory: {directory}"))?;
+    /// Save this level to a file in the specified directory using `options`
+    ///
+    /// The content is written to a temporary file next to the target and renamed
+    /// over it, so an interrupted save never leaves a truncated level behind. An
+    /// existing file is first backed up according to [`SaveOptions::backup_count`].
+    /// Compressed files get a `.gz` suffix unless `filename` already has one.
+    pub fn save_to_directory_with(
+        &self,
+        directory: &str,
+        filename: &str,
+        options: &SaveOptions,
+    ) -> Result<PathBuf> {
+        let compressed = options.compress || is_gzip_path(filename);
+        let mut file_path = Path::new(directory).join(filename);
+        if compressed && !is_gzip_path(filename) {
+            file_path
+                .as_mut_os_string()
+                .push(format!(".{GZIP_EXTENSION}"));
+        }
+        let display_path = file_path.display();
+
+        if !options.overwrite && file_path.exists() {
+            bail!("Level file {display_path} already exists");
+        }
 
-        let file_path = format!("{directory}/{filename}");
-        let toml_content =
-            toml::to_string(self).with_context(|| "Failed to serialize level to TOML")?;
+        // Create the directory (and any subdirectory in `filename`) if it doesn't exist
+        let parent = file_path.parent().unwrap_or(Path::new(directory));
+        std::fs::create_dir_all(parent).with_context(|| {
+            format!(
+                "Failed to create directory: {parent}",
+                parent = parent.display()
+            )
+        })?;
+
+        // Write the file's own name back rather than a load-time rename
+        if self.load_rename.is_some() {
+            let mut restored = self.clone();
+            restored.name = self.saved_name().to_string();
+            restored.load_rename = None;
+            return restored.save_to_directory_with(directory, filename, options);
+        }
+
+        // Files are always written at the current format version
+        if self.format_version != CURRENT_LEVEL_FORMAT {
+            let mut migrated = self.clone();
+            migrated.migrate()?;
+            return migrated.save_to_directory_with(directory, filename, options);
+        }
+
+        let format = LevelFormat::from_path(filename).unwrap_or_default();
+        let content = match format {
+            LevelFormat::Toml if options.use_height_presets => self.to_preset_toml_string(),
+            _ => {
+                if options.use_height_presets {
+                    warn!(
+                        "Height presets are only written for TOML files; saving numeric heights to {display_path}"
+                    );
+                }
+                format.serialize(self)
+            }
+        }?;
+
+        // A failed backup is worth a warning, not a lost save
+        if let Err(err) = rotate_backups(&file_path, options.backup_count) {
+            warn!("Failed to back up {display_path} before saving: {err:#}");
+        }
 
-        fs::write(&file_path, toml_content)
-            .with_context(|| format!("Failed to write level to file: {file_path}"))?;
+        let bytes = if compressed {
+            compress(&content)?
+        } else {
+            content.into_bytes()
+        };
+        write_atomically(&file_path, &bytes)
+            .with_context(|| format!("Failed to write level to file: {display_path}"))?;
 
         info!(
-            "Saved level '{level_name}' to {file_path}",
+            "Saved level '{level_name}' to {display_path}",
             level_name = self.name
         );
-        Ok(())
+        Ok(file_path)
+    }
+}
+
+/// Write `content` to a temporary file beside `path`, then rename it into place
+fn write_atomically(path: &Path, content: &[u8]) -> Result<()> {
+    let file_name = path
+        .file_name()
+        .with_context(|| format!("{path} has no file name", path = path.display()))?;
+    let temp_path = path.with_file_name(format!(
+        ".{file_name}.{pid}.tmp",
+        file_name = file_name.to_string_lossy(),
+        pid = std::process::id()
+    ));
+
+    let result = fs::File::create(&temp_path)
+        .and_then(|mut file| {
+            file.write_all(content)?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&temp_path, path));
+    if result.is_err() {
+        // Best effort; the target itself was never touched
+        let _ = fs::remove_file(&temp_path);
+    }
+    Ok(result?)
+}
+
+/// Options for [`Level::save_to_directory_with`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveOptions {
+    /// Write heights as a `heights_by_preset` grid of preset names when the level
+    /// defines [`Level::height_presets`]
+    pub use_height_presets: bool,
+    /// Replace an existing file; when `false` saving to a taken filename fails,
+    /// as "Save As" needs
+    pub overwrite: bool,
+    /// How many previous versions of an overwritten file to keep as `.bak` files
+    pub backup_count: usize,
+    /// Gzip the file, adding a `.gz` suffix to its name
+    pub compress: bool,
+}
+
+impl Default for SaveOptions {
+    fn default() -> Self {
+        Self {
+            use_height_presets: false,
+            overwrite: true,
+            backup_count: DEFAULT_BACKUP_COUNT,
+            compress: false,
+        }
     }
 }
 
@@ -219,24 +704,25 @@ pub struct LevelsResource {
     pub levels: Vec<Level>,
     /// Index of the currently active level
     pub current_level_index: usize,
+    /// Content hash of each level when it was loaded or last saved
+    saved_hashes: Vec<u64>,
 }
 
 impl LevelsResource {
     /// Create a new LevelsResource with a single default level
     pub fn with_default() -> Self {
-        let default_level = Level::new("Default Level".to_string(), 10, 10);
-        info!("LevelsResource: Created with fallback default level");
-        Self {
-            levels: vec![default_level],
-            current_level_index: 0,
-        }
+        Self::with_config(&DefaultLevelConfig::default())
     }
 
     /// Create a new LevelsResource with provided levels
+    ///
+    /// The levels' current content is recorded as their saved state.
     pub fn new(levels: Vec<Level>) -> Self {
+        let saved_hashes = levels.iter().map(Level::content_hash).collect();
         Self {
             levels,
             current_level_index: 0,
+            saved_hashes,
         }
     }
 
@@ -245,127 +731,235 @@ impl LevelsResource {
         &self.levels[self.current_level_index]
     }
 
+    /// Get mutable access to the currently active level for editing
+    pub fn current_level_mut(&mut self) -> &mut Level {
+        &mut self.levels[self.current_level_index]
+    }
+
     /// Get the total number of available levels
     pub fn level_count(&self) -> usize {
         self.levels.len()
     }
+
+    /// Check whether the level at `index` changed since it was loaded or last saved
+    ///
+    /// Returns `false` for out-of-range indices.
+    pub fn is_modified(&self, index: usize) -> bool {
+        match (self.levels.get(index), self.saved_hashes.get(index)) {
+            (Some(level), Some(&saved)) => level.content_hash() != saved,
+            (Some(_), None) => true,
+            _ => false,
+        }
+    }
+
+    /// Record the current content of the level at `index` as its saved state
+    pub fn mark_saved(&mut self, index: usize) {
+        let Some(hash) = self.levels.get(index).map(Level::content_hash) else {
+            return;
+        };
+        if self.saved_hashes.len() < self.levels.len() {
+            self.saved_hashes.resize(self.levels.len(), 0);
+        }
+        self.saved_hashes[index] = hash;
+    }
+}
+
+/// Parse level file contents and prepare the level for use
+///
+/// Migrates older formats, rejects invalid levels, and prunes out-of-bounds
+/// annotations, exactly as the directory and asset loaders do. `relative_path`
+/// becomes the level's [`Level::source_path`] and names the file in failures.
+pub(crate) fn parse_level_file(
+    content: &str,
+    format: LevelFormat,
+    relative_path: &Path,
+) -> Result<Level, LevelLoadFailure> {
+    let failure = |kind, err| LevelLoadFailure::new(relative_path, kind, err);
+    let mut level = format
+        .parse(content)
+        .map_err(|err| failure(LevelLoadErrorKind::Parse, err))?;
+    level
+        .migrate()
+        .map_err(|err| failure(LevelLoadErrorKind::Validation, err))?;
+    level
+        .validate()
+        .map_err(|err| failure(LevelLoadErrorKind::Validation, err))?;
+    level.prune_out_of_bounds();
+    level.source_path = Some(relative_path.to_path_buf());
+    Ok(level)
+}
+
+/// Levels directory used when no override is given, relative to the working directory
+pub const LEVELS_DIRECTORY: &str = "assets/levels";
+
+/// Decode raw level file bytes, decompressing gzip data, and parse them with
+/// [`parse_level_file`]
+pub(crate) fn parse_level_bytes(
+    bytes: &[u8],
+    format: LevelFormat,
+    relative_path: &Path,
+) -> Result<Level, LevelLoadFailure> {
+    let content = decode_level_bytes(bytes)
+        .map_err(|err| LevelLoadFailure::new(relative_path, LevelLoadErrorKind::Io, err))?;
+    parse_level_file(&content, format, relative_path)
 }
 
 /// Load all level files from the assets/levels/ directory
 pub fn load_levels_from_assets() -> Result<LevelsResource> {
-    load_levels_from_directory("assets/levels")
+    load_levels_from_directory(LEVELS_DIRECTORY)
 }
 
+// Every level file in assets/levels, listed by build.rs
+#[cfg(any(target_arch = "wasm32", test))]
+include!(concat!(env!("OUT_DIR"), "/embedded_levels.rs"));
+
 /// Create levels resource from embedded assets for WASM builds
 #[cfg(target_arch = "wasm32")]
 pub fn create_levels_from_embedded_assets() -> LevelsResource {
-    let mut levels = Vec::new();
-
-    // Manually include the embedded level data
-    // These correspond to the files we embedded with embedded_asset!
-    let level_data = [
-        (
-            "default.toml",
-            include_str!("../../assets/levels/default.toml"),
-        ),
-        (
-            "test_small.toml",
-            include_str!("../../assets/levels/test_small.toml"),
-        ),
-        (
-            "test_large.toml",
-            include_str!("../../assets/levels/test_large.toml"),
-        ),
-    ];
-
-    for (filename, content) in level_data {
-        match toml::from_str::<Level>(content) {
-            Ok(level) => {
-                info!(
-                    "Successfully loaded embedded level: '{}' ({}x{})",
-                    level.name, level.width, level.height
-                );
-                levels.push(level);
-            }
-            Err(err) => {
-                warn!("Failed to parse embedded level file '{filename}': {err}");
-            }
-        }
-    }
-
-    if levels.is_empty() {
-        warn!("No embedded levels loaded, using default level");
-        LevelsResource::with_default()
-    } else {
-        info!("Successfully loaded {} embedded levels", levels.len());
-        LevelsResource::new(levels)
-    }
+    load_embedded_level_report(LevelSortMode::default()).into_levels_resource()
 }
 
-/// Load all level files from a specific directory
-pub fn load_levels_from_directory(levels_dir: &str) -> Result<LevelsResource> {
-    info!("Loading level files from directory: {levels_dir}");
+/// Load the level files compiled into WASM builds in manifest, then `sort_mode` order
+#[cfg(target_arch = "wasm32")]
+pub fn load_embedded_level_report(sort_mode: LevelSortMode) -> LevelLoadReport {
+    embedded_level_report(&EMBEDDED_LEVELS, EMBEDDED_LEVEL_MANIFEST, sort_mode)
+}
 
-    // Check if the levels directory exists
-    if fs::metadata(levels_dir).is_err() {
-        warn!("Levels directory '{levels_dir}' not found, using default level");
-        return Ok(LevelsResource::with_default());
+/// Parse embedded (relative path, contents) level files, ordered like
+/// [`load_level_report`] orders a directory with `manifest` in it
+#[cfg(any(target_arch = "wasm32", test))]
+fn embedded_level_report(
+    files: &[(&str, &[u8])],
+    manifest: Option<&str>,
+    sort_mode: LevelSortMode,
+) -> LevelLoadReport {
+    let mut report = LevelLoadReport::default();
+    for (filename, content) in files {
+        let format = LevelFormat::from_path(filename).unwrap_or_default();
+        report.record(parse_level_bytes(content, format, Path::new(filename)));
     }
+    make_names_unique(&mut report.loaded);
+    let manifest =
+        manifest.and_then(|content| parse_manifest(content, Path::new(LEVEL_MANIFEST_FILE)));
+    report.loaded = order_levels(report.loaded, manifest, sort_mode);
+    report
+}
+
+/// Read the optional level manifest in `levels_dir`
+fn read_manifest(levels_dir: &str) -> Option<LevelManifest> {
+    let path = Path::new(levels_dir).join(LEVEL_MANIFEST_FILE);
+    let content = fs::read_to_string(&path).ok()?;
+    parse_manifest(&content, &path)
+}
 
-    // Read all TOML files from the levels directory
-    let entries = fs::read_dir(levels_dir)
-        .with_context(|| format!("Failed to read levels directory: {levels_dir}"))?;
+/// Parse a level manifest read from `path`
+///
+/// A manifest that fails to parse is reported and ignored.
+fn parse_manifest(content: &str, path: &Path) -> Option<LevelManifest> {
+    LevelManifest::from_toml_str(content)
+        .inspect_err(|err| {
+            warn!(
+                "Ignoring level manifest {path}: {err:#}",
+                path = path.display()
+            )
+        })
+        .ok()
+}
 
-    let mut levels = Vec::new();
+/// Deepest subdirectory level searched for level files, where 0 is `levels_dir`
+pub const MAX_LEVEL_DIRECTORY_DEPTH: usize = 4;
+
+/// Collect level files under `directory` as (full path, path relative to the
+/// levels root, format), skipping hidden entries and non-level files
+fn collect_level_files(
+    directory: &Path,
+    relative_dir: &Path,
+    depth: usize,
+    files: &mut Vec<(PathBuf, PathBuf, LevelFormat)>,
+) -> Result<()> {
+    let entries = fs::read_dir(directory).with_context(|| {
+        format!(
+            "Failed to read levels directory: {directory}",
+            directory = directory.display()
+        )
+    })?;
 
     for entry in entries {
         let entry = entry.with_context(|| "Failed to read directory entry")?;
+        let file_name = entry.file_name();
+        if file_name.to_string_lossy().starts_with('.') {
+            continue;
+        }
         let path = entry.path();
-
-        // Only process .toml files
-        if path.extension().and_then(|s| s.to_str()) == Some("toml") {
-            let file_name = path
-                .file_name()
-                .and_then(|s| s.to_str())
-                .unwrap_or("unknown");
-            info!("Loading level file: {file_name}");
-
-            match fs::read_to_string(&path) {
-                Ok(content) => match toml::from_str::<Level>(&content) {
-                    Ok(level) => {
-                        info!(
-                            "Successfully loaded level: '{level_name}' ({width}x{height})",
-                            level_name = level.name,
-                            width = level.width,
-                            height = level.height
-                        );
-                        levels.push(level);
-                    }
-                    Err(err) => {
-                        warn!("Failed to parse TOML in {file_name}: {err}");
-                    }
-                },
-                Err(err) => {
-                    warn!("Failed to read file {file_name}: {err}");
-                }
+        let relative_path = relative_dir.join(&file_name);
+
+        if path.is_dir() {
+            if depth < MAX_LEVEL_DIRECTORY_DEPTH {
+                collect_level_files(&path, &relative_path, depth + 1, files)?;
+            } else {
+                warn!(
+                    "Skipping level directory {path} deeper than {MAX_LEVEL_DIRECTORY_DEPTH} levels",
+                    path = relative_path.display()
+                );
             }
+        } else if relative_path == Path::new(LEVEL_MANIFEST_FILE)
+            || relative_path == Path::new(REMOTE_LEVEL_MANIFEST_FILE)
+        {
+            continue;
+        } else if let Some(format) = LevelFormat::from_path(&path) {
+            files.push((path, relative_path, format));
         }
     }
+    Ok(())
+}
+
+/// Load all level files from a directory and its subdirectories
+///
+/// Subdirectories are searched up to [`MAX_LEVEL_DIRECTORY_DEPTH`] deep. Each
+/// level records its file relative to `levels_dir` in [`Level::source_path`].
+/// Use [`load_level_report`] to also learn which files failed to load.
+pub fn load_levels_from_directory(levels_dir: &str) -> Result<LevelsResource> {
+    load_levels_from_directory_with(levels_dir, LevelSortMode::default())
+}
 
-    // If no levels were loaded successfully, use default
-    if levels.is_empty() {
-        warn!("No valid level files found, using default level");
-        return Ok(LevelsResource::with_default());
+/// Load all level files from a directory and its subdirectories in `sort_mode` order
+pub fn load_levels_from_directory_with(
+    levels_dir: &str,
+    sort_mode: LevelSortMode,
+) -> Result<LevelsResource> {
+    Ok(load_level_report(levels_dir, sort_mode)?.into_levels_resource())
+}
+
+/// Load all level files from a directory, reporting each file that failed
+///
+/// A missing directory yields an empty report.
+pub fn load_level_report(levels_dir: &str, sort_mode: LevelSortMode) -> Result<LevelLoadReport> {
+    info!("Loading level files from directory: {levels_dir}");
+    let mut report = LevelLoadReport::default();
+
+    // Check if the levels directory exists
+    if fs::metadata(levels_dir).is_err() {
+        warn!("Levels directory '{levels_dir}' not found, using default level");
+        return Ok(report);
     }
 
-    // Sort levels by name for consistent ordering
-    levels.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut files = Vec::new();
+    collect_level_files(Path::new(levels_dir), Path::new(""), 0, &mut files)?;
 
-    info!("Successfully loaded {count} levels", count = levels.len());
-    Ok(LevelsResource {
-        levels,
-        current_level_index: 0,
-    })
+    for (path, relative_path, format) in files {
+        info!(
+            "Loading level file: {file_name}",
+            file_name = relative_path.display()
+        );
+        let result = fs::read(&path)
+            .map_err(|err| LevelLoadFailure::new(&relative_path, LevelLoadErrorKind::Io, err))
+            .and_then(|bytes| parse_level_bytes(&bytes, format, &relative_path));
+        report.record(result);
+    }
+
+    make_names_unique(&mut report.loaded);
+    report.loaded = order_levels(report.loaded, read_manifest(levels_dir), sort_mode);
+    Ok(report)
 }
 
 /// System to handle left/right arrow key input for level cycling
@@ -382,72 +976,179 @@ pub fn level_cycling_input_system(
 
     if keyboard_input.just_pressed(KeyCode::ArrowLeft) {
         // Cycle to previous level (with wraparound)
-        let new_index = if levels_resource.current_level_index == 0 {
-            level_count - 1
-        } else {
-            levels_resource.current_level_index - 1
-        };
-
-        let old_level_name = levels_resource.current_level().name.clone();
-        levels_resource.current_level_index = new_index;
-        let new_level_name = &levels_resource.current_level().name;
-
-        info!(
-            "Level cycling: Previous (←) - switched from '{old_name}' to '{new_name}' (index {new_index})",
-            old_name = old_level_name,
-            new_name = new_level_name
-        );
+        let new_index = (levels_resource.current_level_index + level_count - 1) % level_count;
+        levels_resource.set_current_index(new_index);
     }
 
     if keyboard_input.just_pressed(KeyCode::ArrowRight) {
         // Cycle to next level (with wraparound)
         let new_index = (levels_resource.current_level_index + 1) % level_count;
+        levels_resource.set_current_index(new_index);
+    }
+}
 
-        let old_level_name = levels_resource.current_level().name.clone();
-        levels_resource.current_level_index = new_index;
-        let new_level_name = &levels_resource.current_level().name;
-
-        info!(
-            "Level cycling: Next (→) - switched from '{old_name}' to '{new_name}' (index {new_index})",
-            old_name = old_level_name,
-            new_name = new_level_name
-        );
+/// Environment variable overriding the levels directory
+pub const LEVELS_DIR_ENV: &str = "SYSTEMTACTICS_LEVELS_DIR";
+
+/// Launch argument overriding the levels directory, as `--levels-dir <path>`
+/// or `--levels-dir=<path>`
+pub const LEVELS_DIR_ARG: &str = "--levels-dir";
+
+/// Launch argument naming the level to start on, as `--level <name>` or
+/// `--level=<name>`
+pub const STARTING_LEVEL_ARG: &str = "--level";
+
+/// Value of the last `flag <value>` or `flag=<value>` launch argument
+fn launch_arg_value(args: impl IntoIterator<Item = String>, flag: &str) -> Option<String> {
+    let mut args = args.into_iter();
+    let mut value = None;
+    while let Some(arg) = args.next() {
+        if arg == flag {
+            value = args.next();
+        } else if let Some(inline) = arg
+            .strip_prefix(flag)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            value = Some(inline.to_string());
+        }
     }
+    value
+}
+
+/// Pick the levels directory override from launch arguments or the environment
+///
+/// The launch argument wins over [`LEVELS_DIR_ENV`]. Empty values are ignored.
+pub fn levels_dir_override(
+    args: impl IntoIterator<Item = String>,
+    env_value: Option<String>,
+) -> Option<PathBuf> {
+    launch_arg_value(args, LEVELS_DIR_ARG)
+        .or(env_value)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Pick the starting level name from the [`STARTING_LEVEL_ARG`] launch argument
+pub fn starting_level_arg(args: impl IntoIterator<Item = String>) -> Option<String> {
+    launch_arg_value(args, STARTING_LEVEL_ARG).filter(|name| !name.is_empty())
+}
+
+/// Levels directory chosen at launch, loaded instead of the asset folder
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct LevelDirectoryOverride(pub PathBuf);
+
+/// Levels directory on disk: the launch override, or [`LEVELS_DIRECTORY`]
+pub fn levels_directory(directory_override: Option<&LevelDirectoryOverride>) -> PathBuf {
+    directory_override.map_or_else(|| PathBuf::from(LEVELS_DIRECTORY), |dir| dir.0.clone())
 }
 
 /// Plugin for level geometry creation
-pub struct LevelPlugin;
+#[derive(Debug, Clone, Default)]
+pub struct LevelPlugin {
+    /// Directory to load levels from instead of the asset folder
+    levels_dir: Option<PathBuf>,
+    /// Name of the level to show once levels have loaded
+    starting_level: Option<String>,
+    /// Order of the loaded levels
+    sort_mode: LevelSortMode,
+    /// Level shown until level files load, or if none do
+    default_level: Option<DefaultLevelConfig>,
+    /// Check loaded level files for edits made outside the app (native only)
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    watch_external_changes: bool,
+    /// Save the current level on Ctrl+S (native only)
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    save_shortcut: bool,
+}
+
+impl LevelPlugin {
+    /// Load levels from `levels_dir` instead of the default asset folder
+    pub fn with_directory(levels_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            levels_dir: Some(levels_dir.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Start on the level named `name` instead of the first one
+    pub fn starting_level(mut self, name: impl Into<String>) -> Self {
+        self.starting_level = Some(name.into());
+        self
+    }
+
+    /// Order the loaded levels by `sort_mode`
+    pub fn sort_mode(mut self, sort_mode: LevelSortMode) -> Self {
+        self.sort_mode = sort_mode;
+        self
+    }
+
+    /// Fall back to the level described by `config` instead of the demo gradient
+    pub fn default_level(mut self, config: DefaultLevelConfig) -> Self {
+        self.default_level = Some(config);
+        self
+    }
+
+    /// Report level files edited outside the app as `LevelFileChangedOnDisk`
+    /// events; ignored on WASM, which has no level files
+    pub fn watch_external_changes(mut self) -> Self {
+        self.watch_external_changes = true;
+        self
+    }
+
+    /// Save the current level to its file on Ctrl+S, reporting the outcome as
+    /// `LevelSaved` or `LevelSaveFailed` events; ignored on WASM
+    pub fn save_shortcut(mut self) -> Self {
+        self.save_shortcut = true;
+        self
+    }
+
+    /// Configure the levels directory from `--levels-dir` or [`LEVELS_DIR_ENV`],
+    /// and the starting level from `--level`
+    pub fn from_launch_options() -> Self {
+        Self {
+            levels_dir: levels_dir_override(std::env::args(), std::env::var(LEVELS_DIR_ENV).ok()),
+            starting_level: starting_level_arg(std::env::args()),
+            ..Default::default()
+        }
+    }
+}
 
 impl Plugin for LevelPlugin {
     fn build(&self, app: &mut App) {
-        // Load levels differently for native vs WASM builds
-        let levels_resource = {
-            #[cfg(not(target_arch = "wasm32"))]
-            {
-                // Native: Load from file system
-                match load_levels_from_assets() {
-                    Ok(levels) => {
-                        info!(
-                            "LevelPlugin: Successfully loaded {count} levels from assets",
-                            count = levels.level_count()
-                        );
-                        levels
-                    }
-                    Err(err) => {
-                        warn!("LevelPlugin: Failed to load levels from assets: {err}");
-                        warn!("LevelPlugin: Using fallback default level");
-                        LevelsResource::with_default()
-                    }
-                }
+        if let Some(levels_dir) = &self.levels_dir {
+            if levels_dir.is_dir() {
+                info!(
+                    "LevelPlugin: Loading levels from {path}",
+                    path = levels_dir.display()
+                );
+                app.insert_resource(LevelDirectoryOverride(levels_dir.clone()));
+            } else {
+                warn!(
+                    "LevelPlugin: Levels directory {path} does not exist, using the default levels",
+                    path = levels_dir.display()
+                );
             }
+        }
 
-            #[cfg(target_arch = "wasm32")]
-            {
-                // WASM: Create levels resource with embedded asset data
-                info!("LevelPlugin: Loading levels from embedded assets for WASM");
-                create_levels_from_embedded_assets()
+        if let Some(name) = &self.starting_level {
+            app.insert_resource(StartingLevel(name.clone()));
+        }
+
+        // Level files load through the asset server; show the default level until then
+        #[cfg(target_arch = "wasm32")]
+        {
+            // WASM has no file system, so serve the embedded level files as assets
+            use bevy::asset::io::embedded::EmbeddedAssetRegistry;
+            let registry = app.world().resource::<EmbeddedAssetRegistry>();
+            for (filename, content) in EMBEDDED_LEVELS {
+                let path = std::path::Path::new("levels").join(filename);
+                registry.insert_asset(path.clone(), &path, content);
             }
-        };
+            if let Some(manifest) = EMBEDDED_LEVEL_MANIFEST {
+                let path = std::path::Path::new("levels").join(LEVEL_MANIFEST_FILE);
+                registry.insert_asset(path.clone(), &path, manifest.as_bytes());
+            }
+        }
 
         // Add wireframe plugin only for native builds (WASM doesn't support POLYGON_MODE_LINE)
         #[cfg(not(target_arch = "wasm32"))]
@@ -459,9 +1160,83 @@ impl Plugin for LevelPlugin {
                 default_color: HEX_EDGE_GREEN,
             });
 
-        app.insert_resource(levels_resource)
-            .add_systems(Startup, spawn_hex_grid)
-            .add_systems(Update, (level_cycling_input_system, level_switching_system));
+        let default_level = self.default_level.clone().unwrap_or_default();
+        app.insert_resource(LevelsResource::with_config(&default_level))
+            .insert_resource(default_level)
+            .insert_resource(self.sort_mode)
+            .init_resource::<LevelLoadReport>()
+            .init_asset::<LevelAsset>()
+            .init_asset_loader::<LevelAssetLoader>()
+            .add_event::<HexEntered>()
+            .add_event::<HexTriggerFired>()
+            .add_event::<LevelFileRejected>()
+            .add_event::<RebuildChunk>()
+            // Normally added by WindowPlugin; registered here for headless apps
+            .add_event::<bevy::window::FileDragAndDrop>()
+            .init_resource::<FiredTriggers>()
+            .init_resource::<TerrainColoring>()
+            .init_resource::<HexMeshOptions>()
+            .init_resource::<HexEdgeStyle>()
+            .init_resource::<HexMaterials>()
+            .init_resource::<HexGridRenderConfig>()
+            .add_systems(Startup, (spawn_hex_grid, request_level_assets))
+            .add_systems(
+                Update,
+                (
+                    level_asset_loading_system,
+                    level_hot_reload_system,
+                    level_cycling_input_system,
+                    dropped_level_file_system,
+                    level_switching_system,
+                    chunk_rebuild_system.after(level_switching_system),
+                    hex_trigger_system,
+                    surface_texture_toggle_system,
+                    apply_surface_material_system.after(surface_texture_toggle_system),
+                ),
+            );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_systems(
+            Update,
+            render_config::edge_style_toggle_system.before(level_switching_system),
+        );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.watch_external_changes {
+            app.init_resource::<external::LevelFileStamps>()
+                .add_event::<external::LevelFileChangedOnDisk>()
+                .add_systems(Update, external::external_change_check_system);
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if self.save_shortcut {
+            app.add_event::<saving::LevelSaved>()
+                .add_event::<saving::LevelSaveFailed>()
+                .add_systems(Update, saving::level_save_input_system);
+        }
+
+        // Web builds replace the embedded levels with hosted ones when reachable,
+        // and keep the selected level and unsaved edits across page reloads
+        #[cfg(target_arch = "wasm32")]
+        app.insert_resource(persistence::load_persisted_state())
+            .add_systems(Startup, remote::request_remote_levels)
+            .add_systems(
+                Update,
+                // Recording before the hosted swap keeps edits made on its frame
+                (
+                    persistence::record_level_state_system,
+                    persistence::clear_persisted_state_input_system,
+                    remote::remote_level_loading_system,
+                    persistence::persist_level_state_system,
+                )
+                    .chain(),
+            );
+
+        // Browsers do not report dropped files to Bevy, so listen on the page
+        #[cfg(target_arch = "wasm32")]
+        app.init_resource::<drop::BrowserDropQueue>()
+            .add_systems(Startup, drop::install_browser_drop_listener)
+            .add_systems(Update, drop::browser_dropped_level_system);
 
         info!("LevelPlugin: Plugin setup completed");
     }
@@ -470,8 +1245,209 @@ impl Plugin for LevelPlugin {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::level::builder::LevelBuilder;
     use tempfile::TempDir;
 
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_levels_dir_override_sources() {
+        assert_eq!(levels_dir_override(args(&["game"]), None), None);
+        assert_eq!(
+            levels_dir_override(args(&["game"]), Some("/maps/env".to_string())),
+            Some(PathBuf::from("/maps/env"))
+        );
+        assert_eq!(
+            levels_dir_override(
+                args(&["game", "--levels-dir", "/maps/arg"]),
+                Some("/maps/env".to_string())
+            ),
+            Some(PathBuf::from("/maps/arg")),
+            "Launch argument wins over the environment"
+        );
+        assert_eq!(
+            levels_dir_override(args(&["game", "--levels-dir=custom"]), None),
+            Some(PathBuf::from("custom"))
+        );
+        assert_eq!(
+            levels_dir_override(args(&["game"]), Some(String::new())),
+            None
+        );
+    }
+
+    /// Run the level plugin's startup in an app without rendering
+    fn startup_with(plugin: LevelPlugin) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Shader>()
+            .init_asset::<Mesh>()
+            .init_asset::<StandardMaterial>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_plugins(plugin);
+        app.update();
+        app
+    }
+
+    #[test]
+    fn test_plugin_with_directory_loads_levels_from_it() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        LevelBuilder::new()
+            .name("Custom Map")
+            .dimensions(4, 4)
+            .build()
+            .unwrap()
+            .save_to_directory(temp_path, "custom.toml")
+            .expect("Failed to save level");
+        fs::write(temp_dir.path().join("broken.toml"), "width = ").unwrap();
+
+        let app = startup_with(LevelPlugin::with_directory(temp_dir.path()));
+        let levels = app.world().resource::<LevelsResource>();
+        assert_eq!(levels.level_count(), 1);
+        assert_eq!(levels.current_level().name, "Custom Map");
+        let report = app.world().resource::<LevelLoadReport>();
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].path, Path::new("broken.toml"));
+
+        let missing = startup_with(LevelPlugin::with_directory(temp_dir.path().join("missing")));
+        assert!(
+            missing
+                .world()
+                .get_resource::<LevelDirectoryOverride>()
+                .is_none(),
+            "Nonexistent override falls back to the default levels"
+        );
+    }
+
+    #[test]
+    fn test_plugin_starts_on_requested_level() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        for (name, file) in [("Alpha", "alpha.toml"), ("Bravo", "bravo.toml")] {
+            Level::new(name.to_string(), 3, 3)
+                .save_to_directory(temp_path, file)
+                .expect("Failed to save level");
+        }
+        assert_eq!(
+            starting_level_arg(args(&["game", "--level", "Bravo"])),
+            Some("Bravo".to_string())
+        );
+
+        let app =
+            startup_with(LevelPlugin::with_directory(temp_dir.path()).starting_level("Bravo"));
+        assert_eq!(
+            app.world()
+                .resource::<LevelsResource>()
+                .current_level()
+                .name,
+            "Bravo"
+        );
+
+        let unknown =
+            startup_with(LevelPlugin::with_directory(temp_dir.path()).starting_level("Zulu"));
+        assert_eq!(
+            unknown
+                .world()
+                .resource::<LevelsResource>()
+                .current_level()
+                .name,
+            "Alpha",
+            "Unknown starting level keeps the first level"
+        );
+    }
+
+    #[test]
+    fn test_plugin_falls_back_to_configured_default_level() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let canvas = DefaultLevelConfig {
+            name: "Canvas".to_string(),
+            width: 20,
+            height: 20,
+            generator: HeightGenerator::Flat(1.0),
+        };
+
+        let app = startup_with(
+            LevelPlugin::with_directory(temp_dir.path()).default_level(canvas.clone()),
+        );
+        let levels = app.world().resource::<LevelsResource>();
+        assert_eq!(levels.level_count(), 1);
+        let level = levels.current_level();
+        assert_eq!(level.name, "Canvas");
+        assert_eq!((level.width, level.height), (20, 20));
+        assert!(level.heights.iter().all(|&height| height == 1.0));
+        assert_eq!(*app.world().resource::<DefaultLevelConfig>(), canvas);
+
+        let unconfigured = startup_with(LevelPlugin::with_directory(temp_dir.path()));
+        let level = unconfigured
+            .world()
+            .resource::<LevelsResource>()
+            .current_level()
+            .clone();
+        assert_eq!(level.name, "Default Level");
+        assert_eq!(
+            level.heights,
+            LevelsResource::with_default().current_level().heights,
+            "Without a config the demo gradient is kept"
+        );
+    }
+
+    #[test]
+    fn test_embedded_levels_match_assets_directory() {
+        let levels_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../assets/levels");
+        let mut files = Vec::new();
+        collect_level_files(&levels_dir, Path::new(""), 0, &mut files)
+            .expect("Failed to scan assets/levels");
+        let mut on_disk: Vec<_> = files
+            .iter()
+            .map(|(_, relative_path, _)| relative_path.to_string_lossy().replace('\\', "/"))
+            .collect();
+        on_disk.sort();
+
+        let embedded: Vec<_> = EMBEDDED_LEVELS.iter().map(|(path, _)| *path).collect();
+        assert_eq!(embedded, on_disk, "Embedded list is sorted and complete");
+        for (path, content) in EMBEDDED_LEVELS {
+            assert_eq!(content, fs::read(levels_dir.join(path)).unwrap().as_slice());
+        }
+        assert_eq!(
+            EMBEDDED_LEVEL_MANIFEST,
+            fs::read_to_string(levels_dir.join(LEVEL_MANIFEST_FILE))
+                .ok()
+                .as_deref(),
+            "The manifest is embedded when present"
+        );
+    }
+
+    #[test]
+    fn test_embedded_levels_follow_manifest_order() {
+        let alpha = toml::to_string(&Level::new("Alpha".to_string(), 3, 3)).unwrap();
+        let bravo = toml::to_string(&Level::new("Bravo".to_string(), 3, 3)).unwrap();
+        let files: [(&str, &[u8]); 2] = [
+            ("alpha.toml", alpha.as_bytes()),
+            ("bravo.toml", bravo.as_bytes()),
+        ];
+        let names = |manifest| {
+            embedded_level_report(&files, manifest, LevelSortMode::default())
+                .loaded
+                .into_iter()
+                .map(|level| level.name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(None), ["Alpha", "Bravo"]);
+        assert_eq!(
+            names(Some("[[levels]]\nfile = \"bravo.toml\"\n")),
+            ["Bravo", "Alpha"],
+            "Manifest order comes first"
+        );
+        assert_eq!(
+            names(Some("not a manifest")),
+            ["Alpha", "Bravo"],
+            "A broken manifest is ignored"
+        );
+    }
+
     #[test]
     fn test_level_save_and_load_roundtrip() {
         // Create a temporary directory for this test
@@ -479,21 +1455,40 @@ mod tests {
         let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
 
         // Create test levels
-        let default_level = Level::new("Default Level".to_string(), 10, 10);
-        let small_level = Level::new("Small Test Level".to_string(), 5, 5);
-        let large_level = Level::new("Large Test Level".to_string(), 15, 15);
+        let default_level = LevelBuilder::new()
+            .name("Default Level")
+            .dimensions(10, 10)
+            .gradient(1.0, 4.0)
+            .build()
+            .expect("Failed to build default level");
+        let small_level = LevelBuilder::new()
+            .name("Small Test Level")
+            .dimensions(5, 5)
+            .build()
+            .expect("Failed to build small level");
+        let large_level = LevelBuilder::new()
+            .name("Large Test Level")
+            .dimensions(15, 15)
+            .build()
+            .expect("Failed to build large level");
 
         // Save all levels to TOML files in the temporary directory
         default_level
             .save_to_directory(temp_path, "default.toml")
             .expect("Failed to save default level");
         small_level
-            .save_to_directory(temp_path, "test_small.toml")
+            .save_to_directory(temp_path, "skirmish/test_small.toml")
             .expect("Failed to save small level");
         large_level
-            .save_to_directory(temp_path, "test_large.toml")
+            .save_to_directory(temp_path, "campaign1/act1/test_large.toml")
             .expect("Failed to save large level");
 
+        // Hidden directories and non-level files are skipped
+        small_level
+            .save_to_directory(temp_path, ".drafts/hidden.toml")
+            .expect("Failed to save hidden level");
+        fs::write(temp_dir.path().join("skirmish/notes.txt"), "not a level").unwrap();
+
         // Load levels back from the temporary directory
         let levels_resource = load_levels_from_directory(temp_path)
             .expect("Failed to load levels from temp directory");
@@ -524,6 +1519,17 @@ mod tests {
             "Should contain Large Test Level"
         );
 
+        // Nested levels remember where they came from so they can be saved back
+        let loaded_large = levels_resource
+            .levels
+            .iter()
+            .find(|l| l.name == "Large Test Level")
+            .expect("Large level should be found");
+        assert_eq!(
+            loaded_large.source_path.as_deref(),
+            Some(Path::new("campaign1/act1/test_large.toml"))
+        );
+
         // Test one specific level for correctness (default level)
         let loaded_default = levels_resource
             .levels
@@ -556,19 +1562,488 @@ mod tests {
     }
 
     #[test]
-    fn test_levels_resource_current_level() {
-        let level1 = Level::new("Level 1".to_string(), 5, 5);
-        let level2 = Level::new("Level 2".to_string(), 7, 7);
+    fn test_save_replaces_existing_file_atomically() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        fs::write(temp_dir.path().join("arena.toml"), "stale content").unwrap();
+
+        let level = Level::new("Arena".to_string(), 3, 3);
+        let saved_path = level
+            .save_to_directory(temp_path, "arena.toml")
+            .expect("Overwriting should be allowed by default");
+
+        assert_eq!(saved_path, temp_dir.path().join("arena.toml"));
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(loaded.current_level().name, "Arena");
+        let mut leftovers: Vec<_> = fs::read_dir(temp_dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        leftovers.sort();
+        assert_eq!(
+            leftovers,
+            ["arena.toml", "arena.toml.bak"],
+            "No temporary file is left behind"
+        );
+    }
 
-        let levels_resource = LevelsResource {
-            levels: vec![level1, level2],
-            current_level_index: 0,
+    #[test]
+    fn test_save_without_overwrite_refuses_existing_file() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        fs::write(temp_dir.path().join("arena.toml"), "stale content").unwrap();
+        let save_new = SaveOptions {
+            overwrite: false,
+            ..Default::default()
         };
 
+        let level = Level::new("Arena".to_string(), 3, 3);
+        let error = level
+            .save_to_directory_with(temp_path, "arena.toml", &save_new)
+            .expect_err("Existing file must not be clobbered");
+        assert!(error.to_string().contains("already exists"), "{error}");
+        assert_eq!(
+            fs::read_to_string(temp_dir.path().join("arena.toml")).unwrap(),
+            "stale content"
+        );
+
+        let saved_path = level
+            .save_to_directory_with(temp_path, "arena_copy.toml", &save_new)
+            .expect("New files can still be saved");
+        assert!(saved_path.ends_with("arena_copy.toml"));
+    }
+
+    #[test]
+    fn test_levels_resource_current_level() {
+        let level1 = LevelBuilder::new()
+            .name("Level 1")
+            .dimensions(5, 5)
+            .build()
+            .expect("Failed to build level 1");
+        let level2 = LevelBuilder::new()
+            .name("Level 2")
+            .dimensions(7, 7)
+            .build()
+            .expect("Failed to build level 2");
+
+        let levels_resource = LevelsResource::new(vec![level1, level2]);
+
         assert_eq!(levels_resource.current_level().name, "Level 1");
         assert_eq!(levels_resource.level_count(), 2);
     }
 
+    #[test]
+    fn test_walkability_defaults_to_walkable_for_old_files() {
+        // Level file written before walkability flags existed
+        let content = r#"
+name = "Legacy Level"
+width = 2
+height = 2
+
+[heights]
+v = 1
+dim = [2, 2]
+data = [1.0, 2.0, 3.0, 4.0]
+"#;
+        let level: Level = toml::from_str(content).expect("Legacy level should parse");
+
+        assert!(level.walkable.is_empty(), "Legacy level has no grid");
+        for hex in level.hex_iter() {
+            assert!(
+                level.is_walkable(hex),
+                "Hex {hex:?} should default to walkable"
+            );
+        }
+        assert!(
+            !level.is_walkable(Hex::new(2, 0)),
+            "Out-of-bounds hex should not be walkable"
+        );
+    }
+
+    #[test]
+    fn test_walkability_save_and_load_roundtrip() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let mut level = Level::new("Walls".to_string(), 4, 3);
+        level
+            .set_walkable(Hex::new(1, 2), false)
+            .expect("In-bounds hex should be settable");
+        assert!(
+            level.set_walkable(Hex::new(4, 0), false).is_err(),
+            "Out-of-bounds hex should be rejected"
+        );
+
+        level
+            .save_to_directory(temp_path, "walls.toml")
+            .expect("Failed to save level");
+        let levels_resource = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let loaded = levels_resource.current_level();
+
+        assert_eq!(loaded.walkable.shape(), &[3, 4]);
+        assert!(
+            !loaded.is_walkable(Hex::new(1, 2)),
+            "Wall should stay impassable"
+        );
+        assert!(
+            loaded.is_walkable(Hex::new(2, 1)),
+            "Other hexes stay walkable"
+        );
+    }
+
+    #[test]
+    fn test_negative_heights_and_water_level_in_bounds() {
+        let mut level = LevelBuilder::new()
+            .name("Trench")
+            .dimensions(3, 3)
+            .with_height(Hex::new(1, 1), -2.5)
+            .build()
+            .expect("Failed to build trench level");
+
+        assert!((level.column_base() - -2.5).abs() < 0.001);
+        let (min_bounds, max_bounds) = level.get_world_bounds();
+        assert!(
+            (min_bounds.y - -2.5).abs() < 0.001,
+            "Trench floor sets min Y"
+        );
+        assert!((max_bounds.y - 4.0).abs() < 0.001);
+
+        let dry_diagonal = level.get_level_diagonal_extent();
+        level.water_level = Some(6.0);
+        let (_, flooded_max) = level.get_world_bounds();
+        assert!(
+            (flooded_max.y - 6.0).abs() < 0.001,
+            "Water plane sets max Y"
+        );
+        assert!(level.get_level_diagonal_extent() > dry_diagonal);
+    }
+
+    #[test]
+    fn test_water_level_save_and_load_roundtrip() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let mut level = LevelBuilder::new()
+            .name("Lake")
+            .dimensions(3, 3)
+            .with_height(Hex::new(0, 0), -1.0)
+            .build()
+            .expect("Failed to build lake level");
+        level.water_level = Some(-0.5);
+        level
+            .save_to_directory(temp_path, "lake.toml")
+            .expect("Failed to save level");
+
+        let levels_resource = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let loaded = levels_resource.current_level();
+        assert_eq!(loaded.water_level, Some(-0.5));
+        assert!((loaded.heights[(0, 0)] - -1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_old_files_without_metadata_round_trip() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        // Level file written before metadata fields existed
+        let content = r#"
+name = "Legacy Level"
+width = 2
+height = 2
+
+[heights]
+v = 1
+dim = [2, 2]
+data = [1.0, 2.0, 3.0, 4.0]
+"#;
+        let legacy: Level = toml::from_str(content).expect("Legacy level should parse");
+        assert_eq!(legacy.format_version, 0, "Missing version means format v0");
+        assert_eq!(legacy.author, None);
+        assert_eq!(legacy.description, None);
+
+        legacy
+            .save_to_directory(temp_path, "legacy.toml")
+            .expect("Failed to save legacy level");
+        let levels_resource = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let loaded = levels_resource.current_level();
+
+        assert_eq!(loaded.name, "Legacy Level");
+        assert_eq!(loaded.author, None);
+        assert_eq!(loaded.description, None);
+        assert_eq!(loaded.heights, legacy.heights);
+    }
+
+    #[test]
+    fn test_metadata_save_and_load_roundtrip() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let mut level = Level::new("Annotated".to_string(), 3, 3);
+        level.author = Some("Eren".to_string());
+        level.description = Some("A small hill for testing".to_string());
+        assert_eq!(level.format_version, CURRENT_LEVEL_FORMAT);
+
+        level
+            .save_to_directory(temp_path, "annotated.toml")
+            .expect("Failed to save level");
+        let levels_resource = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let loaded = levels_resource.current_level();
+
+        assert_eq!(loaded.format_version, CURRENT_LEVEL_FORMAT);
+        assert_eq!(loaded.author.as_deref(), Some("Eren"));
+        assert_eq!(
+            loaded.description.as_deref(),
+            Some("A small hill for testing")
+        );
+    }
+
+    #[test]
+    fn test_set_height_on_corner_hexes() {
+        let mut level = Level::new("Editable".to_string(), 4, 3);
+        let corners = [
+            Hex::new(0, 0),
+            Hex::new(3, 0),
+            Hex::new(0, 2),
+            Hex::new(3, 2),
+        ];
+
+        for (i, hex) in corners.into_iter().enumerate() {
+            let new_height = 10.0 + i as f32;
+            level
+                .set_height(hex, new_height)
+                .expect("Corner hex should be settable");
+            assert!((level.get_height(hex) - new_height).abs() < 0.001);
+        }
+        assert_eq!(level.heights.shape(), &[3, 4], "Shape must not change");
+    }
+
+    #[test]
+    fn test_set_height_rejects_out_of_bounds() {
+        let mut level = Level::new("Editable".to_string(), 4, 3);
+        let original = level.heights.clone();
+
+        for hex in [
+            Hex::new(-1, 0),
+            Hex::new(0, -1),
+            Hex::new(4, 0),
+            Hex::new(0, 3),
+        ] {
+            assert!(
+                level.set_height(hex, 2.0).is_err(),
+                "Hex {hex:?} should be rejected"
+            );
+        }
+        assert!(
+            level
+                .fill_region(&[Hex::new(1, 1), Hex::new(9, 9)], 2.0)
+                .is_err(),
+            "Region with an out-of-bounds hex should be rejected"
+        );
+        assert_eq!(
+            level.heights, original,
+            "Failed edits must not modify heights"
+        );
+    }
+
+    #[test]
+    fn test_set_height_rejects_non_finite_values() {
+        let mut level = Level::new("Editable".to_string(), 4, 3);
+        let original = level.heights.clone();
+
+        assert!(level.set_height(Hex::new(1, 1), f32::NAN).is_err());
+        assert!(level.set_height(Hex::new(1, 1), f32::INFINITY).is_err());
+        assert!(
+            level
+                .fill_region(&[Hex::new(0, 0), Hex::new(1, 1)], f32::NAN)
+                .is_err()
+        );
+        assert_eq!(
+            level.heights, original,
+            "Failed edits must not modify heights"
+        );
+    }
+
+    #[test]
+    fn test_fill_region_through_levels_resource() {
+        let mut levels_resource =
+            LevelsResource::new(vec![Level::new("Editable".to_string(), 4, 3)]);
+        let region = [Hex::new(0, 0), Hex::new(1, 0), Hex::new(1, 1)];
+        assert!(!levels_resource.is_modified(0));
+
+        levels_resource
+            .current_level_mut()
+            .fill_region(&region, 7.5)
+            .expect("In-bounds region should be filled");
+        assert!(levels_resource.is_modified(0));
+        levels_resource.mark_saved(0);
+        assert!(!levels_resource.is_modified(0));
+
+        let level = levels_resource.current_level();
+        for hex in region {
+            assert!((level.get_height(hex) - 7.5).abs() < 0.001);
+        }
+        assert!((level.get_height(Hex::new(2, 2)) - 7.5).abs() > 0.001);
+    }
+
+    #[test]
+    fn test_contains_and_try_get_height_bounds() {
+        let level = Level::new("Bounds".to_string(), 4, 3);
+
+        // Negative coordinates
+        assert!(!level.contains(Hex::new(-1, 0)));
+        assert!(!level.contains(Hex::new(0, -1)));
+        assert_eq!(level.try_get_height(Hex::new(-1, -1)), None);
+
+        // Coordinates exactly at width/height are one past the last hex
+        assert!(!level.contains(Hex::new(4, 0)));
+        assert!(!level.contains(Hex::new(0, 3)));
+        assert_eq!(level.try_get_height(Hex::new(4, 2)), None);
+        assert_eq!(level.try_get_height(Hex::new(3, 3)), None);
+
+        // Last valid hex
+        assert!(level.contains(Hex::new(3, 2)));
+        assert_eq!(
+            level.try_get_height(Hex::new(3, 2)),
+            Some(level.heights[(2, 3)])
+        );
+
+        // Compatibility fallback stays explicit
+        assert_eq!(level.get_height(Hex::new(4, 0)), 0.0);
+    }
+
+    #[test]
+    fn test_hex_iter_matches_legacy_grid_order() {
+        let level = LevelBuilder::new().dimensions(4, 3).build().unwrap();
+
+        // Reference implementation of the original Vec-building loop
+        let mut expected = Vec::new();
+        for q in 0..level.width {
+            for r in 0..level.height {
+                expected.push(Hex::new(q, r));
+            }
+        }
+
+        assert_eq!(level.hex_iter().collect::<Vec<_>>(), expected);
+        assert_eq!(level.get_hex_grid(), expected);
+        assert_eq!(level.hex_iter().count(), 12);
+    }
+
+    #[test]
+    fn test_world_bounds_scale_linearly_with_hex_scale() {
+        let base = LevelBuilder::new().dimensions(6, 4).build().unwrap();
+        let half = LevelBuilder::new()
+            .dimensions(6, 4)
+            .hex_scale(0.5)
+            .build()
+            .unwrap();
+
+        let (base_min, base_max) = base.get_world_bounds();
+        let (half_min, half_max) = half.get_world_bounds();
+
+        // Horizontal extents scale with the hex size; heights do not
+        assert!((half_min.x - base_min.x * 0.5).abs() < 0.0001);
+        assert!((half_max.x - base_max.x * 0.5).abs() < 0.0001);
+        assert!((half_max.z - base_max.z * 0.5).abs() < 0.0001);
+        assert_eq!(half_min.y, base_min.y);
+        assert_eq!(half_max.y, base_max.y);
+
+        let base_center = base.get_center_world_pos();
+        let half_center = half.get_center_world_pos();
+        assert!((half_center.x - base_center.x * 0.5).abs() < 0.0001);
+        assert!((half_center.z - base_center.z * 0.5).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_world_bounds_depend_on_orientation() {
+        let pointy = LevelBuilder::new()
+            .dimensions(6, 4)
+            .flat(1.0)
+            .build()
+            .unwrap();
+        let flat = LevelBuilder::new()
+            .dimensions(6, 4)
+            .flat(1.0)
+            .orientation(HexOrientation::Flat)
+            .build()
+            .unwrap();
+
+        let (pointy_min, pointy_max) = pointy.get_world_bounds();
+        let (flat_min, flat_max) = flat.get_world_bounds();
+        let pointy_size = pointy_max - pointy_min;
+        let flat_size = flat_max - flat_min;
+
+        // Along q, pointy hexes are sqrt(3) apart and flat hexes 1.5 apart
+        let sqrt3 = 3.0_f32.sqrt();
+        assert!((pointy.hex_layout().hex_to_world_pos(Hex::new(1, 0)).x - sqrt3).abs() < 0.0001);
+        assert!((flat.hex_layout().hex_to_world_pos(Hex::new(1, 0)).x - 1.5).abs() < 0.0001);
+        assert!((pointy_size.x - flat_size.x).abs() > 0.1);
+        assert!((pointy_size.z - flat_size.z).abs() > 0.1);
+        assert_eq!(pointy_size.y, flat_size.y);
+
+        // Adjacent flat columns must not overlap: center spacing is at least sqrt(3) * radius
+        let layout = flat.hex_layout();
+        for neighbor in flat.neighbors(Hex::new(2, 2)) {
+            let spacing = layout
+                .hex_to_world_pos(neighbor)
+                .distance(layout.hex_to_world_pos(Hex::new(2, 2)));
+            assert!((spacing - sqrt3).abs() < 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_world_to_hex_near_boundaries_and_corners() {
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            let level = LevelBuilder::new()
+                .dimensions(5, 4)
+                .hex_scale(1.5)
+                .orientation(orientation)
+                .build()
+                .unwrap();
+            let layout = level.hex_layout();
+
+            for hex in level.hex_iter() {
+                let center = layout.hex_to_world_pos(hex);
+                assert_eq!(level.world_to_hex(center), Some(hex));
+                // Just inside each corner still resolves to the same hex
+                for corner in layout.hex_corners(hex) {
+                    let near_corner = center + (corner - center) * 0.95;
+                    assert_eq!(level.world_to_hex(near_corner), Some(hex));
+                }
+            }
+
+            // Past the outer corner of the map
+            let origin = layout.hex_to_world_pos(Hex::new(0, 0));
+            let beyond = origin + (origin - layout.hex_to_world_pos(Hex::new(1, 1))) * 2.0;
+            assert_eq!(level.world_to_hex(beyond), None);
+            assert_eq!(level.world_to_hex_clamped(beyond), Hex::new(0, 0));
+
+            let far_corner = layout.hex_to_world_pos(Hex::new(4, 3));
+            let beyond = far_corner + (far_corner - origin) * 0.5;
+            assert_eq!(level.world_to_hex_clamped(beyond), Hex::new(4, 3));
+        }
+    }
+
+    #[test]
+    fn test_hex_scale_roundtrip_and_default() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let level = LevelBuilder::new()
+            .name("Dense")
+            .hex_scale(0.5)
+            .build()
+            .unwrap();
+        level
+            .save_to_directory(temp_path, "dense.toml")
+            .expect("Failed to save level");
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(loaded.current_level().hex_scale, 0.5);
+
+        let old_file = "name = \"Old\"\nwidth = 1\nheight = 1\n\n[heights]\nv = 1\ndim = [1, 1]\ndata = [1.0]\n";
+        let old: Level = toml::from_str(old_file).expect("Old file should parse");
+        assert_eq!(old.hex_scale, 1.0);
+    }
+
     #[test]
     fn test_fallback_to_default_when_no_files() {
         // Try to load from a nonexistent directory
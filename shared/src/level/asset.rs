@@ -0,0 +1,372 @@
+//! Level Assets
+//!
+//! Loads level files through Bevy's asset server instead of blocking file reads
+//! during plugin setup. The default level is shown immediately and the loaded
+//! levels are swapped into [`LevelsResource`] once the whole folder is ready,
+//! together with a [`LevelLoadReport`] of the files that failed.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AssetPath, LoadContext, LoadState, LoadedFolder};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use tracing::{info, warn};
+
+#[cfg(not(target_arch = "wasm32"))]
+use super::LEVELS_DIRECTORY;
+use super::backup::BACKUP_EXTENSION;
+use super::format::LevelFormat;
+use super::management::{DefaultLevelConfig, StartingLevel};
+use super::manifest::{LEVEL_MANIFEST_FILE, LevelManifest, REMOTE_LEVEL_MANIFEST_FILE};
+use super::naming::make_names_unique;
+use super::persistence::PersistedLevelState;
+use super::report::{LevelLoadErrorKind, LevelLoadFailure, LevelLoadReport};
+use super::sorting::{LevelSortMode, order_levels};
+use super::{Level, LevelDirectoryOverride, LevelsResource, load_level_report, parse_level_bytes};
+
+/// Asset folder holding the level files and optional manifest
+///
+/// Every file in it must be a level file, a backup, or the manifest: Bevy fails
+/// the whole folder load on a file no loader accepts, which triggers the
+/// synchronous fallback.
+#[cfg(not(target_arch = "wasm32"))]
+pub const LEVELS_ASSET_FOLDER: &str = "levels";
+
+/// Asset folder holding the level files, registered as embedded data for WASM
+#[cfg(target_arch = "wasm32")]
+pub const LEVELS_ASSET_FOLDER: &str = "embedded://levels";
+
+/// A file from the level folder
+///
+/// The manifest shares the `.toml` extension with level files, so one loader
+/// handles both.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub enum LevelAsset {
+    /// A parsed, migrated, and validated level file
+    Level(Box<Level>),
+    /// The folder's [`LEVEL_MANIFEST_FILE`]
+    Manifest(LevelManifest),
+    /// The [`REMOTE_LEVEL_MANIFEST_FILE`] naming the level files fetched by web
+    /// builds
+    RemoteManifest(LevelManifest),
+    /// A backup left by saving over a level file, which is not loaded
+    Backup,
+    /// A level file that could not be loaded, kept so the failure is reported
+    /// alongside the levels that did load
+    Failed(LevelLoadFailure),
+}
+
+/// Asset loader for `.toml`, `.ron`, and `.json` level files (optionally
+/// gzipped), their `.bak` backups, and the level manifest
+#[derive(Debug, Default)]
+pub struct LevelAssetLoader;
+
+impl AssetLoader for LevelAssetLoader {
+    type Asset = LevelAsset;
+    type Settings = ();
+    type Error = anyhow::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<LevelAsset> {
+        let extension = load_context.path().extension();
+        if extension.is_some_and(|extension| extension == BACKUP_EXTENSION) {
+            return Ok(LevelAsset::Backup);
+        }
+
+        let path = load_context.path().display().to_string();
+        let folder = AssetPath::parse(LEVELS_ASSET_FOLDER);
+        let relative_path = load_context
+            .path()
+            .strip_prefix(folder.path())
+            .unwrap_or(load_context.path());
+
+        let mut bytes = Vec::new();
+        if let Err(err) = reader.read_to_end(&mut bytes).await {
+            let failure = LevelLoadFailure::new(relative_path, LevelLoadErrorKind::Io, err);
+            return Ok(LevelAsset::Failed(failure));
+        }
+
+        if relative_path == Path::new(LEVEL_MANIFEST_FILE) {
+            let content = std::str::from_utf8(&bytes)
+                .with_context(|| format!("Level manifest {path} is not valid UTF-8"))?;
+            let manifest = LevelManifest::from_toml_str(content)
+                .with_context(|| format!("Failed to load level manifest {path}"))?;
+            return Ok(LevelAsset::Manifest(manifest));
+        }
+
+        if relative_path == Path::new(REMOTE_LEVEL_MANIFEST_FILE) {
+            let content = std::str::from_utf8(&bytes)
+                .with_context(|| format!("Level manifest {path} is not valid UTF-8"))?;
+            let manifest = LevelManifest::from_json_str(content)
+                .with_context(|| format!("Failed to load level manifest {path}"))?;
+            return Ok(LevelAsset::RemoteManifest(manifest));
+        }
+
+        let format = LevelFormat::from_path(load_context.path()).unwrap_or_default();
+        let level = match parse_level_bytes(&bytes, format, relative_path) {
+            Ok(level) => level,
+            Err(failure) => return Ok(LevelAsset::Failed(failure)),
+        };
+
+        info!(
+            "Loaded level asset '{level_name}' ({width}x{height}) from {path}",
+            level_name = level.name,
+            width = level.width,
+            height = level.height
+        );
+        Ok(LevelAsset::Level(Box::new(level)))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[
+            "toml",
+            "ron",
+            "json",
+            "toml.gz",
+            "ron.gz",
+            "json.gz",
+            BACKUP_EXTENSION,
+        ]
+    }
+}
+
+/// Handle to the level folder requested at startup
+#[derive(Resource, Debug)]
+pub struct LevelAssetFolder {
+    pub handle: Handle<LoadedFolder>,
+    /// Whether the loaded levels (or the fallback) were already swapped in
+    applied: bool,
+}
+
+impl LevelAssetFolder {
+    /// Whether the loaded levels (or the fallback) were already swapped in
+    pub fn is_applied(&self) -> bool {
+        self.applied
+    }
+}
+
+/// Startup system requesting every level file from the asset server
+///
+/// A [`LevelDirectoryOverride`] is outside the asset folder, so it is loaded
+/// directly from the file system instead.
+pub fn request_level_assets(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    directory_override: Option<Res<LevelDirectoryOverride>>,
+    settings: LevelLoadSettings,
+    mut levels_resource: ResMut<LevelsResource>,
+    mut load_report: ResMut<LevelLoadReport>,
+) {
+    if let Some(LevelDirectoryOverride(levels_dir)) = directory_override.as_deref() {
+        match load_level_report(&levels_dir.to_string_lossy(), *settings.sort_mode) {
+            Ok(report) => settings.swap_in_levels(report, &mut levels_resource, &mut load_report),
+            Err(err) => warn!(
+                "Failed to load levels from {path}: {err:#}",
+                path = levels_dir.display()
+            ),
+        }
+        return;
+    }
+
+    info!("Requesting level assets from '{LEVELS_ASSET_FOLDER}'");
+    commands.insert_resource(LevelAssetFolder {
+        handle: asset_server.load_folder(LEVELS_ASSET_FOLDER),
+        applied: false,
+    });
+}
+
+/// System swapping the loaded level assets into [`LevelsResource`] once the
+/// folder has finished loading
+///
+/// If the folder fails to load, the synchronous loaders are used instead.
+pub fn level_asset_loading_system(
+    asset_server: Res<AssetServer>,
+    folders: Res<Assets<LoadedFolder>>,
+    level_assets: Res<Assets<LevelAsset>>,
+    level_folder: Option<ResMut<LevelAssetFolder>>,
+    settings: LevelLoadSettings,
+    mut levels_resource: ResMut<LevelsResource>,
+    mut load_report: ResMut<LevelLoadReport>,
+) {
+    let Some(mut level_folder) = level_folder else {
+        return;
+    };
+    if level_folder.applied {
+        return;
+    }
+
+    if let LoadState::Failed(err) = asset_server.load_state(&level_folder.handle) {
+        warn!("Failed to load level assets: {err}; falling back to synchronous loading");
+        level_folder.applied = true;
+        settings.swap_in_levels(
+            fallback_report(*settings.sort_mode),
+            &mut levels_resource,
+            &mut load_report,
+        );
+        return;
+    }
+
+    let Some(folder) = folders.get(&level_folder.handle) else {
+        return;
+    };
+    level_folder.applied = true;
+
+    let mut report = LevelLoadReport::default();
+    let mut manifest = None;
+    let assets = folder
+        .handles
+        .iter()
+        .filter_map(|handle| handle.id().try_typed::<LevelAsset>().ok())
+        .filter_map(|id| level_assets.get(id));
+    for asset in assets {
+        match asset {
+            LevelAsset::Level(level) => report.loaded.push(Level::clone(level)),
+            LevelAsset::Manifest(loaded) => manifest = Some(loaded.clone()),
+            LevelAsset::Backup | LevelAsset::RemoteManifest(_) => {}
+            LevelAsset::Failed(failure) => {
+                warn!("Skipping level file {failure}");
+                report.failures.push(failure.clone());
+            }
+        }
+    }
+
+    make_names_unique(&mut report.loaded);
+    report.loaded = order_levels(report.loaded, manifest, *settings.sort_mode);
+    let level_count = report.loaded.len();
+    if level_count == 0 {
+        warn!("No level assets found in '{LEVELS_ASSET_FOLDER}', keeping default level");
+        *load_report = report;
+        return;
+    }
+
+    info!("Loaded {level_count} levels through the asset server");
+    settings.swap_in_levels(report, &mut levels_resource, &mut load_report);
+}
+
+/// `LevelPlugin` options applied when loaded levels are swapped in
+#[derive(SystemParam)]
+pub struct LevelLoadSettings<'w> {
+    sort_mode: Res<'w, LevelSortMode>,
+    starting_level: Option<Res<'w, StartingLevel>>,
+    default_level: Res<'w, DefaultLevelConfig>,
+    persisted_state: Option<Res<'w, PersistedLevelState>>,
+}
+
+impl LevelLoadSettings<'_> {
+    /// Order applied to loaded levels
+    pub(super) fn sort_mode(&self) -> LevelSortMode {
+        *self.sort_mode
+    }
+
+    /// Replace the levels with a finished load, restore any persisted state,
+    /// then switch to the starting level
+    pub(super) fn swap_in_levels(
+        &self,
+        report: LevelLoadReport,
+        levels_resource: &mut LevelsResource,
+        load_report: &mut LevelLoadReport,
+    ) {
+        *levels_resource = report.clone().into_levels_resource_or(&self.default_level);
+        if let Some(persisted_state) = &self.persisted_state {
+            persisted_state.apply(levels_resource);
+        }
+        if let Some(starting_level) = &self.starting_level {
+            starting_level.apply(levels_resource);
+        }
+        *load_report = report;
+    }
+}
+
+/// Levels loaded with the blocking file system (native) or embedded (WASM) path
+fn fallback_report(sort_mode: LevelSortMode) -> LevelLoadReport {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        load_level_report(LEVELS_DIRECTORY, sort_mode).unwrap_or_else(|err| {
+            warn!("Fallback level loading failed: {err:#}");
+            LevelLoadReport::default()
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        super::load_embedded_level_report(sort_mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_levels_swap_in_once_assets_load() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let levels_dir = temp_dir.path().join(LEVELS_ASSET_FOLDER);
+        let levels_path = levels_dir.to_str().expect("Failed to get temp path");
+        // Saving bravo twice leaves a backup in the folder
+        for (name, file) in [
+            ("Bravo", "bravo.toml"),
+            ("Alpha", "alpha.ron.gz"),
+            ("Bravo", "bravo.toml"),
+        ] {
+            LevelBuilder::new()
+                .name(name)
+                .dimensions(3, 3)
+                .build()
+                .unwrap()
+                .save_to_directory(levels_path, file)
+                .expect("Failed to save level");
+        }
+        std::fs::write(
+            levels_dir.join(LEVEL_MANIFEST_FILE),
+            "[[levels]]\nfile = \"bravo.toml\"\n",
+        )
+        .unwrap();
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin {
+                file_path: temp_dir.path().to_str().unwrap().to_string(),
+                ..default()
+            },
+        ))
+        .init_asset::<LevelAsset>()
+        .init_asset_loader::<LevelAssetLoader>()
+        .insert_resource(LevelsResource::with_default())
+        .init_resource::<LevelLoadReport>()
+        .init_resource::<LevelSortMode>()
+        .init_resource::<DefaultLevelConfig>()
+        .add_systems(Startup, request_level_assets)
+        .add_systems(Update, level_asset_loading_system);
+
+        assert_eq!(
+            app.world()
+                .resource::<LevelsResource>()
+                .current_level()
+                .name,
+            "Default Level",
+            "Default level is available before assets finish loading"
+        );
+
+        for _ in 0..500 {
+            app.update();
+            if app.world().resource::<LevelAssetFolder>().applied {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+
+        let levels = app.world().resource::<LevelsResource>();
+        let names: Vec<_> = levels.levels.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(names, ["Bravo", "Alpha"], "Manifest order comes first");
+    }
+}
@@ -0,0 +1,134 @@
+//! Level Backups
+//!
+//! Keeps copies of a level file's previous contents when it is saved over. The
+//! newest copy is `<file>.bak`, older ones are `<file>.1.bak`, `<file>.2.bak`, and
+//! so on, with the oldest dropped once the configured count is reached.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// Extension shared by every backup file
+pub const BACKUP_EXTENSION: &str = "bak";
+
+/// Number of backups kept per level file unless configured otherwise
+pub const DEFAULT_BACKUP_COUNT: usize = 3;
+
+/// Path of the backup of `path` that is `generation` saves old
+///
+/// Generation `0` is the most recent backup.
+pub fn backup_path(path: &Path, generation: usize) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    if generation > 0 {
+        file_name.push(format!(".{generation}"));
+    }
+    file_name.push(format!(".{BACKUP_EXTENSION}"));
+    path.with_file_name(file_name)
+}
+
+/// Shift the existing backups of `path` one generation back and copy its current
+/// content into the newest slot, keeping at most `count` backups
+///
+/// Does nothing when `count` is zero or `path` does not exist yet.
+pub fn rotate_backups(path: &Path, count: usize) -> Result<()> {
+    if count == 0 || !path.exists() {
+        return Ok(());
+    }
+
+    let oldest = backup_path(path, count - 1);
+    if oldest.exists() {
+        fs::remove_file(&oldest).with_context(|| {
+            format!(
+                "Failed to remove old backup {oldest}",
+                oldest = oldest.display()
+            )
+        })?;
+    }
+    for generation in (0..count - 1).rev() {
+        let from = backup_path(path, generation);
+        if from.exists() {
+            let to = backup_path(path, generation + 1);
+            fs::rename(&from, &to).with_context(|| {
+                format!("Failed to rotate backup {from}", from = from.display())
+            })?;
+        }
+    }
+
+    let newest = backup_path(path, 0);
+    fs::copy(path, &newest).with_context(|| {
+        format!(
+            "Failed to back up {path} to {newest}",
+            path = path.display(),
+            newest = newest.display()
+        )
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::{Level, SaveOptions, load_levels_from_directory};
+    use tempfile::TempDir;
+
+    fn saved_name(path: &Path) -> String {
+        let content = fs::read_to_string(path).expect("Failed to read backup");
+        let level: Level = toml::from_str(&content).expect("Backup should be a level file");
+        level.name
+    }
+
+    #[test]
+    fn test_backup_path_naming() {
+        let path = Path::new("levels/arena.toml");
+        assert_eq!(backup_path(path, 0), Path::new("levels/arena.toml.bak"));
+        assert_eq!(backup_path(path, 2), Path::new("levels/arena.toml.2.bak"));
+    }
+
+    #[test]
+    fn test_saves_rotate_backups() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        let options = SaveOptions {
+            backup_count: 2,
+            ..Default::default()
+        };
+
+        for revision in 1..=4 {
+            Level::new(format!("Arena v{revision}"), 3, 3)
+                .save_to_directory_with(temp_path, "arena.toml", &options)
+                .expect("Failed to save level");
+        }
+
+        let target = temp_dir.path().join("arena.toml");
+        assert_eq!(saved_name(&target), "Arena v4");
+        assert_eq!(saved_name(&backup_path(&target, 0)), "Arena v3");
+        assert_eq!(saved_name(&backup_path(&target, 1)), "Arena v2");
+        assert!(
+            !backup_path(&target, 2).exists(),
+            "Only two backups are kept"
+        );
+
+        // Backups are not picked up as levels
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(loaded.level_count(), 1);
+    }
+
+    #[test]
+    fn test_zero_backup_count_keeps_no_backups() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        let options = SaveOptions {
+            backup_count: 0,
+            ..Default::default()
+        };
+
+        for _ in 0..2 {
+            Level::new("Arena".to_string(), 3, 3)
+                .save_to_directory_with(temp_path, "arena.toml", &options)
+                .expect("Failed to save level");
+        }
+
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+}
@@ -0,0 +1,223 @@
+//! Level Builder
+//!
+//! Fluent construction of levels for tests, tools, and fallback content, with
+//! validation of dimensions and height values at build time.
+
+use anyhow::{Result, bail};
+use hexx::Hex;
+
+use super::generation::HeightGenerator;
+use super::{HexOrientation, Level};
+
+/// Builder for constructing [`Level`] values programmatically
+///
+/// Without a terrain call the builder produces the same gradient as [`Level::new`].
+///
+/// ```
+/// # use shared::level::builder::LevelBuilder;
+/// # use hexx::Hex;
+/// let level = LevelBuilder::new()
+///     .name("Arena")
+///     .dimensions(5, 5)
+///     .flat(1.0)
+///     .with_height(Hex::new(2, 2), 3.0)
+///     .build()
+///     .expect("valid level");
+/// assert_eq!(level.get_height(Hex::new(2, 2)), 3.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LevelBuilder {
+    name: String,
+    width: i32,
+    height: i32,
+    hex_scale: f32,
+    orientation: HexOrientation,
+    terrain: HeightGenerator,
+    overrides: Vec<(Hex, f32)>,
+}
+
+impl Default for LevelBuilder {
+    fn default() -> Self {
+        Self {
+            name: "Untitled Level".to_string(),
+            width: 10,
+            height: 10,
+            hex_scale: 1.0,
+            orientation: HexOrientation::Pointy,
+            terrain: HeightGenerator::default(),
+            overrides: Vec::new(),
+        }
+    }
+}
+
+impl LevelBuilder {
+    /// Start a builder for a 10x10 gradient level named "Untitled Level"
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the human-readable level name
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the grid dimensions (columns x rows)
+    pub fn dimensions(mut self, width: i32, height: i32) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Set the hex radius in world units (defaults to 1.0)
+    pub fn hex_scale(mut self, hex_scale: f32) -> Self {
+        self.hex_scale = hex_scale;
+        self
+    }
+
+    /// Set the hex orientation (defaults to pointy-top)
+    pub fn orientation(mut self, orientation: HexOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Fill the grid with a single height
+    pub fn flat(mut self, height: f32) -> Self {
+        self.terrain = HeightGenerator::Flat(height);
+        self
+    }
+
+    /// Fill the grid with a diagonal ramp from `from` (front-left) to `to` (back-right)
+    pub fn gradient(mut self, from: f32, to: f32) -> Self {
+        self.terrain = HeightGenerator::Gradient { from, to };
+        self
+    }
+
+    /// Fill the grid using any [`HeightGenerator`], e.g. a hill or bowl
+    pub fn terrain(mut self, generator: HeightGenerator) -> Self {
+        self.terrain = generator;
+        self
+    }
+
+    /// Override the height of a single hex after the base terrain is applied
+    ///
+    /// Later overrides of the same hex win.
+    pub fn with_height(mut self, hex: Hex, height: f32) -> Self {
+        self.overrides.push((hex, height));
+        self
+    }
+
+    /// Validate the configuration and construct the level
+    ///
+    /// Errors when dimensions or hex scale are not positive, when an override lies
+    /// outside the grid, or when any resulting height is NaN or infinite.
+    pub fn build(self) -> Result<Level> {
+        if self.width <= 0 || self.height <= 0 {
+            bail!(
+                "Level '{name}' must have positive dimensions, got {width}x{height}",
+                name = self.name,
+                width = self.width,
+                height = self.height
+            );
+        }
+
+        if !self.hex_scale.is_finite() || self.hex_scale <= 0.0 {
+            bail!(
+                "Level '{name}' must have a positive hex scale, got {hex_scale}",
+                name = self.name,
+                hex_scale = self.hex_scale
+            );
+        }
+
+        let mut level = Level::generate(self.name, self.width, self.height, self.terrain);
+        level.hex_scale = self.hex_scale;
+        level.orientation = self.orientation;
+
+        for (hex, height) in self.overrides {
+            level.set_height(hex, height)?;
+        }
+
+        if let Some(((row, col), height)) = level
+            .heights
+            .indexed_iter()
+            .find(|(_, height)| !height.is_finite())
+        {
+            bail!(
+                "Level '{name}' has non-finite height {height} at hex ({col}, {row})",
+                name = level.name
+            );
+        }
+
+        Ok(level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_builder_matches_level_new() {
+        let built = LevelBuilder::new()
+            .name("Default Level")
+            .build()
+            .expect("Default builder should be valid");
+        let constructed = Level::new("Default Level".to_string(), 10, 10);
+
+        assert_eq!(built.name, constructed.name);
+        for (a, b) in built.heights.iter().zip(constructed.heights.iter()) {
+            assert!((a - b).abs() < 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_flat_with_overrides() {
+        let level = LevelBuilder::new()
+            .dimensions(4, 3)
+            .flat(2.0)
+            .with_height(Hex::new(3, 2), 5.0)
+            .with_height(Hex::new(0, 0), 1.0)
+            .with_height(Hex::new(0, 0), 0.5)
+            .build()
+            .expect("Flat level should be valid");
+
+        assert_eq!(level.heights.shape(), &[3, 4]);
+        assert_eq!(level.get_height(Hex::new(1, 1)), 2.0);
+        assert_eq!(level.get_height(Hex::new(3, 2)), 5.0);
+        assert_eq!(level.get_height(Hex::new(0, 0)), 0.5, "Last override wins");
+    }
+
+    #[test]
+    fn test_gradient_handles_single_row() {
+        let level = LevelBuilder::new()
+            .dimensions(5, 1)
+            .gradient(0.0, 2.0)
+            .build()
+            .expect("Single-row level should be valid");
+
+        assert_eq!(level.get_height(Hex::new(0, 0)), 0.0);
+        assert!((level.get_height(Hex::new(4, 0)) - 1.0).abs() < 0.0001);
+        assert!(level.heights.iter().all(|h| h.is_finite()));
+    }
+
+    #[test]
+    fn test_build_rejects_invalid_input() {
+        assert!(LevelBuilder::new().dimensions(0, 5).build().is_err());
+        assert!(LevelBuilder::new().dimensions(5, -1).build().is_err());
+        assert!(LevelBuilder::new().flat(f32::NAN).build().is_err());
+        assert!(LevelBuilder::new().hex_scale(0.0).build().is_err());
+        assert!(
+            LevelBuilder::new()
+                .with_height(Hex::new(1, 1), f32::INFINITY)
+                .build()
+                .is_err()
+        );
+        assert!(
+            LevelBuilder::new()
+                .dimensions(3, 3)
+                .with_height(Hex::new(3, 0), 1.0)
+                .build()
+                .is_err()
+        );
+    }
+}
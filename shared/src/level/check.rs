@@ -0,0 +1,165 @@
+//! Level File Checks
+//!
+//! Validates every level file in a directory independently, for command-line
+//! and CI use. Unlike [`load_level_report`](super::load_level_report), the
+//! manifest and duplicate-name handling are skipped so each file is judged on
+//! its own.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+
+use super::format::LevelFormat;
+use super::report::{LevelLoadErrorKind, LevelLoadFailure};
+use super::stats::LevelStats;
+use super::{Level, collect_level_files, parse_level_bytes};
+
+/// Outcome of checking one level file
+#[derive(Debug, Clone, PartialEq)]
+pub enum LevelFileCheck {
+    /// The file loaded and validated
+    Passed {
+        /// File path relative to the checked directory
+        path: PathBuf,
+        name: String,
+        width: i32,
+        height: i32,
+        stats: LevelStats,
+    },
+    Failed(LevelLoadFailure),
+}
+
+impl LevelFileCheck {
+    fn from_result(path: &Path, result: Result<Level, LevelLoadFailure>) -> Self {
+        match result {
+            Ok(level) => Self::Passed {
+                path: path.to_path_buf(),
+                stats: level.stats(),
+                name: level.name,
+                width: level.width,
+                height: level.height,
+            },
+            Err(failure) => Self::Failed(failure),
+        }
+    }
+
+    pub fn is_passed(&self) -> bool {
+        matches!(self, Self::Passed { .. })
+    }
+}
+
+impl fmt::Display for LevelFileCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Passed {
+                path,
+                name,
+                width,
+                height,
+                stats,
+            } => write!(
+                f,
+                "PASS {path}: '{name}' {width}x{height}, height {min:.2}..{max:.2}",
+                path = path.display(),
+                min = stats.min_height,
+                max = stats.max_height
+            ),
+            Self::Failed(failure) => write!(f, "FAIL {failure}"),
+        }
+    }
+}
+
+/// Check every level file under `levels_dir`, in path order
+///
+/// Subdirectories are searched as by the directory loader. Fails only if the
+/// directory cannot be read; broken files are reported as
+/// [`LevelFileCheck::Failed`].
+pub fn check_level_directory(levels_dir: &Path) -> Result<Vec<LevelFileCheck>> {
+    if !levels_dir.is_dir() {
+        bail!(
+            "Levels directory {path} does not exist",
+            path = levels_dir.display()
+        );
+    }
+
+    let mut files = Vec::new();
+    collect_level_files(levels_dir, Path::new(""), 0, &mut files)?;
+    files.sort_by(|a, b| a.1.cmp(&b.1));
+
+    Ok(files
+        .into_iter()
+        .map(|(path, relative_path, format)| {
+            let result = fs::read(&path)
+                .map_err(|err| LevelLoadFailure::new(&relative_path, LevelLoadErrorKind::Io, err))
+                .and_then(|bytes| parse_level_bytes(&bytes, format, &relative_path));
+            LevelFileCheck::from_result(&relative_path, result)
+        })
+        .collect())
+}
+
+/// Load and validate a single level file, picking the format from its extension
+pub fn load_level_file(path: &Path) -> Result<Level> {
+    let format = LevelFormat::from_path(path).with_context(|| {
+        format!(
+            "{path} is not a level file; expected .toml, .ron, or .json",
+            path = path.display()
+        )
+    })?;
+    let bytes =
+        fs::read(path).with_context(|| format!("Failed to read {path}", path = path.display()))?;
+    let file_name = path.file_name().map_or(path, Path::new);
+    Ok(parse_level_bytes(&bytes, format, file_name)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_check_reports_each_file() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        for file in ["b.toml", "campaign/a.toml"] {
+            LevelBuilder::new()
+                .name("Same Name")
+                .dimensions(4, 3)
+                .flat(2.0)
+                .build()
+                .unwrap()
+                .save_to_directory(temp_path, file)
+                .expect("Failed to save level");
+        }
+        fs::write(temp_dir.path().join("broken.toml"), "width = ").unwrap();
+        fs::write(temp_dir.path().join("manifest.toml"), "unlisted = \"skip\"").unwrap();
+
+        let checks = check_level_directory(temp_dir.path()).expect("Directory exists");
+
+        let lines: Vec<_> = checks.iter().map(ToString::to_string).collect();
+        assert_eq!(lines.len(), 3, "Manifest is not a level: {lines:?}");
+        assert_eq!(lines[0], "PASS b.toml: 'Same Name' 4x3, height 2.00..2.00");
+        assert!(lines[1].starts_with("FAIL broken.toml: parse error"));
+        assert!(checks[2].is_passed(), "Duplicate names are not an error");
+
+        assert!(check_level_directory(&temp_dir.path().join("missing")).is_err());
+    }
+
+    #[test]
+    fn test_load_single_level_file() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        let path = Level::new("Solo".to_string(), 5, 5)
+            .save_to_directory(temp_path, "solo.ron")
+            .expect("Failed to save level");
+
+        let level = load_level_file(&path).expect("Saved level loads");
+        assert_eq!(level.name, "Solo");
+        assert_eq!(level.source_path.as_deref(), Some(Path::new("solo.ron")));
+
+        let error = load_level_file(&temp_dir.path().join("notes.txt")).unwrap_err();
+        assert!(error.to_string().contains("not a level file"));
+    }
+}
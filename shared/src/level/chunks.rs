@@ -0,0 +1,397 @@
+//! Hex Grid Chunks
+//!
+//! The rendered grid is split into fixed-size square chunks of axial
+//! coordinates, each spawned as its own merged mesh. Sending [`RebuildChunk`]
+//! regenerates only the chunk containing the edited hex instead of the whole
+//! grid, and level changes that only touch column heights or walkability
+//! respawn just the affected chunks (see [`changed_column_hexes`]).
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use hexx::Hex;
+use tracing::debug;
+
+use super::mesh::{GridSpawnAssets, HexGridIndex, spawn_chunk_columns};
+use super::{Level, LevelsResource};
+
+/// Hexes along each side of a chunk
+pub const HEX_CHUNK_SIZE: i32 = 16;
+
+/// A rectangular block of hexes, both corners inclusive
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HexChunk {
+    pub min: Hex,
+    pub max: Hex,
+}
+
+impl HexChunk {
+    /// The chunk of a `width`x`height` level that contains `hex`
+    ///
+    /// Returns `None` if `hex` lies outside the level.
+    pub fn containing(hex: Hex, width: i32, height: i32) -> Option<Self> {
+        if !(0..width).contains(&hex.x) || !(0..height).contains(&hex.y) {
+            return None;
+        }
+        let min = Hex::new(
+            hex.x - hex.x % HEX_CHUNK_SIZE,
+            hex.y - hex.y % HEX_CHUNK_SIZE,
+        );
+        let max = Hex::new(
+            (min.x + HEX_CHUNK_SIZE).min(width) - 1,
+            (min.y + HEX_CHUNK_SIZE).min(height) - 1,
+        );
+        Some(Self { min, max })
+    }
+
+    pub fn contains(&self, hex: Hex) -> bool {
+        (self.min.x..=self.max.x).contains(&hex.x) && (self.min.y..=self.max.y).contains(&hex.y)
+    }
+
+    /// Iterate the chunk's hexes in [`Level::hex_iter`](super::Level::hex_iter) order
+    pub fn hexes(&self) -> impl Iterator<Item = Hex> + use<> {
+        let Self { min, max } = *self;
+        (min.x..=max.x).flat_map(move |q| (min.y..=max.y).map(move |r| Hex::new(q, r)))
+    }
+}
+
+/// Chunks covering a `width`x`height` level; edge chunks are clipped to the level
+pub fn hex_chunks(width: i32, height: i32) -> Vec<HexChunk> {
+    (0..width)
+        .step_by(HEX_CHUNK_SIZE as usize)
+        .flat_map(|q| {
+            (0..height)
+                .step_by(HEX_CHUNK_SIZE as usize)
+                .filter_map(move |r| HexChunk::containing(Hex::new(q, r), width, height))
+        })
+        .collect()
+}
+
+/// Snapshot of the level the rendered grid was last built from
+///
+/// Compared against the current level to find which chunks need respawning.
+#[derive(Resource, Debug, Clone)]
+pub struct SpawnedGrid(pub Level);
+
+/// Hexes whose column must be rebuilt to turn the `old` grid into the `new` one
+///
+/// Returns `None` when the change reaches beyond individual columns and the
+/// whole grid must be respawned: different dimensions or layout, a different
+/// column base or height range (which terrain colors span), changed water,
+/// overlays, or ramps, or a height change under a ramp.
+pub fn changed_column_hexes(old: &Level, new: &Level) -> Option<HashSet<Hex>> {
+    if (old.width, old.height) != (new.width, new.height)
+        || old.hex_scale != new.hex_scale
+        || old.orientation != new.orientation
+        || old.column_base() != new.column_base()
+        || height_range(old) != height_range(new)
+        || old.water_level != new.water_level
+        || old.overlays != new.overlays
+        || old.ramps != new.ramps
+    {
+        return None;
+    }
+
+    let changed: HashSet<Hex> = new
+        .hex_iter()
+        .filter(|&hex| {
+            old.get_height(hex) != new.get_height(hex)
+                || old.is_walkable(hex) != new.is_walkable(hex)
+        })
+        .collect();
+    let under_ramp = new.ramps.iter().any(|ramp| {
+        let (a, b) = ramp.hexes();
+        changed.contains(&a) || changed.contains(&b)
+    });
+    if under_ramp {
+        return None;
+    }
+    Some(changed)
+}
+
+fn height_range(level: &Level) -> (f32, f32) {
+    let stats = level.stats();
+    (stats.min_height, stats.max_height)
+}
+
+/// Despawn the entities of the `dirty` chunks and spawn them again from `level`,
+/// pointing `grid_index` at the new entities
+pub fn respawn_chunks(
+    commands: &mut Commands,
+    assets: &mut GridSpawnAssets,
+    level: &Level,
+    dirty: &HashSet<HexChunk>,
+    chunk_query: &Query<(Entity, &HexChunk)>,
+    grid_index: &mut HexGridIndex,
+) {
+    for (entity, chunk) in chunk_query {
+        if dirty.contains(chunk) {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let style = assets.style();
+    for &chunk in dirty {
+        debug!(
+            "Rebuilding hex chunk {min:?}..={max:?}",
+            min = chunk.min,
+            max = chunk.max
+        );
+        spawn_chunk_columns(
+            commands,
+            &mut assets.meshes,
+            level,
+            chunk,
+            &assets.hex_materials,
+            style,
+            grid_index,
+        );
+    }
+}
+
+/// Request regenerating the chunk containing `hex` from the current level
+///
+/// Edits that send this for every touched hex can mutate [`LevelsResource`]
+/// through `bypass_change_detection` to skip the full grid respawn.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RebuildChunk {
+    pub hex: Hex,
+}
+
+/// System rebuilding the chunks named by [`RebuildChunk`] events
+///
+/// Each dirty chunk's entities are despawned, dropping their mesh assets, and
+/// respawned from the current level. Edits that move the column base or height
+/// range restyle every column, so all chunks are respawned then (see
+/// [`changed_column_hexes`]). Requests are dropped on frames where the level
+/// changed, since the whole grid is respawned then anyway, and before the grid
+/// is first spawned.
+pub fn chunk_rebuild_system(
+    mut commands: Commands,
+    mut rebuild_events: EventReader<RebuildChunk>,
+    mut assets: GridSpawnAssets,
+    levels_resource: Res<LevelsResource>,
+    chunk_query: Query<(Entity, &HexChunk)>,
+    mut spawned_grid: Option<ResMut<SpawnedGrid>>,
+    grid_index: Option<ResMut<HexGridIndex>>,
+) {
+    if levels_resource.is_changed() {
+        rebuild_events.clear();
+        return;
+    }
+
+    let level = levels_resource.current_level();
+    let mut dirty: HashSet<HexChunk> = rebuild_events
+        .read()
+        .filter_map(|event| HexChunk::containing(event.hex, level.width, level.height))
+        .collect();
+    let Some(mut grid_index) = grid_index.filter(|_| !dirty.is_empty()) else {
+        return;
+    };
+
+    if let Some(spawned_grid) = spawned_grid.as_mut() {
+        match changed_column_hexes(&spawned_grid.0, level) {
+            Some(changed) => dirty.extend(
+                changed
+                    .into_iter()
+                    .filter_map(|hex| HexChunk::containing(hex, level.width, level.height)),
+            ),
+            None => dirty.extend(hex_chunks(level.width, level.height)),
+        }
+        spawned_grid.0 = level.clone();
+    }
+
+    respawn_chunks(
+        &mut commands,
+        &mut assets,
+        level,
+        &dirty,
+        &chunk_query,
+        &mut grid_index,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::Level;
+    use crate::level::mesh::{
+        HexEdgeStyle, HexMaterials, HexMeshOptions, TerrainColoring, spawn_hex_grid,
+    };
+    use crate::level::ramps::Ramp;
+    use crate::level::render_config::HexGridRenderConfig;
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_chunks_cover_level_exactly_once() {
+        for (width, height) in [(1, 1), (16, 16), (17, 5), (37, 50), (48, 33)] {
+            let chunks = hex_chunks(width, height);
+            let level = Level::new("Chunks".to_string(), width, height);
+            for hex in level.hex_iter() {
+                let owners = chunks.iter().filter(|chunk| chunk.contains(hex)).count();
+                assert_eq!(owners, 1, "{hex:?} in {width}x{height}");
+            }
+
+            let chunk_hexes: usize = chunks.iter().map(|chunk| chunk.hexes().count()).sum();
+            assert_eq!(chunk_hexes, (width * height) as usize);
+            for chunk in &chunks {
+                assert!(chunk.max.x < width && chunk.max.y < height);
+                assert!(chunk.max.x - chunk.min.x < HEX_CHUNK_SIZE);
+            }
+        }
+        assert_eq!(hex_chunks(37, 50).len(), 3 * 4);
+        assert_eq!(HexChunk::containing(Hex::new(37, 0), 37, 50), None);
+    }
+
+    #[test]
+    fn test_changed_column_hexes() {
+        let old = Level::new("Diff".to_string(), 20, 20);
+        assert_eq!(
+            changed_column_hexes(&old, &old.clone()),
+            Some(HashSet::new())
+        );
+
+        let mut new = old.clone();
+        new.set_height(Hex::new(3, 4), old.get_height(Hex::new(3, 4)) + 0.1)
+            .unwrap();
+        new.set_walkable(Hex::new(17, 2), false).unwrap();
+        // Rewriting the same height is not a change
+        new.set_height(Hex::new(0, 0), old.get_height(Hex::new(0, 0)))
+            .unwrap();
+        assert_eq!(
+            changed_column_hexes(&old, &new),
+            Some(HashSet::from([Hex::new(3, 4), Hex::new(17, 2)]))
+        );
+
+        let mut peak = old.clone();
+        peak.set_height(Hex::new(8, 8), old.stats().max_height + 1.0)
+            .unwrap();
+        assert_eq!(changed_column_hexes(&old, &peak), None, "Colors rescale");
+
+        let resized = Level::new("Diff".to_string(), 20, 21);
+        assert_eq!(changed_column_hexes(&old, &resized), None);
+
+        // Digging below the lowest column moves every column's base
+        let mut trench = old.clone();
+        trench.set_height(Hex::new(5, 5), -2.0).unwrap();
+        assert_eq!(changed_column_hexes(&old, &trench), None);
+
+        let mut flooded = old.clone();
+        flooded.water_level = Some(0.5);
+        assert_eq!(changed_column_hexes(&old, &flooded), None);
+    }
+
+    #[test]
+    fn test_height_change_under_ramp_needs_full_respawn() {
+        let mut old = Level::new("Ramp".to_string(), 4, 4);
+        old.ramps = vec![Ramp::new(Hex::new(1, 1), Hex::new(2, 1))];
+        let stats = old.stats();
+        // Stay inside the height range so only the column itself changes
+        let mid_height = (stats.min_height + stats.max_height) / 2.0 + 0.01;
+        let mut new = old.clone();
+        new.set_height(Hex::new(1, 2), mid_height).unwrap();
+        assert_eq!(
+            changed_column_hexes(&old, &new),
+            Some(HashSet::from([Hex::new(1, 2)]))
+        );
+
+        new.set_height(Hex::new(2, 1), mid_height).unwrap();
+        assert_eq!(changed_column_hexes(&old, &new), None);
+    }
+
+    /// World with a spawned 40x20 grid, whose 6 chunks are all clean
+    fn world_with_spawned_grid() -> (World, Schedule) {
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<HexMaterials>();
+        world.init_resource::<TerrainColoring>();
+        world.init_resource::<HexMeshOptions>();
+        world.init_resource::<HexGridRenderConfig>();
+        world.init_resource::<HexEdgeStyle>();
+        world.init_resource::<Events<RebuildChunk>>();
+        world.insert_resource(LevelsResource::new(vec![Level::new(
+            "Chunks".to_string(),
+            40,
+            20,
+        )]));
+        world
+            .run_system_once(spawn_hex_grid)
+            .expect("Grid spawning runs");
+        // First run only sees the freshly inserted level as changed
+        let mut schedule = Schedule::default();
+        schedule.add_systems(chunk_rebuild_system);
+        schedule.run(&mut world);
+        (world, schedule)
+    }
+
+    fn meshes_of(world: &mut World) -> Vec<(HexChunk, AssetId<Mesh>)> {
+        let mut query = world.query::<(&HexChunk, &Mesh3d)>();
+        let mut meshes: Vec<_> = query
+            .iter(world)
+            .map(|(chunk, mesh)| (*chunk, mesh.0.id()))
+            .collect();
+        meshes.sort_by_key(|(chunk, _)| (chunk.min.x, chunk.min.y));
+        meshes
+    }
+
+    /// Set `hex` to `height` without change detection and request its rebuild
+    fn edit_height(world: &mut World, schedule: &mut Schedule, hex: Hex, height: f32) {
+        world
+            .resource_mut::<LevelsResource>()
+            .bypass_change_detection()
+            .current_level_mut()
+            .set_height(hex, height)
+            .unwrap();
+        world.send_event(RebuildChunk { hex });
+        schedule.run(world);
+    }
+
+    #[test]
+    fn test_rebuild_only_touches_edited_chunk() {
+        let (mut world, mut schedule) = world_with_spawned_grid();
+        let before = meshes_of(&mut world);
+        assert_eq!(before.len(), 6);
+
+        // Inside the level's height range, so other columns keep their colors
+        let edited = Hex::new(18, 3);
+        edit_height(&mut world, &mut schedule, edited, 2.5);
+
+        let after = meshes_of(&mut world);
+        assert_eq!(after.len(), 6);
+        for ((chunk, old_mesh), (_, new_mesh)) in before.iter().zip(&after) {
+            assert_eq!(
+                old_mesh != new_mesh,
+                chunk.contains(edited),
+                "{chunk:?} rebuilt"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rebuild_respawns_every_chunk_when_column_base_moves() {
+        let (mut world, mut schedule) = world_with_spawned_grid();
+        let before = meshes_of(&mut world);
+
+        // Digging below the lowest column lowers the base of every column
+        edit_height(&mut world, &mut schedule, Hex::new(3, 3), -2.0);
+
+        let after = meshes_of(&mut world);
+        assert_eq!(after.len(), before.len());
+        for ((chunk, old_mesh), (_, new_mesh)) in before.iter().zip(&after) {
+            assert_ne!(old_mesh, new_mesh, "{chunk:?} rebuilt");
+        }
+        let spawned = &world.resource::<SpawnedGrid>().0;
+        assert_eq!(spawned.get_height(Hex::new(3, 3)), -2.0);
+
+        // The snapshot is current, so the next in-range edit is local again
+        let rebuilt = meshes_of(&mut world);
+        edit_height(&mut world, &mut schedule, Hex::new(35, 15), 2.5);
+        let changed = rebuilt
+            .iter()
+            .zip(meshes_of(&mut world))
+            .filter(|((_, old_mesh), (_, new_mesh))| old_mesh != new_mesh)
+            .count();
+        assert_eq!(changed, 1);
+    }
+}
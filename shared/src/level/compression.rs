@@ -0,0 +1,124 @@
+//! Level File Compression
+//!
+//! Gzip support for level files. A `.gz` suffix on any level file name
+//! (`arena.toml.gz`) marks it as compressed; loaders also recognise gzip data by
+//! its magic bytes, so a compressed file with a plain extension still loads.
+
+use std::io::{Read, Write};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+/// Extension appended to compressed level files, without the dot
+pub const GZIP_EXTENSION: &str = "gz";
+
+/// First bytes of every gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Whether `path` names a gzip-compressed file
+pub fn is_gzip_path(path: impl AsRef<Path>) -> bool {
+    path.as_ref()
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case(GZIP_EXTENSION))
+}
+
+/// Whether `bytes` start with the gzip magic number
+pub fn is_gzip(bytes: &[u8]) -> bool {
+    bytes.starts_with(&GZIP_MAGIC)
+}
+
+/// Gzip-compress level file contents
+pub fn compress(content: &str) -> Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(content.as_bytes())
+        .and_then(|()| encoder.finish())
+        .context("Failed to compress level data")
+}
+
+/// Turn raw level file bytes into text, decompressing gzip data first
+pub fn decode_level_bytes(bytes: &[u8]) -> Result<String> {
+    if is_gzip(bytes) {
+        let mut content = String::new();
+        GzDecoder::new(bytes)
+            .read_to_string(&mut content)
+            .context("Failed to decompress gzip level data")?;
+        return Ok(content);
+    }
+
+    String::from_utf8(bytes.to_vec()).context("Level file is not valid UTF-8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::report::LevelLoadErrorKind;
+    use crate::level::sorting::LevelSortMode;
+    use crate::level::{Level, SaveOptions, load_level_report};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_compressed_save_and_load_roundtrip() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        let level = Level::new("Big Plains".to_string(), 40, 30);
+        let compressed = SaveOptions {
+            compress: true,
+            ..Default::default()
+        };
+
+        let toml_path = level
+            .save_to_directory_with(temp_path, "plains.toml", &compressed)
+            .expect("Failed to save compressed level");
+        let ron_path = level
+            .save_to_directory_with(temp_path, "plains_ron.ron.gz", &SaveOptions::default())
+            .expect("A .gz file name alone asks for compression");
+
+        assert!(toml_path.ends_with("plains.toml.gz"));
+        for path in [&toml_path, &ron_path] {
+            let bytes = fs::read(path).unwrap();
+            assert!(is_gzip(&bytes), "{path:?} should be gzip data");
+        }
+        let plain_size = level.to_json_string().unwrap().len();
+        assert!(fs::metadata(&toml_path).unwrap().len() < plain_size as u64);
+
+        let report =
+            load_level_report(temp_path, LevelSortMode::default()).expect("Failed to load levels");
+        assert!(!report.has_failures(), "{:?}", report.failures);
+        assert_eq!(report.loaded.len(), 2);
+        for loaded in &report.loaded {
+            assert_eq!(loaded.heights, level.heights);
+        }
+    }
+
+    #[test]
+    fn test_gzip_detected_by_magic_bytes() {
+        let toml = toml::to_string(&Level::new("Sneaky".to_string(), 3, 3)).unwrap();
+        let decoded = decode_level_bytes(&compress(&toml).unwrap()).unwrap();
+        assert_eq!(decoded, toml);
+    }
+
+    #[test]
+    fn test_corrupt_gzip_is_reported() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        let mut truncated = compress("name = \"Broken\"").unwrap();
+        truncated.truncate(truncated.len() / 2);
+        fs::write(temp_dir.path().join("broken.toml.gz"), truncated).unwrap();
+
+        let report = load_level_report(temp_path, LevelSortMode::default())
+            .expect("Corrupt files must not abort loading");
+        assert!(report.loaded.is_empty());
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].kind, LevelLoadErrorKind::Io);
+        assert!(
+            report.failures[0].message.contains("decompress"),
+            "{}",
+            report.failures[0].message
+        );
+    }
+}
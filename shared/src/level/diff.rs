@@ -0,0 +1,231 @@
+//! Level Diffing
+//!
+//! Comparison of two levels for map iteration, reporting which hexes and
+//! metadata fields changed between a saved file and an in-memory version.
+
+use std::fmt;
+
+use hexx::Hex;
+
+use super::Level;
+
+/// A height change at a single hex present in both levels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeightChange {
+    pub hex: Hex,
+    pub old: f32,
+    pub new: f32,
+}
+
+/// A change to a named metadata field, with both values formatted for display
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub old: String,
+    pub new: String,
+}
+
+/// Differences between two levels, produced by [`Level::diff`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LevelDiff {
+    /// Old and new (width, height) when the grid size changed
+    pub dimensions: Option<((i32, i32), (i32, i32))>,
+    /// Changed name, author, description, layout, and water level fields
+    pub metadata: Vec<FieldChange>,
+    /// Height changes for hexes inside both grids, in row-major order
+    pub heights: Vec<HeightChange>,
+}
+
+impl LevelDiff {
+    /// Check whether the two levels were identical in every compared field
+    pub fn is_empty(&self) -> bool {
+        self.dimensions.is_none() && self.metadata.is_empty() && self.heights.is_empty()
+    }
+
+    /// Get the hexes whose height changed, for highlighting in the editor
+    pub fn changed_hexes(&self) -> Vec<Hex> {
+        self.heights.iter().map(|change| change.hex).collect()
+    }
+}
+
+impl fmt::Display for LevelDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "No differences");
+        }
+
+        let mut lines = Vec::new();
+        if let Some(((old_width, old_height), (new_width, new_height))) = self.dimensions {
+            lines.push(format!(
+                "dimensions: {old_width}x{old_height} -> {new_width}x{new_height}"
+            ));
+        }
+        for change in &self.metadata {
+            lines.push(format!(
+                "{field}: {old} -> {new}",
+                field = change.field,
+                old = change.old,
+                new = change.new
+            ));
+        }
+        for change in &self.heights {
+            lines.push(format!(
+                "height ({q}, {r}): {old} -> {new}",
+                q = change.hex.x,
+                r = change.hex.y,
+                old = change.old,
+                new = change.new
+            ));
+        }
+        write!(f, "{lines}", lines = lines.join("\n"))
+    }
+}
+
+/// Record a [`FieldChange`] when two values differ, formatting both with `Debug`
+fn push_field_change<T: PartialEq + fmt::Debug>(
+    changes: &mut Vec<FieldChange>,
+    field: &'static str,
+    old: &T,
+    new: &T,
+) {
+    if old != new {
+        changes.push(FieldChange {
+            field,
+            old: format!("{old:?}"),
+            new: format!("{new:?}"),
+        });
+    }
+}
+
+impl Level {
+    /// Compare this level (the old version) against `other` (the new version)
+    ///
+    /// Heights are compared only where both grids have a hex; a size change is
+    /// reported once through [`LevelDiff::dimensions`].
+    pub fn diff(&self, other: &Level) -> LevelDiff {
+        let mut diff = LevelDiff::default();
+
+        if (self.width, self.height) != (other.width, other.height) {
+            diff.dimensions = Some(((self.width, self.height), (other.width, other.height)));
+        }
+
+        push_field_change(&mut diff.metadata, "name", &self.name, &other.name);
+        push_field_change(&mut diff.metadata, "author", &self.author, &other.author);
+        push_field_change(
+            &mut diff.metadata,
+            "description",
+            &self.description,
+            &other.description,
+        );
+        push_field_change(
+            &mut diff.metadata,
+            "hex_scale",
+            &self.hex_scale,
+            &other.hex_scale,
+        );
+        push_field_change(
+            &mut diff.metadata,
+            "orientation",
+            &self.orientation,
+            &other.orientation,
+        );
+        push_field_change(
+            &mut diff.metadata,
+            "water_level",
+            &self.water_level,
+            &other.water_level,
+        );
+
+        for r in 0..self.height.min(other.height) {
+            for q in 0..self.width.min(other.width) {
+                let hex = Hex::new(q, r);
+                if let (Some(old), Some(new)) =
+                    (self.try_get_height(hex), other.try_get_height(hex))
+                    && old != new
+                {
+                    diff.heights.push(HeightChange { hex, old, new });
+                }
+            }
+        }
+
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+
+    fn base_level() -> Level {
+        LevelBuilder::new()
+            .name("Base")
+            .dimensions(4, 4)
+            .flat(1.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_identical_levels_have_empty_diff() {
+        let level = base_level();
+        let diff = level.diff(&level.clone());
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "No differences");
+    }
+
+    #[test]
+    fn test_diff_enumerates_touched_cells() {
+        let old = base_level();
+        let mut new = old.clone();
+        new.set_height(Hex::new(2, 1), 3.0).unwrap();
+        new.set_height(Hex::new(0, 3), 0.5).unwrap();
+        // Writing the same value is not a change
+        new.set_height(Hex::new(1, 1), 1.0).unwrap();
+
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.changed_hexes(), vec![Hex::new(2, 1), Hex::new(0, 3)]);
+        assert_eq!(
+            diff.heights[0],
+            HeightChange {
+                hex: Hex::new(2, 1),
+                old: 1.0,
+                new: 3.0
+            }
+        );
+        assert!(diff.dimensions.is_none());
+        assert!(diff.metadata.is_empty());
+        assert_eq!(
+            diff.to_string(),
+            "height (2, 1): 1 -> 3\nheight (0, 3): 1 -> 0.5"
+        );
+    }
+
+    #[test]
+    fn test_diff_reports_dimensions_and_metadata() {
+        let old = base_level();
+        let mut new = old.clone();
+        new.resize(5, 4, Default::default(), 2.0).unwrap();
+        new.name = "Renamed".to_string();
+        new.author = Some("Designer".to_string());
+
+        let diff = old.diff(&new);
+
+        assert_eq!(diff.dimensions, Some(((4, 4), (5, 4))));
+        assert!(
+            diff.heights.is_empty(),
+            "New column is not an overlap change"
+        );
+        let fields: Vec<_> = diff.metadata.iter().map(|change| change.field).collect();
+        assert_eq!(fields, vec!["name", "author"]);
+
+        let text = diff.to_string();
+        assert!(text.contains("dimensions: 4x4 -> 5x4"), "Diff was: {text}");
+        assert!(
+            text.contains("name: \"Base\" -> \"Renamed\""),
+            "Diff was: {text}"
+        );
+    }
+}
@@ -0,0 +1,278 @@
+//! Dropped Level Files
+//!
+//! Dropping a level file onto the window loads it with the same validation as
+//! the directory loader, appends it to [`LevelsResource`], and switches to it.
+//! Native builds receive drops through Bevy's [`FileDragAndDrop`] events.
+//! Browsers do not report dropped files to Bevy, so web builds read them through
+//! a DOM drop listener instead. Rejected files raise a [`LevelFileRejected`]
+//! event so the UI can show them.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use anyhow::anyhow;
+use bevy::prelude::*;
+use bevy::window::FileDragAndDrop;
+use tracing::{info, warn};
+
+use super::format::LevelFormat;
+use super::naming::unique_name;
+use super::report::{LevelLoadErrorKind, LevelLoadFailure};
+use super::{LevelsResource, parse_level_bytes};
+
+/// A dropped file that could not be loaded as a level
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct LevelFileRejected(pub LevelLoadFailure);
+
+impl LevelsResource {
+    /// Load a dropped level file, append it, and make it the current level
+    ///
+    /// `file_name` picks the format and names the file in failures. A level whose
+    /// name is already taken is renamed after the file, as on load. Returns the
+    /// new level's index.
+    pub fn add_dropped_level(
+        &mut self,
+        file_name: &str,
+        bytes: &[u8],
+    ) -> Result<usize, LevelLoadFailure> {
+        let path = Path::new(file_name);
+        let Some(format) = LevelFormat::from_path(path) else {
+            return Err(LevelLoadFailure::new(
+                path,
+                LevelLoadErrorKind::Parse,
+                anyhow!("not a level file; expected .toml, .ron, or .json"),
+            ));
+        };
+        let mut level = parse_level_bytes(bytes, format, path)?;
+        // Dropped files live outside the levels directory
+        level.source_path = None;
+
+        if self.index_of(&level.name).is_some() {
+            let taken: HashSet<String> = self.levels.iter().map(|l| l.name.clone()).collect();
+            let unique = unique_name(&level.name, Some(path), &taken);
+            level.rename_unique(unique);
+        }
+
+        info!(
+            "Loaded dropped level '{level_name}' from {file_name}",
+            level_name = level.name
+        );
+        let index = self.add_level(level);
+        Ok(self.set_current_index(index))
+    }
+}
+
+/// Load a dropped file into `levels_resource`, reporting failures as events
+fn load_dropped_file(
+    file_name: &str,
+    bytes: &[u8],
+    levels_resource: &mut LevelsResource,
+    rejected: &mut EventWriter<LevelFileRejected>,
+) {
+    if let Err(failure) = levels_resource.add_dropped_level(file_name, bytes) {
+        warn!("Rejected dropped level file {failure}");
+        rejected.write(LevelFileRejected(failure));
+    }
+}
+
+/// System loading level files dropped onto a native window
+pub fn dropped_level_file_system(
+    mut drop_events: EventReader<FileDragAndDrop>,
+    mut levels_resource: ResMut<LevelsResource>,
+    mut rejected: EventWriter<LevelFileRejected>,
+) {
+    for event in drop_events.read() {
+        let FileDragAndDrop::DroppedFile { path_buf, .. } = event else {
+            continue;
+        };
+        let file_name = path_buf
+            .file_name()
+            .unwrap_or(path_buf.as_os_str())
+            .to_string_lossy();
+        match std::fs::read(path_buf) {
+            Ok(bytes) => load_dropped_file(&file_name, &bytes, &mut levels_resource, &mut rejected),
+            Err(err) => {
+                let failure = LevelLoadFailure::new(&*file_name, LevelLoadErrorKind::Io, err);
+                warn!("Rejected dropped level file {failure}");
+                rejected.write(LevelFileRejected(failure));
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub use browser::{BrowserDropQueue, browser_dropped_level_system, install_browser_drop_listener};
+
+/// DOM glue reading files dropped onto the page
+#[cfg(target_arch = "wasm32")]
+mod browser {
+    use std::sync::{Arc, Mutex};
+
+    use bevy::prelude::*;
+    use js_sys::Uint8Array;
+    use tracing::{info, warn};
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::DragEvent;
+
+    use super::{LevelFileRejected, load_dropped_file};
+    use crate::level::LevelsResource;
+
+    /// Dropped files as (file name, contents)
+    type DroppedFiles = Vec<(String, Vec<u8>)>;
+
+    /// Files read by the drop listener
+    #[derive(Resource, Debug, Clone, Default)]
+    pub struct BrowserDropQueue(Arc<Mutex<DroppedFiles>>);
+
+    /// Startup system listening for files dropped anywhere on the page,
+    /// including the canvas
+    pub fn install_browser_drop_listener(queue: Res<BrowserDropQueue>) {
+        let Some(document) = web_sys::window().and_then(|window| window.document()) else {
+            warn!("No document available; dropped level files will be ignored");
+            return;
+        };
+
+        // The browser only allows dropping where dragover is cancelled
+        let on_drag_over = Closure::<dyn FnMut(DragEvent)>::new(|event: DragEvent| {
+            event.prevent_default();
+        });
+        let queue = queue.0.clone();
+        let on_drop = Closure::<dyn FnMut(DragEvent)>::new(move |event: DragEvent| {
+            event.prevent_default();
+            let Some(files) = event.data_transfer().and_then(|transfer| transfer.files()) else {
+                return;
+            };
+            for index in 0..files.length() {
+                let Some(file) = files.item(index) else {
+                    continue;
+                };
+                let queue = queue.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let file_name = file.name();
+                    match JsFuture::from(file.array_buffer()).await {
+                        Ok(buffer) => {
+                            let bytes = Uint8Array::new(&buffer).to_vec();
+                            if let Ok(mut queue) = queue.lock() {
+                                queue.push((file_name, bytes));
+                            }
+                        }
+                        Err(err) => warn!("Failed to read dropped file {file_name}: {err:?}"),
+                    }
+                });
+            }
+        });
+
+        let listeners = [
+            ("dragover", on_drag_over.as_ref()),
+            ("drop", on_drop.as_ref()),
+        ];
+        for (event_name, listener) in listeners {
+            if let Err(err) =
+                document.add_event_listener_with_callback(event_name, listener.unchecked_ref())
+            {
+                warn!("Failed to listen for {event_name} events: {err:?}");
+            }
+        }
+        // The listeners live as long as the page
+        on_drag_over.forget();
+        on_drop.forget();
+        info!("Listening for level files dropped onto the page");
+    }
+
+    /// System loading the files read by the drop listener
+    pub fn browser_dropped_level_system(
+        queue: Res<BrowserDropQueue>,
+        mut levels_resource: ResMut<LevelsResource>,
+        mut rejected: EventWriter<LevelFileRejected>,
+    ) {
+        let dropped = match queue.0.lock() {
+            Ok(mut queue) if !queue.is_empty() => std::mem::take(&mut *queue),
+            _ => return,
+        };
+        for (file_name, bytes) in dropped {
+            load_dropped_file(&file_name, &bytes, &mut levels_resource, &mut rejected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::Level;
+    use crate::level::format::LevelFormat;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn toml_bytes(name: &str) -> Vec<u8> {
+        LevelFormat::Toml
+            .serialize(&Level::new(name.to_string(), 3, 3))
+            .unwrap()
+            .into_bytes()
+    }
+
+    #[test]
+    fn test_dropped_level_is_appended_and_selected() {
+        let mut levels = LevelsResource::new(vec![Level::new("Arena".to_string(), 3, 3)]);
+
+        let index = levels
+            .add_dropped_level("arena_v2.toml", &toml_bytes("Arena"))
+            .expect("Dropped level is valid");
+
+        assert_eq!(index, 1);
+        assert_eq!(levels.current_level_index, 1);
+        assert_eq!(levels.current_level().name, "Arena (arena_v2)");
+        assert_eq!(levels.current_level().saved_name(), "Arena");
+        assert_eq!(levels.current_level().source_path, None);
+    }
+
+    #[test]
+    fn test_invalid_dropped_files_are_rejected() {
+        let mut levels = LevelsResource::new(vec![Level::new("Arena".to_string(), 3, 3)]);
+
+        let not_level = levels
+            .add_dropped_level("notes.txt", b"hello")
+            .expect_err("Text files are not levels");
+        assert_eq!(not_level.path, PathBuf::from("notes.txt"));
+        let broken = levels
+            .add_dropped_level("broken.toml", b"width = ")
+            .expect_err("Broken TOML is rejected");
+        assert_eq!(broken.kind, LevelLoadErrorKind::Parse);
+
+        assert_eq!(levels.level_count(), 1);
+        assert_eq!(levels.current_level().name, "Arena");
+    }
+
+    #[test]
+    fn test_native_drop_events_load_levels() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let good = temp_dir.path().join("dropped.toml");
+        std::fs::write(&good, toml_bytes("Dropped")).unwrap();
+        let bad = temp_dir.path().join("bad.toml");
+        std::fs::write(&bad, "width = ").unwrap();
+
+        let mut app = App::new();
+        app.add_event::<FileDragAndDrop>()
+            .add_event::<LevelFileRejected>()
+            .insert_resource(LevelsResource::with_default())
+            .add_systems(Update, dropped_level_file_system);
+        for path_buf in [good, bad] {
+            app.world_mut().send_event(FileDragAndDrop::DroppedFile {
+                window: Entity::PLACEHOLDER,
+                path_buf,
+            });
+        }
+        app.update();
+
+        let levels = app.world().resource::<LevelsResource>();
+        assert_eq!(levels.current_level().name, "Dropped");
+        let rejected: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Events<LevelFileRejected>>()
+            .drain()
+            .collect();
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].0.path, PathBuf::from("bad.toml"));
+    }
+}
@@ -0,0 +1,322 @@
+//! Level Geometry Export
+//!
+//! Writes the rendered hex column geometry of a level to OBJ or glTF files for
+//! promotional renders and inspection in external tools. Native only.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use bevy::prelude::*;
+use serde_json::json;
+use tracing::info;
+
+use super::Level;
+use super::mesh::{HexMeshOptions, hex_column_mesh_info};
+
+/// File format written by [`Level::export_mesh`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeshExportFormat {
+    /// Wavefront OBJ with positions, normals, and UVs
+    Obj,
+    /// Self-contained glTF 2.0 JSON with the buffer embedded as a data URI
+    Gltf,
+}
+
+/// All hex columns of a level merged into one indexed triangle list
+struct CombinedMesh {
+    positions: Vec<Vec3>,
+    normals: Vec<Vec3>,
+    uvs: Vec<Vec2>,
+    indices: Vec<u32>,
+}
+
+impl Level {
+    /// Merge every hex column into world-space geometry, exactly as the game
+    /// spawns them (same layout, column base, and per-column mesh)
+    fn combined_column_mesh(&self, options: &HexMeshOptions) -> CombinedMesh {
+        let layout = self.hex_layout();
+        let column_base = self.column_base();
+        let mut combined = CombinedMesh {
+            positions: Vec::new(),
+            normals: Vec::new(),
+            uvs: Vec::new(),
+            indices: Vec::new(),
+        };
+
+        for hex in self.hex_iter() {
+            let world_pos = layout.hex_to_world_pos(hex);
+            let offset = Vec3::new(world_pos.x, column_base, world_pos.y);
+            let column = hex_column_mesh_info(&layout, self.get_height(hex) - column_base, options);
+
+            // Indices are u32 since large levels exceed u16 vertex counts
+            let index_offset = combined.positions.len() as u32;
+            combined
+                .indices
+                .extend(column.indices.iter().map(|&i| i + index_offset));
+            combined
+                .positions
+                .extend(column.vertices.iter().map(|&v| v + offset));
+            combined.normals.extend(column.normals);
+            combined.uvs.extend(column.uvs);
+        }
+
+        combined
+    }
+
+    /// Export the level's hex columns as a single combined mesh file
+    ///
+    /// Columns are built with `options`, so exports can close the bottom faces
+    /// the game leaves open. Water planes and ramps are not included. Parent
+    /// directories are created as needed.
+    pub fn export_mesh(
+        &self,
+        path: impl AsRef<Path>,
+        format: MeshExportFormat,
+        options: &HexMeshOptions,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let mesh = self.combined_column_mesh(options);
+        let contents = match format {
+            MeshExportFormat::Obj => obj_document(&mesh, &self.name),
+            MeshExportFormat::Gltf => serde_json::to_string(&gltf_document(&mesh, &self.name))
+                .context("Failed to serialize glTF document")?,
+        };
+
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "Failed to create directory: {parent}",
+                    parent = parent.display()
+                )
+            })?;
+        }
+        std::fs::write(path, contents).with_context(|| {
+            format!(
+                "Failed to write mesh to file: {path}",
+                path = path.display()
+            )
+        })?;
+
+        info!(
+            "Exported level '{level_name}' geometry to {path} ({format:?}, {vertices} vertices)",
+            level_name = self.name,
+            path = path.display(),
+            vertices = mesh.positions.len()
+        );
+        Ok(())
+    }
+}
+
+/// Format `mesh` as a Wavefront OBJ document
+fn obj_document(mesh: &CombinedMesh, name: &str) -> String {
+    let mut obj = format!("# SystemTactics level export\no {name}\n");
+    for v in &mesh.positions {
+        let _ = writeln!(obj, "v {x} {y} {z}", x = v.x, y = v.y, z = v.z);
+    }
+    for n in &mesh.normals {
+        let _ = writeln!(obj, "vn {x} {y} {z}", x = n.x, y = n.y, z = n.z);
+    }
+    // OBJ puts the UV origin at the bottom-left, Bevy at the top-left
+    for uv in &mesh.uvs {
+        let _ = writeln!(obj, "vt {u} {v}", u = uv.x, v = 1.0 - uv.y);
+    }
+    // OBJ indices are 1-based and every attribute shares the vertex index
+    for triangle in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [triangle[0] + 1, triangle[1] + 1, triangle[2] + 1];
+        let _ = writeln!(obj, "f {a}/{a}/{a} {b}/{b}/{b} {c}/{c}/{c}");
+    }
+    obj
+}
+
+/// Build a glTF 2.0 document for `mesh` with its binary buffer embedded
+fn gltf_document(mesh: &CombinedMesh, name: &str) -> serde_json::Value {
+    const FLOAT: u32 = 5126;
+    const UNSIGNED_INT: u32 = 5125;
+    const ARRAY_BUFFER: u32 = 34962;
+    const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+    let mut buffer = Vec::new();
+    let mut views = Vec::new();
+    // Every section holds 4-byte components, so offsets stay aligned
+    let mut push_view = |bytes: Vec<u8>, target: u32| {
+        views.push(json!({
+            "buffer": 0,
+            "byteOffset": buffer.len(),
+            "byteLength": bytes.len(),
+            "target": target,
+        }));
+        buffer.extend(bytes);
+    };
+    push_view(
+        mesh.positions
+            .iter()
+            .flat_map(|v| v.to_array())
+            .flat_map(f32::to_le_bytes)
+            .collect(),
+        ARRAY_BUFFER,
+    );
+    push_view(
+        mesh.normals
+            .iter()
+            .flat_map(|n| n.to_array())
+            .flat_map(f32::to_le_bytes)
+            .collect(),
+        ARRAY_BUFFER,
+    );
+    push_view(
+        mesh.uvs
+            .iter()
+            .flat_map(|uv| uv.to_array())
+            .flat_map(f32::to_le_bytes)
+            .collect(),
+        ARRAY_BUFFER,
+    );
+    push_view(
+        mesh.indices.iter().flat_map(|i| i.to_le_bytes()).collect(),
+        ELEMENT_ARRAY_BUFFER,
+    );
+
+    // glTF requires position bounds
+    let min = mesh
+        .positions
+        .iter()
+        .copied()
+        .reduce(Vec3::min)
+        .unwrap_or(Vec3::ZERO);
+    let max = mesh
+        .positions
+        .iter()
+        .copied()
+        .reduce(Vec3::max)
+        .unwrap_or(Vec3::ZERO);
+    let vertex_count = mesh.positions.len();
+
+    json!({
+        "asset": { "version": "2.0", "generator": "SystemTactics" },
+        "scene": 0,
+        "scenes": [{ "name": name, "nodes": [0] }],
+        "nodes": [{ "name": name, "mesh": 0 }],
+        "meshes": [{
+            "name": name,
+            "primitives": [{
+                "attributes": { "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2 },
+                "indices": 3,
+                "mode": 4,
+            }],
+        }],
+        "accessors": [
+            {
+                "bufferView": 0,
+                "componentType": FLOAT,
+                "count": vertex_count,
+                "type": "VEC3",
+                "min": min.to_array(),
+                "max": max.to_array(),
+            },
+            { "bufferView": 1, "componentType": FLOAT, "count": vertex_count, "type": "VEC3" },
+            { "bufferView": 2, "componentType": FLOAT, "count": vertex_count, "type": "VEC2" },
+            {
+                "bufferView": 3,
+                "componentType": UNSIGNED_INT,
+                "count": mesh.indices.len(),
+                "type": "SCALAR",
+            },
+        ],
+        "bufferViews": views,
+        "buffers": [{
+            "byteLength": buffer.len(),
+            "uri": format!(
+                "data:application/octet-stream;base64,{data}",
+                data = base64::engine::general_purpose::STANDARD.encode(&buffer)
+            ),
+        }],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use tempfile::TempDir;
+
+    fn export_level() -> Level {
+        LevelBuilder::new()
+            .name("Export")
+            .dimensions(4, 3)
+            .gradient(1.0, 3.0)
+            .build()
+            .unwrap()
+    }
+
+    fn vertices_per_hex(level: &Level, options: &HexMeshOptions) -> usize {
+        hex_column_mesh_info(&level.hex_layout(), 1.0, options)
+            .vertices
+            .len()
+    }
+
+    #[test]
+    fn test_obj_export_contains_every_column() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let path = temp_dir.path().join("renders/export.obj");
+        let level = export_level();
+
+        level
+            .export_mesh(&path, MeshExportFormat::Obj, &HexMeshOptions::default())
+            .expect("Failed to export OBJ");
+
+        let obj = std::fs::read_to_string(&path).expect("Failed to read OBJ");
+        let expected = (level.width * level.height) as usize
+            * vertices_per_hex(&level, &HexMeshOptions::default());
+        let count = |prefix: &str| obj.lines().filter(|line| line.starts_with(prefix)).count();
+        assert_eq!(count("v "), expected);
+        assert_eq!(count("vn "), expected);
+        assert_eq!(count("vt "), expected);
+
+        for line in obj.lines().filter(|line| line.starts_with("f ")) {
+            let corners: Vec<&str> = line.split_whitespace().skip(1).collect();
+            assert_eq!(corners.len(), 3, "Face is not a triangle: {line}");
+            for corner in corners {
+                for index in corner.split('/') {
+                    let index: usize = index.parse().expect("Face index is not a number");
+                    assert!(
+                        (1..=expected).contains(&index),
+                        "Index out of range: {line}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gltf_export_parses() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let path = temp_dir.path().join("export.gltf");
+        let level = export_level();
+        let closed = HexMeshOptions {
+            bottom_face: true,
+            ..default()
+        };
+
+        level
+            .export_mesh(&path, MeshExportFormat::Gltf, &closed)
+            .expect("Failed to export glTF");
+
+        let text = std::fs::read_to_string(&path).expect("Failed to read glTF");
+        let document: serde_json::Value = serde_json::from_str(&text).expect("Invalid JSON");
+        let expected = (level.width * level.height) as usize * vertices_per_hex(&level, &closed);
+        assert_eq!(document["accessors"][0]["count"], expected);
+
+        let uri = document["buffers"][0]["uri"].as_str().unwrap();
+        let data = uri
+            .strip_prefix("data:application/octet-stream;base64,")
+            .expect("Buffer should be embedded");
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(data)
+            .expect("Invalid base64 buffer");
+        assert_eq!(document["buffers"][0]["byteLength"], bytes.len());
+    }
+}
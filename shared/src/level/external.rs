@@ -0,0 +1,216 @@
+//! External Level File Changes
+//!
+//! Detects level files edited outside the app while they are loaded, so the
+//! editor can offer to reload them instead of silently overwriting the edits on
+//! save. Files are compared by content hash whenever the window regains focus
+//! and on a timer. A file whose content matches the loaded level, as after the
+//! app's own save or a hot reload, is not reported.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::window::WindowFocused;
+use tracing::info;
+
+use super::format::LevelFormat;
+use super::hash::hash_bytes;
+use super::{Level, LevelDirectoryOverride, LevelsResource, levels_directory, parse_level_bytes};
+
+/// How often loaded level files are checked while the window keeps focus
+pub const EXTERNAL_CHANGE_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A loaded level's file changed on disk
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct LevelFileChangedOnDisk {
+    /// Index of the level in [`LevelsResource`]
+    pub index: usize,
+    /// File path relative to the levels directory
+    pub source_path: PathBuf,
+    /// Whether reloading would discard in-memory edits
+    pub has_unsaved_edits: bool,
+}
+
+/// Content hash of each loaded level file as last seen on disk
+#[derive(Resource, Debug, Default)]
+pub struct LevelFileStamps {
+    hashes: HashMap<PathBuf, u64>,
+}
+
+impl LevelFileStamps {
+    /// Compare the files behind `levels` with their last seen content
+    ///
+    /// Files seen for the first time are only recorded. Each change is reported
+    /// once; unreadable files are skipped until they can be read again.
+    pub fn check(
+        &mut self,
+        levels_dir: &Path,
+        levels: &LevelsResource,
+    ) -> Vec<LevelFileChangedOnDisk> {
+        let mut changes = Vec::new();
+        for (index, level) in levels.levels.iter().enumerate() {
+            let Some(source_path) = &level.source_path else {
+                continue;
+            };
+            let Ok(bytes) = fs::read(levels_dir.join(source_path)) else {
+                continue;
+            };
+            let hash = hash_bytes(&bytes);
+            let Some(previous) = self.hashes.insert(source_path.clone(), hash) else {
+                continue;
+            };
+            if previous == hash || matches_loaded_level(&bytes, level) {
+                continue;
+            }
+
+            info!(
+                "Level file {path} changed on disk",
+                path = source_path.display()
+            );
+            changes.push(LevelFileChangedOnDisk {
+                index,
+                source_path: source_path.clone(),
+                has_unsaved_edits: levels.is_modified(index),
+            });
+        }
+        changes
+    }
+}
+
+/// Whether `bytes` parse to the same content as the loaded `level`
+fn matches_loaded_level(bytes: &[u8], level: &Level) -> bool {
+    let Some(source_path) = &level.source_path else {
+        return false;
+    };
+    let format = LevelFormat::from_path(source_path).unwrap_or_default();
+    let Ok(mut on_disk) = parse_level_bytes(bytes, format, source_path) else {
+        return false;
+    };
+    // The file keeps its own name when loading renamed a duplicate
+    if on_disk.name == level.saved_name() {
+        on_disk.name = level.name.clone();
+    }
+    on_disk.content_hash() == level.content_hash()
+}
+
+/// System checking loaded level files for external changes when the window
+/// regains focus and every [`EXTERNAL_CHANGE_CHECK_INTERVAL`]
+pub fn external_change_check_system(
+    time: Res<Time>,
+    mut focus_events: EventReader<WindowFocused>,
+    mut check_timer: Local<Option<Timer>>,
+    mut stamps: ResMut<LevelFileStamps>,
+    levels_resource: Res<LevelsResource>,
+    directory_override: Option<Res<LevelDirectoryOverride>>,
+    mut changed: EventWriter<LevelFileChangedOnDisk>,
+) {
+    let timer = check_timer
+        .get_or_insert_with(|| Timer::new(EXTERNAL_CHANGE_CHECK_INTERVAL, TimerMode::Repeating));
+    let timer_fired = timer.tick(time.delta()).just_finished();
+    let focus_gained = focus_events.read().any(|event| event.focused);
+    if !timer_fired && !focus_gained {
+        return;
+    }
+
+    let levels_dir = levels_directory(directory_override.as_deref());
+    changed.write_batch(stamps.check(&levels_dir, &levels_resource));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use hexx::Hex;
+    use tempfile::TempDir;
+
+    fn save(temp_path: &str, height: f32) {
+        LevelBuilder::new()
+            .name("Arena")
+            .dimensions(3, 3)
+            .flat(height)
+            .build()
+            .unwrap()
+            .save_to_directory(temp_path, "arena.toml")
+            .expect("Failed to save level");
+    }
+
+    #[test]
+    fn test_external_edit_is_reported_once() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        save(temp_path, 1.0);
+        let mut levels = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let mut stamps = LevelFileStamps::default();
+        assert!(stamps.check(temp_dir.path(), &levels).is_empty());
+
+        levels.levels[0].set_height(Hex::new(0, 0), 3.0).unwrap();
+        save(temp_path, 2.0);
+
+        let changes = stamps.check(temp_dir.path(), &levels);
+        assert_eq!(
+            changes,
+            [LevelFileChangedOnDisk {
+                index: 0,
+                source_path: PathBuf::from("arena.toml"),
+                has_unsaved_edits: true,
+            }]
+        );
+        assert!(stamps.check(temp_dir.path(), &levels).is_empty());
+    }
+
+    #[test]
+    fn test_own_save_is_not_reported() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        save(temp_path, 1.0);
+        let mut levels = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let mut stamps = LevelFileStamps::default();
+        stamps.check(temp_dir.path(), &levels);
+
+        levels.levels[0].set_height(Hex::new(1, 1), 4.0).unwrap();
+        levels.levels[0]
+            .save_to_directory(temp_path, "arena.toml")
+            .unwrap();
+
+        assert!(stamps.check(temp_dir.path(), &levels).is_empty());
+    }
+
+    #[test]
+    fn test_check_system_fires_on_focus() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        save(temp_path, 1.0);
+        let levels = load_levels_from_directory(temp_path).expect("Failed to load levels");
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_event::<WindowFocused>()
+            .add_event::<LevelFileChangedOnDisk>()
+            .insert_resource(levels)
+            .insert_resource(LevelDirectoryOverride(temp_dir.path().to_path_buf()))
+            .init_resource::<LevelFileStamps>()
+            .add_systems(Update, external_change_check_system);
+        let focus = |app: &mut App| {
+            app.world_mut().send_event(WindowFocused {
+                window: Entity::PLACEHOLDER,
+                focused: true,
+            });
+            app.update();
+        };
+
+        focus(&mut app);
+        save(temp_path, 2.0);
+        focus(&mut app);
+
+        let changes: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Events<LevelFileChangedOnDisk>>()
+            .drain()
+            .collect();
+        assert_eq!(changes.len(), 1);
+        assert!(!changes[0].has_unsaved_edits);
+    }
+}
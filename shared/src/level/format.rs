@@ -0,0 +1,236 @@
+//! Level File Formats
+//!
+//! Serialization formats level files can be stored in, picked by file extension.
+//! Every format uses the same serde representation, so fields carry over as-is.
+
+use std::fmt;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use ron::ser::PrettyConfig;
+
+use super::Level;
+use super::compression::is_gzip_path;
+
+/// A supported level file format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LevelFormat {
+    /// `.toml` files, the original format
+    #[default]
+    Toml,
+    /// `.ron` files, whose inline arrays are easier to hand-edit for large grids
+    ///
+    /// RON files always store numeric heights; `heights_by_preset` is TOML-only.
+    Ron,
+    /// `.json` files, as emitted by external map tools
+    Json,
+}
+
+impl LevelFormat {
+    /// Every supported format
+    pub const ALL: [LevelFormat; 3] = [LevelFormat::Toml, LevelFormat::Ron, LevelFormat::Json];
+
+    /// Detect the format from a path's extension, or `None` if it is not a level file
+    ///
+    /// A trailing `.gz` is skipped, so `arena.toml.gz` is a TOML level.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let mut path = path.as_ref();
+        if is_gzip_path(path) {
+            path = Path::new(path.file_stem()?);
+        }
+        let extension = path.extension()?.to_str()?;
+        Self::ALL
+            .into_iter()
+            .find(|format| extension.eq_ignore_ascii_case(format.extension()))
+    }
+
+    /// File extension used for this format, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Toml => "toml",
+            Self::Ron => "ron",
+            Self::Json => "json",
+        }
+    }
+
+    /// Parse a level from file contents in this format
+    pub fn parse(self, content: &str) -> Result<Level> {
+        match self {
+            Self::Toml => toml::from_str(content).context("Failed to parse TOML level"),
+            Self::Ron => {
+                // RON cannot read the flattened wrapper behind `heights_by_preset`, so
+                // RON files go through the plain field-by-field representation
+                let mut deserializer =
+                    ron::Deserializer::from_str(content).context("Failed to parse RON level")?;
+                let level = Level::deserialize(&mut deserializer)
+                    .and_then(|level| deserializer.end().map(|()| level))
+                    .context("Failed to parse RON level")?;
+                if level.heights.is_empty() {
+                    bail!("Failed to parse RON level: missing field `heights`");
+                }
+                Ok(level)
+            }
+            Self::Json => serde_json::from_str(content).context("Failed to parse JSON level"),
+        }
+    }
+
+    /// Serialize a level into this format
+    pub fn serialize(self, level: &Level) -> Result<String> {
+        match self {
+            Self::Toml => toml::to_string(level).context("Failed to serialize level to TOML"),
+            Self::Ron => {
+                // Keep each array on one line so height grids stay readable
+                let config = PrettyConfig::new().compact_arrays(true);
+                ron::ser::to_string_pretty(level, config)
+                    .context("Failed to serialize level to RON")
+            }
+            Self::Json => {
+                serde_json::to_string_pretty(level).context("Failed to serialize level to JSON")
+            }
+        }
+    }
+}
+
+impl fmt::Display for LevelFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Toml => "TOML",
+            Self::Ron => "RON",
+            Self::Json => "JSON",
+        })
+    }
+}
+
+impl Level {
+    /// Parse a level from JSON using the same schema as TOML level files
+    pub fn from_json_str(content: &str) -> Result<Level> {
+        LevelFormat::Json.parse(content)
+    }
+
+    /// Serialize this level to pretty-printed JSON
+    pub fn to_json_string(&self) -> Result<String> {
+        LevelFormat::Json.serialize(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use crate::level::ramps::Ramp;
+    use hexx::Hex;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(LevelFormat::from_path("a/b.toml"), Some(LevelFormat::Toml));
+        assert_eq!(LevelFormat::from_path("b.RON"), Some(LevelFormat::Ron));
+        assert_eq!(LevelFormat::from_path("notes.txt"), None);
+        assert_eq!(LevelFormat::from_path("README"), None);
+    }
+
+    #[test]
+    fn test_ron_roundtrip() {
+        let mut level = LevelBuilder::new()
+            .name("Canyon")
+            .dimensions(6, 4)
+            .with_height(Hex::new(2, 1), 3.5)
+            .build()
+            .unwrap();
+        level.set_walkable(Hex::new(0, 0), false).unwrap();
+        level.water_level = Some(1.5);
+        level.ramps.push(Ramp::new(Hex::new(1, 1), Hex::new(2, 1)));
+
+        let ron = LevelFormat::Ron
+            .serialize(&level)
+            .expect("Failed to write RON");
+        let parsed = LevelFormat::Ron.parse(&ron).expect("Failed to parse RON");
+
+        assert_eq!(parsed.content_hash(), level.content_hash());
+        assert_eq!(parsed.name, "Canyon");
+        assert_eq!(parsed.heights, level.heights);
+    }
+
+    /// External-facing JSON schema; grids are nested rows, stored as [row][col]
+    const JSON_FIXTURE: &str = r#"{
+        "name": "Sketch",
+        "width": 3,
+        "height": 2,
+        "heights": [[1.0, 1.5, 2.0], [2.5, 3.0, 3.5]],
+        "walkable": [[true, true, false], [true, true, true]],
+        "water_level": 1.25,
+        "spawn_points": [{ "hex": [0, 1], "team": "player" }]
+    }"#;
+
+    #[test]
+    fn test_json_fixture_schema() {
+        let level = Level::from_json_str(JSON_FIXTURE).expect("Fixture should parse");
+
+        assert_eq!(level.name, "Sketch");
+        assert_eq!(level.get_height(Hex::new(2, 0)), 2.0);
+        assert_eq!(level.get_height(Hex::new(0, 1)), 2.5);
+        assert!(!level.is_walkable(Hex::new(2, 0)));
+        assert_eq!(level.water_level, Some(1.25));
+        assert_eq!(level.spawn_points[0].coord(), Hex::new(0, 1));
+        assert!(level.validate().is_ok());
+    }
+
+    #[test]
+    fn test_json_grids_are_nested_arrays() {
+        let level = Level::from_json_str(JSON_FIXTURE).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&level.to_json_string().unwrap()).unwrap();
+
+        assert_eq!(
+            json["heights"],
+            serde_json::json!([[1.0, 1.5, 2.0], [2.5, 3.0, 3.5]])
+        );
+        assert_eq!(
+            json["walkable"],
+            serde_json::json!([[true, true, false], [true, true, true]])
+        );
+        let reparsed = Level::from_json_str(&level.to_json_string().unwrap()).unwrap();
+        assert_eq!(reparsed.content_hash(), level.content_hash());
+    }
+
+    #[test]
+    fn test_json_accepts_ndarray_layout() {
+        let legacy = r#"{
+            "name": "Legacy",
+            "width": 3,
+            "height": 2,
+            "heights": { "v": 1, "dim": [2, 3], "data": [1.0, 1.5, 2.0, 2.5, 3.0, 3.5] }
+        }"#;
+        let level = Level::from_json_str(legacy).expect("Old layout should parse");
+        let current = Level::from_json_str(JSON_FIXTURE).unwrap();
+        assert_eq!(level.heights, current.heights);
+    }
+
+    #[test]
+    fn test_mixed_directory_loads_all_formats() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let names = ["Alpha", "Bravo", "Charlie"];
+        for (name, file) in names
+            .iter()
+            .zip(["alpha.toml", "bravo.ron", "charlie.json"])
+        {
+            LevelBuilder::new()
+                .name(*name)
+                .dimensions(3, 3)
+                .build()
+                .unwrap()
+                .save_to_directory(temp_path, file)
+                .expect("Failed to save level");
+        }
+
+        let bravo = std::fs::read_to_string(temp_dir.path().join("bravo.ron")).unwrap();
+        assert!(bravo.contains("name: \"Bravo\""), "Saved: {bravo}");
+
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let loaded_names: Vec<_> = loaded.levels.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(loaded_names, names);
+    }
+}
@@ -0,0 +1,441 @@
+//! Procedural Level Generation
+//!
+//! Deterministic terrain generation for tactical RPG levels, producing height
+//! data from seeded value noise so the same parameters always yield the same map.
+
+use tracing::info;
+
+use super::Level;
+
+/// Deterministic base terrain shapes for [`Level::generate`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HeightGenerator {
+    /// Every hex at the same height
+    Flat(f32),
+    /// Linear ramp from the front-left corner (`from`) to the back-right corner (`to`)
+    Gradient { from: f32, to: f32 },
+    /// Radial rise from `base` at the corners to `peak` at the center
+    Hill { base: f32, peak: f32 },
+    /// Radial dip from `rim` at the corners to `floor` at the center
+    Bowl { rim: f32, floor: f32 },
+}
+
+impl Default for HeightGenerator {
+    /// The front-left to back-right gradient used by [`Level::new`]
+    fn default() -> Self {
+        Self::Gradient { from: 1.0, to: 4.0 }
+    }
+}
+
+impl HeightGenerator {
+    /// Height of the hex at column `q` and row `r` in a `width` x `height` grid
+    pub fn height_at(&self, q: i32, r: i32, width: i32, height: i32) -> f32 {
+        // Single-row or single-column levels would divide by zero when normalizing
+        let q_norm = q as f32 / (width - 1).max(1) as f32;
+        let r_norm = r as f32 / (height - 1).max(1) as f32;
+        // 0.0 at the center, 1.0 at the corners
+        let radial = || {
+            let dq = q_norm * 2.0 - 1.0;
+            let dr = r_norm * 2.0 - 1.0;
+            ((dq * dq + dr * dr) / 2.0).sqrt()
+        };
+
+        match *self {
+            Self::Flat(height) => height,
+            Self::Gradient { from, to } => from + (q_norm + r_norm) / 2.0 * (to - from),
+            Self::Hill { base, peak } => peak + radial() * (base - peak),
+            Self::Bowl { rim, floor } => floor + radial() * (rim - floor),
+        }
+    }
+}
+
+/// Parameters controlling noise-based terrain generation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseParams {
+    /// Seed selecting the noise pattern; equal seeds produce identical terrain
+    pub seed: u64,
+    /// Number of noise layers summed together (more octaves = finer detail)
+    pub octaves: u32,
+    /// Spatial frequency of the first octave in noise cycles per world unit
+    pub frequency: f32,
+    /// Maximum deviation from `base_height` before clamping
+    pub amplitude: f32,
+    /// Height the noise is centered around
+    pub base_height: f32,
+    /// Lowest height a generated hex may have
+    pub min_height: f32,
+    /// Highest height a generated hex may have
+    pub max_height: f32,
+}
+
+impl Default for NoiseParams {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            octaves: 4,
+            frequency: 0.15,
+            amplitude: 2.0,
+            base_height: 2.0,
+            min_height: 0.5,
+            max_height: 5.0,
+        }
+    }
+}
+
+/// Hash a lattice point and seed into a pseudo-random value in [0, 1)
+///
+/// Uses SplitMix64 finalization so results are identical on every platform.
+fn lattice_value(x: i32, y: i32, seed: u64) -> f32 {
+    let mut hash = seed
+        ^ (x as u32 as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        ^ (y as u32 as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F);
+    hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    hash ^= hash >> 31;
+    // Keep the top 24 bits so the result is exactly representable as f32
+    (hash >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Sample smooth 2D value noise in [0, 1) at a continuous position
+fn value_noise(x: f32, y: f32, seed: u64) -> f32 {
+    let x0 = x.floor();
+    let y0 = y.floor();
+    let (ix, iy) = (x0 as i32, y0 as i32);
+
+    // Smoothstep fade removes visible grid creases between lattice cells
+    let fade = |t: f32| t * t * (3.0 - 2.0 * t);
+    let tx = fade(x - x0);
+    let ty = fade(y - y0);
+
+    let top = lattice_value(ix, iy, seed) * (1.0 - tx) + lattice_value(ix + 1, iy, seed) * tx;
+    let bottom =
+        lattice_value(ix, iy + 1, seed) * (1.0 - tx) + lattice_value(ix + 1, iy + 1, seed) * tx;
+    top * (1.0 - ty) + bottom * ty
+}
+
+/// Sum `octaves` layers of value noise, normalized to [-1, 1]
+fn fractal_noise(position: (f32, f32), params: &NoiseParams) -> f32 {
+    let mut total = 0.0;
+    let mut weight = 1.0;
+    let mut weight_sum = 0.0;
+    let mut frequency = params.frequency;
+
+    for octave in 0..params.octaves.max(1) {
+        // Offset each octave's seed so layers don't share lattice values
+        let octave_seed = params
+            .seed
+            .wrapping_add(u64::from(octave).wrapping_mul(0x632B_E59B_D9B4_E019));
+        let sample = value_noise(position.0 * frequency, position.1 * frequency, octave_seed);
+        total += (sample * 2.0 - 1.0) * weight;
+        weight_sum += weight;
+        weight *= 0.5;
+        frequency *= 2.0;
+    }
+
+    total / weight_sum
+}
+
+impl Level {
+    /// Create a level with deterministic noise-based terrain
+    ///
+    /// Noise is sampled at each hex's world position so terrain features are not
+    /// skewed by the axial grid layout. Heights are clamped between
+    /// `params.min_height` and `params.max_height`, in either order; a NaN bound
+    /// is ignored.
+    pub fn from_noise(name: String, width: i32, height: i32, params: NoiseParams) -> Self {
+        let mut level = Self::new(name, width, height);
+        level.fill_heights_from_noise(params);
+
+        info!(
+            "Generated noise level '{level_name}' ({width}x{height}) with seed {seed}",
+            level_name = level.name,
+            seed = params.seed
+        );
+        level
+    }
+
+    /// Replace every height with noise terrain, as in [`Level::from_noise`]
+    ///
+    /// Only the height grid changes; the level keeps its file, layout,
+    /// walkability, overlays, and other settings.
+    pub fn fill_heights_from_noise(&mut self, params: NoiseParams) {
+        let hex_layout = self.hex_layout();
+        let lowest = params.min_height.min(params.max_height);
+        let highest = params.min_height.max(params.max_height);
+
+        for ((row, col), value) in self.heights.indexed_iter_mut() {
+            let world_pos = hex_layout.hex_to_world_pos(hexx::Hex::new(col as i32, row as i32));
+            let noise = fractal_noise((world_pos.x, world_pos.y), &params);
+            // max/min rather than clamp, which panics on NaN bounds
+            *value = (params.base_height + noise * params.amplitude)
+                .max(lowest)
+                .min(highest);
+        }
+        self.invalidate_geometry();
+    }
+
+    /// Perturb every height by a deterministic offset in `-amplitude..=amplitude`
+    ///
+    /// Offsets are hashed from the seed and each hex's coordinates, so the same
+    /// seed roughens a map identically on every run and platform. Results are
+    /// clamped to stay at or above 0.0, or the lowest height already in the
+    /// level if that is below zero, so trenches survive.
+    pub fn add_noise(&mut self, seed: u64, amplitude: f32) {
+        let floor = self.heights.iter().copied().fold(0.0, f32::min);
+        for ((row, col), value) in self.heights.indexed_iter_mut() {
+            let offset = (lattice_value(col as i32, row as i32, seed) * 2.0 - 1.0) * amplitude;
+            *value = (*value + offset).max(floor);
+        }
+        self.invalidate_geometry();
+
+        info!(
+            "Added noise to level '{level_name}' with seed {seed} and amplitude {amplitude}",
+            level_name = self.name
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use tempfile::TempDir;
+
+    fn corner_heights(generator: HeightGenerator) -> [f32; 4] {
+        let level = Level::generate("Corners".to_string(), 5, 5, generator);
+        [(0, 0), (4, 0), (0, 4), (4, 4)].map(|(q, r)| level.get_height(hexx::Hex::new(q, r)))
+    }
+
+    #[test]
+    fn test_flat_generator() {
+        assert_eq!(corner_heights(HeightGenerator::Flat(2.5)), [2.5; 4]);
+    }
+
+    #[test]
+    fn test_gradient_generator() {
+        let generator = HeightGenerator::Gradient { from: 1.0, to: 4.0 };
+        assert_eq!(corner_heights(generator), [1.0, 2.5, 2.5, 4.0]);
+        assert_eq!(generator, HeightGenerator::default());
+    }
+
+    #[test]
+    fn test_hill_and_bowl_generators() {
+        let hill = HeightGenerator::Hill {
+            base: 1.0,
+            peak: 5.0,
+        };
+        let bowl = HeightGenerator::Bowl {
+            rim: 5.0,
+            floor: 1.0,
+        };
+        let center = hexx::Hex::new(2, 2);
+
+        for corner in corner_heights(hill) {
+            assert!((corner - 1.0).abs() < 1e-5, "Hill corner {corner}");
+        }
+        for corner in corner_heights(bowl) {
+            assert!((corner - 5.0).abs() < 1e-5, "Bowl corner {corner}");
+        }
+        assert_eq!(
+            Level::generate("Hill".to_string(), 5, 5, hill).get_height(center),
+            5.0
+        );
+        assert_eq!(
+            Level::generate("Bowl".to_string(), 5, 5, bowl).get_height(center),
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_fixed_seed_is_deterministic() {
+        let params = NoiseParams {
+            seed: 42,
+            ..Default::default()
+        };
+        let first = Level::from_noise("Noise".to_string(), 12, 9, params);
+        let second = Level::from_noise("Noise".to_string(), 12, 9, params);
+
+        assert_eq!(first.heights, second.heights);
+        assert_eq!(first.heights.shape(), &[9, 12]);
+
+        // Pinned values catch accidental changes to the noise algorithm, which
+        // would silently alter every level generated from a saved seed
+        assert!((first.heights[(0, 0)] - 1.769679).abs() < 1e-5);
+        assert!((first.heights[(2, 1)] - 1.8746696).abs() < 1e-5);
+
+        assert!(
+            first
+                .heights
+                .iter()
+                .any(|&h| (h - first.heights[(0, 0)]).abs() > 0.01),
+            "Noise terrain should not be flat"
+        );
+    }
+
+    #[test]
+    fn test_different_seeds_produce_different_terrain() {
+        let a = Level::from_noise(
+            "A".to_string(),
+            10,
+            10,
+            NoiseParams {
+                seed: 1,
+                ..Default::default()
+            },
+        );
+        let b = Level::from_noise(
+            "B".to_string(),
+            10,
+            10,
+            NoiseParams {
+                seed: 2,
+                ..Default::default()
+            },
+        );
+        assert_ne!(a.heights, b.heights);
+    }
+
+    #[test]
+    fn test_heights_are_clamped_to_range() {
+        let params = NoiseParams {
+            seed: 7,
+            amplitude: 50.0,
+            min_height: 1.0,
+            max_height: 3.0,
+            ..Default::default()
+        };
+        let level = Level::from_noise("Clamped".to_string(), 15, 15, params);
+
+        for &height in level.heights.iter() {
+            assert!(
+                (1.0..=3.0).contains(&height),
+                "Height {height} out of range"
+            );
+        }
+    }
+
+    #[test]
+    fn test_filling_heights_keeps_the_rest_of_the_level() {
+        let mut level = LevelBuilder::new()
+            .name("Keep")
+            .dimensions(8, 6)
+            .orientation(crate::level::HexOrientation::Flat)
+            .hex_scale(2.0)
+            .build()
+            .unwrap();
+        level.source_path = Some("keep.toml".into());
+        level.water_level = Some(0.5);
+        level.set_walkable(hexx::Hex::new(1, 1), false).unwrap();
+        let before = level.geometry();
+
+        let params = NoiseParams {
+            seed: 5,
+            ..Default::default()
+        };
+        level.fill_heights_from_noise(params);
+
+        assert_eq!(level.source_path.as_deref(), Some("keep.toml".as_ref()));
+        assert_eq!(level.orientation, crate::level::HexOrientation::Flat);
+        assert_eq!(level.hex_scale, 2.0);
+        assert_eq!(level.water_level, Some(0.5));
+        assert!(!level.is_walkable(hexx::Hex::new(1, 1)));
+        assert_ne!(level.geometry(), before, "Cached geometry is refreshed");
+        assert!(
+            level
+                .heights
+                .iter()
+                .all(|&height| (params.min_height..=params.max_height).contains(&height))
+        );
+    }
+
+    #[test]
+    fn test_inverted_and_nan_bounds_do_not_panic() {
+        let inverted = NoiseParams {
+            seed: 7,
+            amplitude: 50.0,
+            min_height: 3.0,
+            max_height: 1.0,
+            ..Default::default()
+        };
+        let level = Level::from_noise("Inverted".to_string(), 15, 15, inverted);
+        for &height in level.heights.iter() {
+            assert!(
+                (1.0..=3.0).contains(&height),
+                "Height {height} out of swapped range"
+            );
+        }
+
+        let nan = NoiseParams {
+            seed: 7,
+            amplitude: 50.0,
+            min_height: f32::NAN,
+            max_height: 3.0,
+            ..Default::default()
+        };
+        let level = Level::from_noise("NaN".to_string(), 15, 15, nan);
+        assert!(level.heights.iter().all(|&height| height <= 3.0));
+    }
+
+    #[test]
+    fn test_add_noise_is_seeded_and_bounded() {
+        let flat = LevelBuilder::new()
+            .dimensions(8, 6)
+            .flat(0.5)
+            .build()
+            .unwrap();
+
+        let mut first = flat.clone();
+        first.add_noise(11, 1.0);
+        let mut second = flat.clone();
+        second.add_noise(11, 1.0);
+        let mut other = flat.clone();
+        other.add_noise(12, 1.0);
+
+        assert_eq!(first.heights, second.heights);
+        assert_ne!(first.heights, other.heights);
+        assert_ne!(first.heights, flat.heights);
+
+        for &height in first.heights.iter() {
+            assert!(
+                (0.0..=1.5).contains(&height),
+                "Height {height} out of range"
+            );
+        }
+        assert!(
+            first.heights.iter().any(|&height| height == 0.0),
+            "Amplitude larger than the base height should clamp some hexes to 0"
+        );
+    }
+
+    #[test]
+    fn test_add_noise_keeps_negative_heights() {
+        let mut level = LevelBuilder::new()
+            .dimensions(8, 6)
+            .flat(1.0)
+            .with_height(hexx::Hex::new(2, 2), -3.0)
+            .with_height(hexx::Hex::new(5, 3), -1.5)
+            .build()
+            .unwrap();
+        level.add_noise(11, 0.5);
+
+        let trench = level.get_height(hexx::Hex::new(2, 2));
+        assert!((-3.0..=-2.5).contains(&trench), "Trench at {trench}");
+        assert!(level.get_height(hexx::Hex::new(5, 3)) < -1.0);
+        assert!(level.heights.iter().all(|&height| height >= -3.0));
+    }
+
+    #[test]
+    fn test_noise_level_save_and_load_roundtrip() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let level = Level::from_noise("Noisy".to_string(), 6, 4, NoiseParams::default());
+        level
+            .save_to_directory(temp_path, "noisy.toml")
+            .expect("Failed to save level");
+
+        let levels_resource = load_levels_from_directory(temp_path).expect("Failed to load");
+        assert_eq!(levels_resource.current_level().heights, level.heights);
+    }
+}
@@ -0,0 +1,99 @@
+//! Cached Level Geometry
+//!
+//! World-space bounds, center, and diagonal extent derived from a level's grid,
+//! computed once and reused by camera systems until the level is edited.
+
+use std::sync::OnceLock;
+
+use bevy::prelude::*;
+
+use super::Level;
+
+/// World-space geometry derived from a level, returned by [`Level::geometry`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelGeometry {
+    /// Minimum corner of the bounding box, as from [`Level::get_world_bounds`]
+    pub min_bounds: Vec3,
+    /// Maximum corner of the bounding box, as from [`Level::get_world_bounds`]
+    pub max_bounds: Vec3,
+    /// Average position of the center hexes, as from [`Level::get_center_world_pos`]
+    pub center: Vec3,
+    /// 3D bounding box diagonal, as from [`Level::get_level_diagonal_extent`]
+    pub diagonal_extent: f32,
+}
+
+impl Level {
+    /// Get this level's derived world-space geometry, computing it on first use
+    ///
+    /// Editing methods such as [`Level::set_height`], [`Level::resize`], and the
+    /// mirror/rotate transforms reset the cache. Code that writes level fields
+    /// directly (heights, dimensions, layout, or water level) must call
+    /// [`Level::invalidate_geometry`] afterwards.
+    pub fn geometry(&self) -> LevelGeometry {
+        *self.geometry_cache.get_or_init(|| {
+            let (min_bounds, max_bounds) = self.get_world_bounds();
+            LevelGeometry {
+                min_bounds,
+                max_bounds,
+                center: self.get_center_world_pos(),
+                diagonal_extent: self.get_level_diagonal_extent(),
+            }
+        })
+    }
+
+    /// Discard the cached [`Level::geometry`] so it is recomputed on next access
+    pub fn invalidate_geometry(&mut self) {
+        self.geometry_cache = OnceLock::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::transform::ResizeAnchor;
+    use hexx::Hex;
+
+    fn assert_matches_fresh(level: &Level) {
+        let geometry = level.geometry();
+        assert_eq!(
+            (geometry.min_bounds, geometry.max_bounds),
+            level.get_world_bounds()
+        );
+        assert_eq!(geometry.center, level.get_center_world_pos());
+        assert_eq!(geometry.diagonal_extent, level.get_level_diagonal_extent());
+    }
+
+    #[test]
+    fn test_geometry_matches_direct_calculation() {
+        let level = LevelBuilder::new().dimensions(6, 5).build().unwrap();
+        assert_matches_fresh(&level);
+        // Second access comes from the cache
+        assert_eq!(level.geometry(), level.geometry());
+    }
+
+    #[test]
+    fn test_mutation_invalidates_cache() {
+        let mut level = LevelBuilder::new()
+            .dimensions(5, 5)
+            .flat(1.0)
+            .build()
+            .unwrap();
+        let before = level.geometry();
+
+        level.set_height(Hex::new(2, 2), 9.0).unwrap();
+        assert_ne!(level.geometry().max_bounds.y, before.max_bounds.y);
+        assert_matches_fresh(&level);
+
+        level.resize(8, 5, ResizeAnchor::TopLeft, 1.0).unwrap();
+        assert!(level.geometry().max_bounds.x > before.max_bounds.x);
+        assert_matches_fresh(&level);
+
+        level.rotate_cw();
+        assert_matches_fresh(&level);
+
+        level.water_level = Some(20.0);
+        level.invalidate_geometry();
+        assert_eq!(level.geometry().max_bounds.y, 20.0);
+    }
+}
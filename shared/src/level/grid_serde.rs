@@ -0,0 +1,79 @@
+//! Grid Serialization
+//!
+//! Level grids are written as nested rows, stored as [row][col], so every file
+//! format shows the map row by row instead of ndarray's `{ v, dim, data }`
+//! layout. Files written in that older layout still load.
+
+use ndarray::Array2;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A grid as found in a level file
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum GridFile<T> {
+    Rows(Vec<Vec<T>>),
+    Ndarray(Array2<T>),
+}
+
+/// Serialize a grid as a list of rows
+pub(crate) fn serialize_rows<T, S>(grid: &Array2<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize + Clone,
+    S: Serializer,
+{
+    serializer.collect_seq(grid.rows().into_iter().map(|row| row.to_vec()))
+}
+
+/// Deserialize a grid from a list of equally long rows or ndarray's layout
+pub(crate) fn deserialize_rows<'de, T, D>(deserializer: D) -> Result<Array2<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let rows = match GridFile::deserialize(deserializer)? {
+        GridFile::Rows(rows) => rows,
+        GridFile::Ndarray(grid) => return Ok(grid),
+    };
+    let width = rows.first().map_or(0, Vec::len);
+    if let Some((r, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+        return Err(D::Error::custom(format!(
+            "grid row {r} has {len} entries, expected {width}",
+            len = row.len()
+        )));
+    }
+    Array2::from_shape_vec((rows.len(), width), rows.into_iter().flatten().collect())
+        .map_err(D::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::level::Level;
+    use crate::level::format::LevelFormat;
+    use hexx::Hex;
+
+    #[test]
+    fn test_grids_are_written_as_rows_in_every_format() {
+        let mut level = Level::new("Rows".to_string(), 3, 2);
+        level.set_height(Hex::new(2, 0), 4.0).unwrap();
+        level.set_walkable(Hex::new(0, 1), false).unwrap();
+
+        for format in LevelFormat::ALL {
+            let content = format.serialize(&level).unwrap();
+            assert!(!content.contains("dim"), "{format}: {content}");
+            let parsed = format.parse(&content).unwrap();
+            assert_eq!(parsed.heights, level.heights, "{format}");
+            assert_eq!(parsed.walkable, level.walkable, "{format}");
+        }
+    }
+
+    #[test]
+    fn test_ragged_rows_are_rejected() {
+        let ragged = "name = \"Ragged\"\nwidth = 2\nheight = 2\nheights = [[1.0, 2.0], [3.0]]\n";
+        let err = LevelFormat::Toml.parse(ragged).unwrap_err();
+        assert!(
+            format!("{err:#}").contains("grid row 1 has 1 entries, expected 2"),
+            "{err:#}"
+        );
+    }
+}
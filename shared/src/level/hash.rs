@@ -0,0 +1,266 @@
+//! Level Content Hashing
+//!
+//! Cheap, platform-stable fingerprints of level content for "modified since
+//! last save" tracking and for skipping redundant geometry rebuilds.
+
+use super::Level;
+
+/// 64-bit FNV-1a hasher
+///
+/// Implemented locally because `std`'s default hasher is not guaranteed to be
+/// stable across Rust releases, and hashes may be persisted or compared between
+/// the native and WASM builds.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_i32(&mut self, value: i32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_f32(&mut self, value: f32) {
+        self.write(&value.to_bits().to_le_bytes());
+    }
+
+    /// Length-prefixed so adjacent strings cannot collide by shifting bytes
+    fn write_str(&mut self, value: &str) {
+        self.write(&(value.len() as u64).to_le_bytes());
+        self.write(value.as_bytes());
+    }
+
+    /// Tagged so a missing value differs from an empty one
+    fn write_optional_str(&mut self, value: Option<&str>) {
+        match value {
+            Some(value) => {
+                self.write(&[1]);
+                self.write_str(value);
+            }
+            None => self.write(&[0]),
+        }
+    }
+}
+
+/// Platform-stable FNV-1a hash of raw bytes, such as a level file's contents
+pub fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = Fnv1a::new();
+    hasher.write(bytes);
+    hasher.0
+}
+
+impl Level {
+    /// Compute a platform-stable hash of this level's content
+    ///
+    /// Covers every field saved to the level file, so any edit that changes the
+    /// file changes the hash. Heights are hashed by bit pattern.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv1a::new();
+        hasher.write_str(&self.name);
+        hasher.write(&self.format_version.to_le_bytes());
+        hasher.write_optional_str(self.author.as_deref());
+        hasher.write_optional_str(self.description.as_deref());
+        hasher.write_i32(self.width);
+        hasher.write_i32(self.height);
+        hasher.write_f32(self.hex_scale);
+        hasher.write(&[self.orientation as u8]);
+
+        for (name, &height) in &self.height_presets {
+            hasher.write_str(name);
+            hasher.write_f32(height);
+        }
+        for &height in self.heights.iter() {
+            hasher.write_f32(height);
+        }
+
+        // An empty walkability grid and an all-true grid describe the same map
+        for r in 0..self.height {
+            for q in 0..self.width {
+                hasher.write(&[u8::from(self.is_walkable(hexx::Hex::new(q, r)))]);
+            }
+        }
+
+        match self.water_level {
+            Some(water_level) => {
+                hasher.write(&[1]);
+                hasher.write_f32(water_level);
+            }
+            None => hasher.write(&[0]),
+        }
+
+        for spawn in &self.spawn_points {
+            hasher.write_i32(spawn.hex.0);
+            hasher.write_i32(spawn.hex.1);
+            hasher.write_str(&spawn.team);
+        }
+        for (tag, coords) in &self.tags {
+            hasher.write_str(tag);
+            for &(q, r) in coords {
+                hasher.write_i32(q);
+                hasher.write_i32(r);
+            }
+        }
+        for region in &self.regions {
+            hasher.write_str(&region.name);
+            for &(q, r) in &region.hexes {
+                hasher.write_i32(q);
+                hasher.write_i32(r);
+            }
+        }
+        for ramp in &self.ramps {
+            hasher.write_i32(ramp.from.0);
+            hasher.write_i32(ramp.from.1);
+            hasher.write_i32(ramp.to.0);
+            hasher.write_i32(ramp.to.1);
+        }
+        for trigger in &self.triggers {
+            hasher.write_i32(trigger.hex.0);
+            hasher.write_i32(trigger.hex.1);
+            hasher.write_str(&trigger.event);
+            hasher.write(&[u8::from(trigger.once)]);
+        }
+        for overlay in &self.overlays {
+            hasher.write_i32(overlay.hex.0);
+            hasher.write_i32(overlay.hex.1);
+            hasher.write_f32(overlay.floor);
+            hasher.write_f32(overlay.thickness);
+        }
+
+        let lighting = &self.lighting;
+        for value in [
+            lighting.sun_yaw,
+            lighting.sun_pitch,
+            lighting.illuminance,
+            lighting.ambient_brightness,
+        ]
+        .into_iter()
+        .chain(lighting.color)
+        {
+            hasher.write_f32(value);
+        }
+        match self.background_color {
+            Some(color) => {
+                hasher.write(&[1]);
+                for channel in color {
+                    hasher.write_f32(channel);
+                }
+            }
+            None => hasher.write(&[0]),
+        }
+
+        hasher.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use hexx::Hex;
+    use tempfile::TempDir;
+
+    fn sample_level() -> Level {
+        LevelBuilder::new()
+            .name("Hashed")
+            .dimensions(5, 4)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_hash_changes_with_content() {
+        let level = sample_level();
+        let original = level.content_hash();
+        assert_eq!(original, level.clone().content_hash());
+
+        let mut edited = level.clone();
+        edited.set_height(Hex::new(3, 2), 9.0).unwrap();
+        assert_ne!(edited.content_hash(), original);
+
+        let mut renamed = level.clone();
+        renamed.name = "Other".to_string();
+        assert_ne!(renamed.content_hash(), original);
+
+        let mut blocked = level.clone();
+        blocked.set_walkable(Hex::new(0, 0), false).unwrap();
+        assert_ne!(blocked.content_hash(), original);
+
+        // Equivalent walkability representations hash the same
+        let mut implicit = level.clone();
+        implicit.walkable = ndarray::Array2::from_elem((0, 0), true);
+        assert_eq!(implicit.content_hash(), original);
+    }
+
+    /// Assert that `edit` changes the hash of [`sample_level`]
+    fn assert_edit_changes_hash(edit: impl FnOnce(&mut Level)) {
+        let level = sample_level();
+        let mut edited = level.clone();
+        edit(&mut edited);
+        assert_ne!(edited.content_hash(), level.content_hash());
+    }
+
+    #[test]
+    fn test_hash_covers_author() {
+        assert_edit_changes_hash(|level| level.author = Some("Designer".to_string()));
+        // An empty credit is still a saved field
+        assert_edit_changes_hash(|level| level.author = Some(String::new()));
+    }
+
+    #[test]
+    fn test_hash_covers_description() {
+        assert_edit_changes_hash(|level| level.description = Some("Hold the ridge".to_string()));
+    }
+
+    #[test]
+    fn test_hash_covers_format_version() {
+        assert_edit_changes_hash(|level| level.format_version += 1);
+    }
+
+    #[test]
+    fn test_hash_covers_height_presets() {
+        assert_edit_changes_hash(|level| {
+            level.height_presets.insert("hill".to_string(), 3.0);
+        });
+    }
+
+    #[test]
+    fn test_hash_covers_lighting() {
+        assert_edit_changes_hash(|level| level.lighting.sun_yaw += 10.0);
+        assert_edit_changes_hash(|level| level.lighting.sun_pitch += 10.0);
+        assert_edit_changes_hash(|level| level.lighting.illuminance *= 2.0);
+        assert_edit_changes_hash(|level| level.lighting.ambient_brightness *= 2.0);
+        assert_edit_changes_hash(|level| level.lighting.color = [1.0, 0.8, 0.6]);
+    }
+
+    #[test]
+    fn test_hash_covers_background_color() {
+        assert_edit_changes_hash(|level| level.background_color = Some([0.1, 0.2, 0.3]));
+    }
+
+    #[test]
+    fn test_hash_survives_save_load_roundtrip() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let level = sample_level();
+        level
+            .save_to_directory(temp_path, "hashed.toml")
+            .expect("Failed to save level");
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+
+        assert_eq!(loaded.current_level().content_hash(), level.content_hash());
+        assert!(!loaded.is_modified(0));
+    }
+}
@@ -0,0 +1,241 @@
+//! Heightmap Images
+//!
+//! Conversion between grayscale PNG heightmaps and level heights so terrain can
+//! be authored in external tools. Native only, since it reads from the file system.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use image::{ImageBuffer, Luma};
+use ndarray::Array2;
+use tracing::info;
+
+use super::Level;
+
+/// Largest accepted heightmap width or height in pixels
+///
+/// Every pixel becomes a hex column entity, so much larger images would produce
+/// levels far beyond what the renderer handles.
+pub const DEFAULT_MAX_HEIGHTMAP_SIZE: u32 = 256;
+
+impl Level {
+    /// Create a level from a grayscale PNG heightmap
+    ///
+    /// See [`Level::from_heightmap_png_with_max_size`]; images wider or taller than
+    /// [`DEFAULT_MAX_HEIGHTMAP_SIZE`] are rejected.
+    pub fn from_heightmap_png(
+        path: impl AsRef<Path>,
+        name: impl Into<String>,
+        min_height: f32,
+        max_height: f32,
+    ) -> Result<Level> {
+        Self::from_heightmap_png_with_max_size(
+            path,
+            name,
+            min_height,
+            max_height,
+            DEFAULT_MAX_HEIGHTMAP_SIZE,
+        )
+    }
+
+    /// Create a level from a grayscale PNG heightmap, rejecting images larger than
+    /// `max_size` pixels on either side
+    ///
+    /// Pixel (x, y) becomes hex (q, r) = (x, y), so the level's width and height
+    /// match the image. Black maps to `min_height` and white to `max_height`; color
+    /// images are converted to luminance first.
+    pub fn from_heightmap_png_with_max_size(
+        path: impl AsRef<Path>,
+        name: impl Into<String>,
+        min_height: f32,
+        max_height: f32,
+        max_size: u32,
+    ) -> Result<Level> {
+        let path = path.as_ref();
+        if !min_height.is_finite() || !max_height.is_finite() || min_height > max_height {
+            bail!("Invalid heightmap height range {min_height}..{max_height}");
+        }
+
+        let image = image::open(path).with_context(|| {
+            format!(
+                "Failed to read heightmap image: {path}",
+                path = path.display()
+            )
+        })?;
+        let (width, height) = (image.width(), image.height());
+        if width == 0 || height == 0 {
+            bail!("Heightmap {path} has no pixels", path = path.display());
+        }
+        if width > max_size || height > max_size {
+            bail!(
+                "Heightmap {path} is {width}x{height}, larger than the {max_size}x{max_size} limit",
+                path = path.display()
+            );
+        }
+
+        // 16-bit luminance keeps full precision for 16-bit source images
+        let luma = image.to_luma16();
+        let heights = Array2::from_shape_fn((height as usize, width as usize), |(row, col)| {
+            let value = f32::from(luma.get_pixel(col as u32, row as u32)[0]) / f32::from(u16::MAX);
+            min_height + value * (max_height - min_height)
+        });
+
+        let mut level = Level::new(name.into(), width as i32, height as i32);
+        level.heights = heights;
+
+        info!(
+            "Imported heightmap {path} as level '{level_name}' ({width}x{height}, heights {min_height}..{max_height})",
+            path = path.display(),
+            level_name = level.name
+        );
+        Ok(level)
+    }
+
+    /// Write this level's heights to a 16-bit grayscale PNG heightmap
+    ///
+    /// Hex (q, r) becomes pixel (x, y) = (q, r). Black is the level's lowest height
+    /// and white its highest; the range is logged so the image can be re-imported
+    /// with [`Level::from_heightmap_png`]. Parent directories are created as needed.
+    pub fn to_heightmap_png(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if self.width <= 0 || self.height <= 0 {
+            bail!(
+                "Cannot export level '{name}' with no hexes as a heightmap",
+                name = self.name
+            );
+        }
+
+        let stats = self.stats();
+        let (min_height, max_height) = (stats.min_height, stats.max_height);
+        let range = max_height - min_height;
+
+        let image = ImageBuffer::from_fn(self.width as u32, self.height as u32, |x, y| {
+            let height = self.heights[(y as usize, x as usize)];
+            // A flat level has no range to spread, so every pixel is black
+            let value = if range > 0.0 {
+                (height - min_height) / range
+            } else {
+                0.0
+            };
+            Luma([(value * f32::from(u16::MAX)).round() as u16])
+        });
+
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            std::fs::create_dir_all(parent).with_context(|| {
+                format!(
+                    "Failed to create directory: {parent}",
+                    parent = parent.display()
+                )
+            })?;
+        }
+        image.save(path).with_context(|| {
+            format!(
+                "Failed to write heightmap image: {path}",
+                path = path.display()
+            )
+        })?;
+
+        info!(
+            "Exported level '{level_name}' to heightmap {path} (black = {min_height}, white = {max_height})",
+            level_name = self.name,
+            path = path.display()
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use hexx::Hex;
+    use image::{GrayImage, Rgb, RgbImage};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_import_maps_corner_values() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let path = temp_dir.path().join("terrain.png");
+
+        let mut image = GrayImage::from_pixel(3, 2, Luma([128]));
+        image.put_pixel(0, 0, Luma([0]));
+        image.put_pixel(2, 0, Luma([255]));
+        image.put_pixel(0, 1, Luma([51]));
+        image.save(&path).expect("Failed to write fixture");
+
+        let level = Level::from_heightmap_png(&path, "Imported", 1.0, 6.0)
+            .expect("Failed to import heightmap");
+
+        assert_eq!(level.name, "Imported");
+        assert_eq!((level.width, level.height), (3, 2));
+        assert!(level.validate().is_ok());
+        assert!((level.get_height(Hex::new(0, 0)) - 1.0).abs() < 0.001);
+        assert!((level.get_height(Hex::new(2, 0)) - 6.0).abs() < 0.001);
+        assert!((level.get_height(Hex::new(0, 1)) - 2.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_color_images_are_converted() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let path = temp_dir.path().join("color.png");
+
+        let mut image = RgbImage::from_pixel(2, 2, Rgb([255, 255, 255]));
+        image.put_pixel(1, 1, Rgb([0, 0, 0]));
+        image.save(&path).expect("Failed to write fixture");
+
+        let level = Level::from_heightmap_png(&path, "Color", 0.0, 2.0)
+            .expect("Failed to import heightmap");
+
+        assert!((level.get_height(Hex::new(0, 0)) - 2.0).abs() < 0.001);
+        assert!(level.get_height(Hex::new(1, 1)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_oversized_image_is_rejected() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let path = temp_dir.path().join("huge.png");
+        GrayImage::new(8, 4)
+            .save(&path)
+            .expect("Failed to write fixture");
+
+        let error = Level::from_heightmap_png_with_max_size(&path, "Huge", 0.0, 1.0, 6)
+            .expect_err("Oversized heightmap should be rejected");
+        assert!(
+            error.to_string().contains("larger than the 6x6 limit"),
+            "Unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let path = temp_dir.path().join("exports/terrain.png");
+
+        let level = LevelBuilder::new()
+            .name("Gradient")
+            .dimensions(6, 4)
+            .gradient(0.5, 3.5)
+            .with_height(Hex::new(2, 1), 2.2)
+            .build()
+            .unwrap();
+        level
+            .to_heightmap_png(&path)
+            .expect("Failed to export heightmap");
+
+        let stats = level.stats();
+        let imported =
+            Level::from_heightmap_png(&path, "Gradient", stats.min_height, stats.max_height)
+                .expect("Failed to import heightmap");
+
+        assert_eq!(imported.heights.shape(), level.heights.shape());
+        // 16-bit quantization over a 3.0 range is well under a thousandth
+        for (original, roundtripped) in level.heights.iter().zip(imported.heights.iter()) {
+            assert!(
+                (original - roundtripped).abs() < 0.001,
+                "Height {original} came back as {roundtripped}"
+            );
+        }
+    }
+}
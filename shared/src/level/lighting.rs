@@ -0,0 +1,150 @@
+//! Level Lighting
+//!
+//! Per-level sun, ambient light, and background settings so maps can set their
+//! own mood instead of sharing one hardcoded light rig.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+use crate::colors::LIGHT_BACKGROUND;
+
+/// Lighting configuration stored in a level's optional `[lighting]` table
+///
+/// Missing fields fall back to the defaults, which reproduce the original light
+/// rig: a white sun shining from (4, 8, 4) toward the origin at 10000 lux with
+/// ambient brightness 300.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LevelLighting {
+    /// Compass direction the sun shines from, in degrees around +Y (0 = +Z, 90 = +X)
+    pub sun_yaw: f32,
+    /// Sun elevation above the horizon in degrees
+    pub sun_pitch: f32,
+    /// Directional light illuminance in lux
+    pub illuminance: f32,
+    /// Ambient light brightness
+    pub ambient_brightness: f32,
+    /// Sun color as sRGB components in 0.0..=1.0
+    pub color: [f32; 3],
+}
+
+impl Default for LevelLighting {
+    fn default() -> Self {
+        Self {
+            sun_yaw: 45.0,
+            // Elevation of (4, 8, 4) as seen from the origin
+            sun_pitch: 54.735_61,
+            illuminance: 10000.0,
+            ambient_brightness: 300.0,
+            color: [1.0, 1.0, 1.0],
+        }
+    }
+}
+
+impl LevelLighting {
+    /// Check whether these settings equal the defaults (used to keep files minimal)
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Unit vector pointing from the origin toward the sun
+    pub fn sun_direction(&self) -> Vec3 {
+        let yaw = self.sun_yaw.to_radians();
+        let pitch = self.sun_pitch.to_radians();
+        Vec3::new(
+            pitch.cos() * yaw.sin(),
+            pitch.sin(),
+            pitch.cos() * yaw.cos(),
+        )
+    }
+
+    /// Transform for the directional light, placed along the sun direction and
+    /// looking at the origin
+    pub fn sun_transform(&self) -> Transform {
+        // Distance only matters for gizmos and debugging; directional lights use rotation
+        let light_pos = self.sun_direction() * 9.797_959;
+        Transform::from_translation(light_pos).looking_at(Vec3::ZERO, Vec3::Y)
+    }
+
+    /// Sun color as a Bevy [`Color`]
+    pub fn sun_color(&self) -> Color {
+        Color::srgb(self.color[0], self.color[1], self.color[2])
+    }
+}
+
+impl Level {
+    /// Get the clear color for this level, falling back to [`LIGHT_BACKGROUND`]
+    pub fn clear_color(&self) -> Color {
+        self.background_color
+            .map_or(LIGHT_BACKGROUND, |[r, g, b]| Color::srgb(r, g, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_default_matches_original_light_rig() {
+        let transform = LevelLighting::default().sun_transform();
+        assert!(
+            transform.translation.distance(Vec3::new(4.0, 8.0, 4.0)) < 0.001,
+            "Sun at {translation:?}",
+            translation = transform.translation
+        );
+    }
+
+    #[test]
+    fn test_lighting_roundtrip() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let mut level = LevelBuilder::new().name("Dusk").build().unwrap();
+        level.lighting = LevelLighting {
+            sun_yaw: 270.0,
+            sun_pitch: 10.0,
+            illuminance: 2500.0,
+            ambient_brightness: 80.0,
+            color: [1.0, 0.6, 0.3],
+        };
+        level
+            .save_to_directory(temp_path, "dusk.toml")
+            .expect("Failed to save level");
+
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(loaded.current_level().lighting, level.lighting);
+    }
+
+    #[test]
+    fn test_partial_lighting_table_uses_defaults() {
+        let lighting: LevelLighting = toml::from_str("illuminance = 500.0").unwrap();
+        assert_eq!(lighting.illuminance, 500.0);
+        assert_eq!(
+            lighting.ambient_brightness,
+            LevelLighting::default().ambient_brightness
+        );
+    }
+
+    #[test]
+    fn test_background_color_roundtrip_and_fallback() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let mut level = LevelBuilder::new().name("Night").build().unwrap();
+        assert_eq!(level.clear_color(), LIGHT_BACKGROUND);
+
+        level.background_color = Some([0.05, 0.05, 0.12]);
+        level
+            .save_to_directory(temp_path, "night.toml")
+            .expect("Failed to save level");
+
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let loaded = loaded.current_level();
+        assert_eq!(loaded.background_color, Some([0.05, 0.05, 0.12]));
+        assert_eq!(loaded.clear_color(), Color::srgb(0.05, 0.05, 0.12));
+    }
+}
@@ -3,26 +3,257 @@
 //! Level switching, grid lifecycle management, and level state transitions
 //! for dynamic tactical RPG level loading.
 
+use std::collections::HashSet;
+use std::fmt;
+
+use anyhow::{Result, bail};
 use bevy::prelude::*;
-use tracing::info;
+use tracing::{info, warn};
+
+use super::builder::LevelBuilder;
+use super::chunks::{HexChunk, SpawnedGrid, changed_column_hexes, respawn_chunks};
+use super::generation::HeightGenerator;
+use super::mesh::{GridSpawnAssets, HexGridEntity, HexGridIndex, spawn_hex_grid_internal};
+use super::{Level, LevelsResource};
+
+/// Error returned by [`LevelsResource::switch_to`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LevelSwitchError {
+    /// No loaded level has this name
+    UnknownLevel(String),
+    /// Several loaded levels share this name, so it does not pick one level
+    DuplicateName { name: String, count: usize },
+}
+
+impl fmt::Display for LevelSwitchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownLevel(name) => write!(f, "no level named '{name}' is loaded"),
+            Self::DuplicateName { name, count } => {
+                write!(f, "{count} loaded levels are named '{name}'")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LevelSwitchError {}
+
+impl LevelsResource {
+    /// Index of the first level named `name`
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.levels.iter().position(|level| level.name == name)
+    }
+
+    /// Make the level named `name` current
+    ///
+    /// Fails without switching if no level, or more than one level, has that name.
+    pub fn switch_to(&mut self, name: &str) -> Result<(), LevelSwitchError> {
+        let mut matches = self
+            .levels
+            .iter()
+            .enumerate()
+            .filter(|(_, level)| level.name == name)
+            .map(|(index, _)| index);
+        let Some(index) = matches.next() else {
+            return Err(LevelSwitchError::UnknownLevel(name.to_string()));
+        };
+        let others = matches.count();
+        if others > 0 {
+            return Err(LevelSwitchError::DuplicateName {
+                name: name.to_string(),
+                count: others + 1,
+            });
+        }
+
+        self.set_current_index(index);
+        Ok(())
+    }
+
+    /// Make the level at `index` current, clamped to the last loaded level
+    ///
+    /// Returns the index that was selected.
+    pub fn set_current_index(&mut self, index: usize) -> usize {
+        let index = index.min(self.levels.len().saturating_sub(1));
+        if index != self.current_level_index {
+            info!(
+                "Switched to level '{level_name}' (index {index})",
+                level_name = self.levels[index].name
+            );
+        }
+        self.current_level_index = index;
+        index
+    }
+
+    /// Append `level` and return its index
+    ///
+    /// The new level counts as unsaved. The current level does not change.
+    pub fn add_level(&mut self, level: Level) -> usize {
+        self.saved_hashes.truncate(self.levels.len());
+        self.levels.push(level);
+        self.levels.len() - 1
+    }
+
+    /// Remove and return the level at `index`
+    ///
+    /// Levels after the current one keep the current level selected; removing
+    /// the current level selects the level that moved into its slot, or the
+    /// previous one if it was last. The last remaining level cannot be removed.
+    pub fn remove_level(&mut self, index: usize) -> Result<Level> {
+        let level_count = self.levels.len();
+        if index >= level_count {
+            bail!("No level at index {index}; {level_count} levels are loaded");
+        }
+        if level_count == 1 {
+            bail!("Cannot remove the last remaining level");
+        }
+
+        let level = self.levels.remove(index);
+        if index < self.saved_hashes.len() {
+            self.saved_hashes.remove(index);
+        }
+        if index < self.current_level_index {
+            self.current_level_index -= 1;
+        } else {
+            self.current_level_index = self.current_level_index.min(self.levels.len() - 1);
+        }
+
+        info!(
+            "Removed level '{level_name}' (index {index})",
+            level_name = level.name
+        );
+        Ok(level)
+    }
+
+    /// Replace the level at `index` with `level`, returning the old level
+    ///
+    /// The saved state is kept, so the replacement shows as modified unless it
+    /// matches what was last saved.
+    pub fn replace_level(&mut self, index: usize, level: Level) -> Result<Level> {
+        let Some(slot) = self.levels.get_mut(index) else {
+            bail!(
+                "No level at index {index}; {level_count} levels are loaded",
+                level_count = self.levels.len()
+            );
+        };
+        Ok(std::mem::replace(slot, level))
+    }
+}
+
+/// Level to show first, chosen by name and applied once levels have loaded
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct StartingLevel(pub String);
+
+impl StartingLevel {
+    /// Switch `levels` to this level, keeping the current one if that fails
+    pub fn apply(&self, levels: &mut LevelsResource) {
+        if let Err(err) = levels.switch_to(&self.0) {
+            warn!("Cannot start on the requested level: {err}");
+        }
+    }
+}
+
+/// Level shown while level files load, and kept when none of them load
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct DefaultLevelConfig {
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    /// Terrain of the fallback level
+    pub generator: HeightGenerator,
+}
+
+impl Default for DefaultLevelConfig {
+    /// The 10x10 demo gradient used by [`LevelsResource::with_default`]
+    fn default() -> Self {
+        Self {
+            name: "Default Level".to_string(),
+            width: 10,
+            height: 10,
+            generator: HeightGenerator::default(),
+        }
+    }
+}
 
-use super::LevelsResource;
-use super::mesh::{HexGridEntity, spawn_hex_grid_internal};
+impl DefaultLevelConfig {
+    /// Build the fallback level, failing on non-positive dimensions
+    pub fn build(&self) -> Result<Level> {
+        LevelBuilder::new()
+            .name(&self.name)
+            .dimensions(self.width, self.height)
+            .terrain(self.generator)
+            .build()
+    }
+}
 
-/// System to handle level switching by despawning old hex grid and spawning new one
+impl LevelsResource {
+    /// Create a LevelsResource holding only the level described by `config`
+    ///
+    /// An invalid config is logged and replaced by [`DefaultLevelConfig::default`].
+    pub fn with_config(config: &DefaultLevelConfig) -> Self {
+        let level = config.build().unwrap_or_else(|err| {
+            warn!("Invalid default level config, using the built-in default: {err:#}");
+            DefaultLevelConfig::default()
+                .build()
+                .expect("Built-in default level parameters are valid")
+        });
+        info!(
+            "LevelsResource: Created with fallback level '{level_name}'",
+            level_name = level.name
+        );
+        Self::new(vec![level])
+    }
+}
+
+/// System to handle level switching by despawning old hex grid chunks and spawning new ones
+///
+/// When only column heights or walkability changed since the grid was spawned,
+/// just the affected chunks are respawned.
 pub fn level_switching_system(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut assets: GridSpawnAssets,
     levels_resource: Res<LevelsResource>,
     hex_grid_query: Query<Entity, With<HexGridEntity>>,
+    chunk_query: Query<(Entity, &HexChunk)>,
+    spawned_grid: Option<ResMut<SpawnedGrid>>,
+    grid_index: Option<ResMut<HexGridIndex>>,
 ) {
-    // Only trigger when LevelsResource has actually changed
-    if !levels_resource.is_changed() {
+    // Only trigger when LevelsResource or the column style has actually changed
+    let style_changed = assets.is_style_changed();
+    assets.mark_style_checked();
+    if !levels_resource.is_changed() && !style_changed {
         return;
     }
 
     let level = levels_resource.current_level();
+    let style = assets.style();
+    if !style_changed
+        && let Some(mut spawned_grid) = spawned_grid
+        && let Some(mut grid_index) = grid_index
+        && let Some(changed) = changed_column_hexes(&spawned_grid.0, level)
+    {
+        let dirty: HashSet<HexChunk> = changed
+            .into_iter()
+            .filter_map(|hex| HexChunk::containing(hex, level.width, level.height))
+            .collect();
+        if !dirty.is_empty() {
+            info!(
+                "Level changed: respawning {count} hex chunks for '{level_name}'",
+                count = dirty.len(),
+                level_name = level.name
+            );
+            respawn_chunks(
+                &mut commands,
+                &mut assets,
+                level,
+                &dirty,
+                &chunk_query,
+                &mut grid_index,
+            );
+        }
+        spawned_grid.0 = level.clone();
+        return;
+    }
+
     info!(
         "Level switched: Despawning old hex grid and spawning new grid for '{level_name}'",
         level_name = level.name
@@ -42,5 +273,384 @@ pub fn level_switching_system(
     }
 
     // Spawn new hex grid for the current level using existing logic
-    spawn_hex_grid_internal(&mut commands, &mut meshes, &mut materials, &levels_resource);
+    spawn_hex_grid_internal(
+        &mut commands,
+        &mut assets.meshes,
+        &assets.hex_materials,
+        level,
+        style,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::Level;
+    use crate::level::mesh::{
+        HexCoord, HexEdgeStyle, HexMaterials, HexMeshOptions, TerrainColoring, spawn_hex_grid,
+    };
+    use crate::level::overlays::OverlayHex;
+    use crate::level::render_config::HexGridRenderConfig;
+    use bevy::ecs::system::RunSystemOnce;
+    use hexx::Hex;
+
+    fn levels(names: &[&str]) -> LevelsResource {
+        LevelsResource::new(
+            names
+                .iter()
+                .map(|name| Level::new(name.to_string(), 3, 3))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_switch_to_by_name() {
+        let mut levels = levels(&["Alpha", "Bravo", "Charlie"]);
+        assert_eq!(levels.index_of("Charlie"), Some(2));
+
+        levels.switch_to("Charlie").expect("Charlie is loaded");
+        assert_eq!(levels.current_level().name, "Charlie");
+        assert_eq!(levels.current_level_index, 2);
+    }
+
+    #[test]
+    fn test_switch_to_unknown_name_keeps_current_level() {
+        let mut levels = levels(&["Alpha", "Bravo"]);
+        levels.set_current_index(1);
+
+        assert_eq!(
+            levels.switch_to("Zulu"),
+            Err(LevelSwitchError::UnknownLevel("Zulu".to_string()))
+        );
+        assert_eq!(levels.index_of("Zulu"), None);
+        assert_eq!(levels.current_level().name, "Bravo");
+    }
+
+    #[test]
+    fn test_switch_to_duplicate_name_is_refused() {
+        let mut levels = levels(&["Skirmish", "Alpha", "Skirmish"]);
+
+        assert_eq!(
+            levels.switch_to("Skirmish"),
+            Err(LevelSwitchError::DuplicateName {
+                name: "Skirmish".to_string(),
+                count: 2
+            })
+        );
+        assert_eq!(levels.index_of("Skirmish"), Some(0));
+        assert_eq!(levels.current_level_index, 0);
+    }
+
+    fn names(levels: &LevelsResource) -> Vec<&str> {
+        levels
+            .levels
+            .iter()
+            .map(|level| level.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_add_level_keeps_current_and_is_unsaved() {
+        let mut levels = levels(&["Alpha", "Bravo"]);
+        levels.set_current_index(1);
+
+        let index = levels.add_level(Level::new("Charlie".to_string(), 3, 3));
+
+        assert_eq!(index, 2);
+        assert_eq!(levels.current_level().name, "Bravo");
+        assert!(levels.is_modified(2));
+        assert!(!levels.is_modified(1));
+    }
+
+    #[test]
+    fn test_remove_level_before_current_shifts_index() {
+        let mut levels = levels(&["Alpha", "Bravo", "Charlie"]);
+        levels.set_current_index(2);
+
+        let removed = levels.remove_level(0).expect("Index 0 exists");
+
+        assert_eq!(removed.name, "Alpha");
+        assert_eq!(levels.current_level_index, 1);
+        assert_eq!(levels.current_level().name, "Charlie");
+    }
+
+    #[test]
+    fn test_remove_level_after_current_keeps_index() {
+        let mut levels = levels(&["Alpha", "Bravo", "Charlie"]);
+
+        levels.remove_level(2).expect("Index 2 exists");
+
+        assert_eq!(levels.current_level_index, 0);
+        assert_eq!(names(&levels), ["Alpha", "Bravo"]);
+    }
+
+    #[test]
+    fn test_remove_current_level_selects_neighbor() {
+        let mut levels = levels(&["Alpha", "Bravo", "Charlie"]);
+        levels.set_current_index(1);
+        levels.remove_level(1).expect("Index 1 exists");
+        assert_eq!(
+            levels.current_level().name,
+            "Charlie",
+            "Next level moves up"
+        );
+
+        levels.remove_level(1).expect("Index 1 exists");
+        assert_eq!(
+            levels.current_level().name,
+            "Alpha",
+            "Removing the last level selects the previous one"
+        );
+    }
+
+    #[test]
+    fn test_remove_level_keeps_saved_state_aligned() {
+        let mut levels = levels(&["Alpha", "Bravo", "Charlie"]);
+        levels.levels[2].set_height(Hex::new(0, 0), 9.0).unwrap();
+
+        levels.remove_level(0).expect("Index 0 exists");
+
+        assert!(!levels.is_modified(0), "Bravo is unchanged");
+        assert!(levels.is_modified(1), "Charlie keeps its unsaved edit");
+    }
+
+    #[test]
+    fn test_remove_level_errors() {
+        let mut levels = levels(&["Alpha", "Bravo"]);
+        assert!(levels.remove_level(5).is_err());
+
+        levels.remove_level(0).expect("Index 0 exists");
+        let error = levels
+            .remove_level(0)
+            .expect_err("The last level must stay");
+        assert!(error.to_string().contains("last remaining level"));
+        assert_eq!(levels.current_level().name, "Bravo");
+    }
+
+    #[test]
+    fn test_replace_level() {
+        let mut levels = levels(&["Alpha", "Bravo"]);
+        levels.set_current_index(1);
+
+        let old = levels
+            .replace_level(1, Level::new("Bravo Remastered".to_string(), 4, 4))
+            .expect("Index 1 exists");
+
+        assert_eq!(old.name, "Bravo");
+        assert_eq!(levels.current_level().name, "Bravo Remastered");
+        assert!(levels.is_modified(1));
+        assert!(
+            levels
+                .replace_level(2, Level::new("Nowhere".to_string(), 3, 3))
+                .is_err()
+        );
+        assert_eq!(levels.level_count(), 2);
+    }
+
+    #[test]
+    fn test_set_current_index_clamps() {
+        let mut levels = levels(&["Alpha", "Bravo"]);
+
+        assert_eq!(levels.set_current_index(7), 1);
+        assert_eq!(levels.current_level().name, "Bravo");
+    }
+
+    #[test]
+    fn test_invalid_default_level_config_uses_builtin_default() {
+        let config = DefaultLevelConfig {
+            width: 0,
+            ..Default::default()
+        };
+        assert!(config.build().is_err());
+
+        let levels = LevelsResource::with_config(&config);
+        assert_eq!(levels.level_count(), 1);
+        assert_eq!(levels.current_level().width, 10);
+    }
+
+    #[test]
+    fn test_height_edit_respawns_only_its_chunk() {
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<HexMaterials>();
+        world.init_resource::<TerrainColoring>();
+        world.init_resource::<HexMeshOptions>();
+        world.init_resource::<HexGridRenderConfig>();
+        world.init_resource::<HexEdgeStyle>();
+        world.insert_resource(LevelsResource::new(vec![
+            Level::new("Wide".to_string(), 20, 20),
+            Level::new("Small".to_string(), 5, 5),
+        ]));
+        world
+            .run_system_once(spawn_hex_grid)
+            .expect("Grid spawning runs");
+        let mut schedule = Schedule::default();
+        schedule.add_systems(level_switching_system);
+        schedule.run(&mut world);
+        let chunk_meshes = |world: &mut World| {
+            let mut query = world.query::<(&HexChunk, &Mesh3d)>();
+            query
+                .iter(world)
+                .map(|(chunk, mesh)| (*chunk, mesh.0.id()))
+                .collect::<Vec<_>>()
+        };
+        let before = chunk_meshes(&mut world);
+        assert_eq!(before.len(), 4, "Startup frame keeps the spawned grid");
+
+        let edited = Hex::new(2, 17);
+        world
+            .resource_mut::<LevelsResource>()
+            .current_level_mut()
+            .set_height(edited, 2.0)
+            .unwrap();
+        schedule.run(&mut world);
+        let after = chunk_meshes(&mut world);
+        assert_eq!(after.len(), 4);
+        let kept = after.iter().filter(|entry| before.contains(entry)).count();
+        assert_eq!(kept, 3, "Only the edited chunk is respawned");
+        assert!(!after.iter().any(
+            |(chunk, mesh)| chunk.contains(edited) && before.iter().any(|(_, old)| old == mesh)
+        ));
+
+        world.resource_mut::<LevelsResource>().set_current_index(1);
+        schedule.run(&mut world);
+        assert_eq!(chunk_meshes(&mut world).len(), 1, "Resized grid is rebuilt");
+    }
+
+    #[test]
+    fn test_grid_index_follows_edits_and_level_switches() {
+        let mut wide = Level::new("Wide".to_string(), 20, 20);
+        wide.overlays = vec![OverlayHex::new(Hex::new(4, 4), 3.0, 0.5)];
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<HexMaterials>();
+        world.init_resource::<TerrainColoring>();
+        world.init_resource::<HexMeshOptions>();
+        world.init_resource::<HexGridRenderConfig>();
+        world.init_resource::<HexEdgeStyle>();
+        world.insert_resource(LevelsResource::new(vec![
+            wide,
+            Level::new("Small".to_string(), 5, 5),
+        ]));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(level_switching_system);
+        schedule.run(&mut world);
+
+        // Every hex of the current level points at the live chunk drawing it
+        let assert_index_complete = |world: &mut World| {
+            let level = world.resource::<LevelsResource>().current_level().clone();
+            let index = world.resource::<HexGridIndex>().clone();
+            assert_eq!(index.len(), level.hex_iter().count());
+            for hex in level.hex_iter() {
+                let entity = index.get(hex).expect("Every hex is indexed");
+                let chunk = world
+                    .get::<HexChunk>(entity)
+                    .expect("Indexed entity is a live chunk entity");
+                assert!(chunk.contains(hex));
+                assert_eq!(index.height(hex), Some(level.get_height(hex)));
+            }
+        };
+        assert_index_complete(&mut world);
+        let overlay_hexes: Vec<_> = world
+            .query::<&HexCoord>()
+            .iter(&world)
+            .map(|coord| coord.0)
+            .collect();
+        assert_eq!(overlay_hexes, vec![Hex::new(4, 4)]);
+
+        let edited = Hex::new(2, 17);
+        let before = world.resource::<HexGridIndex>().get(edited);
+        world
+            .resource_mut::<LevelsResource>()
+            .current_level_mut()
+            .set_height(edited, 2.0)
+            .unwrap();
+        schedule.run(&mut world);
+        assert_ne!(world.resource::<HexGridIndex>().get(edited), before);
+        assert_index_complete(&mut world);
+
+        world.resource_mut::<LevelsResource>().set_current_index(1);
+        schedule.run(&mut world);
+        assert_index_complete(&mut world);
+        assert_eq!(world.resource::<HexGridIndex>().get(edited), None);
+        assert!(world.query::<&HexCoord>().iter(&world).next().is_none());
+    }
+
+    #[test]
+    fn test_level_switches_reuse_materials() {
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<HexMaterials>();
+        world.init_resource::<TerrainColoring>();
+        world.init_resource::<HexMeshOptions>();
+        world.init_resource::<HexGridRenderConfig>();
+        world.init_resource::<HexEdgeStyle>();
+        let mut flooded = Level::new("Flooded".to_string(), 6, 6);
+        flooded.water_level = Some(1.5);
+        world.insert_resource(LevelsResource::new(vec![
+            Level::new("Plain".to_string(), 4, 4),
+            flooded,
+        ]));
+        let material_count = world.resource::<Assets<StandardMaterial>>().len();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(level_switching_system);
+        for switch in 0..10 {
+            world
+                .resource_mut::<LevelsResource>()
+                .set_current_index(switch % 2);
+            schedule.run(&mut world);
+        }
+
+        assert_eq!(
+            world.resource::<Assets<StandardMaterial>>().len(),
+            material_count
+        );
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn test_edge_outlines_follow_level_switches() {
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<HexMaterials>();
+        world.init_resource::<TerrainColoring>();
+        world.init_resource::<HexMeshOptions>();
+        world.init_resource::<HexGridRenderConfig>();
+        world.insert_resource(HexEdgeStyle::Outline);
+        world.insert_resource(LevelsResource::new(vec![
+            Level::new("Wide".to_string(), 20, 20),
+            Level::new("Small".to_string(), 5, 5),
+        ]));
+        let mut schedule = Schedule::default();
+        schedule.add_systems(level_switching_system);
+        schedule.run(&mut world);
+        let outline_count = |world: &mut World| {
+            world
+                .query_filtered::<(), (
+                    With<crate::level::mesh::HexEdgeOutline>,
+                    With<HexGridEntity>,
+                )>()
+                .iter(world)
+                .count()
+        };
+        assert_eq!(outline_count(&mut world), 4, "One outline per chunk");
+
+        world.resource_mut::<LevelsResource>().set_current_index(1);
+        schedule.run(&mut world);
+        assert_eq!(outline_count(&mut world), 1);
+
+        *world.resource_mut::<HexEdgeStyle>() = HexEdgeStyle::Wireframe;
+        schedule.run(&mut world);
+        assert_eq!(outline_count(&mut world), 0);
+        let wireframes = world
+            .query_filtered::<(), With<bevy::pbr::wireframe::Wireframe>>()
+            .iter(&world)
+            .count();
+        assert_eq!(wireframes, 1, "Switching styles respawns the grid");
+    }
 }
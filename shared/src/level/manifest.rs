@@ -0,0 +1,227 @@
+//! Level Manifest
+//!
+//! Optional `manifest.toml` in the levels directory giving a curated load order
+//! and per-level enable flags, so ←/→ cycling follows designer intent instead of
+//! alphabetical order.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::Level;
+
+/// File name of the manifest inside the levels directory
+pub const LEVEL_MANIFEST_FILE: &str = "manifest.toml";
+
+/// File name of the JSON manifest listing the level files a web server hosts
+///
+/// HTTP cannot list a directory, so web builds only fetch the files named here.
+pub const REMOTE_LEVEL_MANIFEST_FILE: &str = "manifest.json";
+
+/// What happens to level files the manifest does not list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnlistedLevels {
+    /// Load them after the listed levels, sorted by name
+    #[default]
+    Append,
+    /// Leave them out entirely
+    Skip,
+}
+
+/// One level file listed in the manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Level file path relative to the levels directory
+    pub file: PathBuf,
+    /// Whether the level is loaded; disabled entries are left out
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Curated level order read from [`LEVEL_MANIFEST_FILE`]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelManifest {
+    /// Handling of level files missing from `levels`
+    #[serde(default)]
+    pub unlisted: UnlistedLevels,
+    /// Level files in the order they should be cycled through
+    #[serde(default)]
+    pub levels: Vec<ManifestEntry>,
+}
+
+impl LevelManifest {
+    /// Parse a manifest from TOML
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        toml::from_str(content).context("Failed to parse level manifest")
+    }
+
+    /// Parse a manifest from JSON, as served in [`REMOTE_LEVEL_MANIFEST_FILE`]
+    pub fn from_json_str(content: &str) -> Result<Self> {
+        serde_json::from_str(content).context("Failed to parse level manifest")
+    }
+
+    /// Files of the enabled entries, in manifest order
+    pub fn enabled_files(&self) -> impl Iterator<Item = &PathBuf> {
+        self.levels
+            .iter()
+            .filter(|entry| entry.enabled)
+            .map(|entry| &entry.file)
+    }
+
+    /// Order loaded levels by the manifest, matching entries to
+    /// [`Level::source_path`]
+    ///
+    /// Disabled entries are dropped, entries naming files that did not load are
+    /// reported and skipped, and unlisted levels follow [`LevelManifest::unlisted`].
+    pub fn apply(&self, mut levels: Vec<Level>) -> Vec<Level> {
+        let mut ordered = Vec::with_capacity(levels.len());
+
+        for entry in &self.levels {
+            let Some(index) = levels
+                .iter()
+                .position(|level| level.source_path.as_ref() == Some(&entry.file))
+            else {
+                warn!(
+                    "Level manifest lists {file}, but no such level was loaded",
+                    file = entry.file.display()
+                );
+                continue;
+            };
+            let level = levels.remove(index);
+            if entry.enabled {
+                ordered.push(level);
+            } else {
+                info!(
+                    "Level '{level_name}' is disabled in the manifest",
+                    level_name = level.name
+                );
+            }
+        }
+
+        match self.unlisted {
+            UnlistedLevels::Append => {
+                levels.sort_by(|a, b| a.name.cmp(&b.name));
+                ordered.append(&mut levels);
+            }
+            UnlistedLevels::Skip => {
+                for level in levels {
+                    info!(
+                        "Skipping level '{level_name}' not listed in the manifest",
+                        level_name = level.name
+                    );
+                }
+            }
+        }
+        ordered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    /// Save levels named after their files into a fresh temporary directory
+    fn level_directory(files: &[&str]) -> TempDir {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        for file in files {
+            let name = file.trim_end_matches(".toml").replace('/', " ");
+            LevelBuilder::new()
+                .name(name)
+                .dimensions(3, 3)
+                .build()
+                .unwrap()
+                .save_to_directory(temp_path, file)
+                .expect("Failed to save level");
+        }
+        temp_dir
+    }
+
+    fn loaded_names(temp_dir: &TempDir) -> Vec<String> {
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        load_levels_from_directory(temp_path)
+            .expect("Failed to load levels")
+            .levels
+            .into_iter()
+            .map(|level| level.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_manifest_orders_and_filters_levels() {
+        let temp_dir = level_directory(&["alpha.toml", "bravo.toml", "charlie.toml", "delta.toml"]);
+        fs::write(
+            temp_dir.path().join(LEVEL_MANIFEST_FILE),
+            r#"
+[[levels]]
+file = "charlie.toml"
+
+[[levels]]
+file = "alpha.toml"
+
+[[levels]]
+file = "bravo.toml"
+enabled = false
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(loaded_names(&temp_dir), ["charlie", "alpha", "delta"]);
+    }
+
+    #[test]
+    fn test_manifest_can_skip_unlisted_levels() {
+        let temp_dir = level_directory(&["alpha.toml", "bravo.toml", "campaign/one.toml"]);
+        fs::write(
+            temp_dir.path().join(LEVEL_MANIFEST_FILE),
+            "unlisted = \"skip\"\n\n[[levels]]\nfile = \"campaign/one.toml\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(loaded_names(&temp_dir), ["campaign one"]);
+    }
+
+    #[test]
+    fn test_missing_manifest_sorts_by_name() {
+        let temp_dir = level_directory(&["charlie.toml", "alpha.toml", "bravo.toml"]);
+
+        assert_eq!(loaded_names(&temp_dir), ["alpha", "bravo", "charlie"]);
+    }
+
+    #[test]
+    fn test_json_manifest_lists_enabled_files() {
+        let manifest = LevelManifest::from_json_str(
+            r#"{"levels": [{"file": "b.toml"}, {"file": "a.toml", "enabled": false}, {"file": "c/d.toml"}]}"#,
+        )
+        .expect("Valid JSON manifest");
+
+        let files: Vec<_> = manifest.enabled_files().collect();
+        assert_eq!(files, [Path::new("b.toml"), Path::new("c/d.toml")]);
+        assert_eq!(manifest.unlisted, UnlistedLevels::Append);
+        assert!(LevelManifest::from_json_str("{\"levels\": 3}").is_err());
+    }
+
+    #[test]
+    fn test_manifest_referencing_missing_file_is_skipped() {
+        let temp_dir = level_directory(&["alpha.toml", "bravo.toml"]);
+        fs::write(
+            temp_dir.path().join(LEVEL_MANIFEST_FILE),
+            "[[levels]]\nfile = \"gone.toml\"\n\n[[levels]]\nfile = \"bravo.toml\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(loaded_names(&temp_dir), ["bravo", "alpha"]);
+    }
+}
@@ -2,28 +2,374 @@
 //!
 //! Hex column mesh generation, grid spawning systems, and mesh utilities
 //! for rendering tactical RPG level geometry.
+//!
+//! Ground columns are merged into one mesh per material and [`HexChunk`], so
+//! draw calls grow with the chunk count rather than the hex count, and editing a
+//! hex only rebuilds its chunk.
+//!
+//! Hex edges are drawn either as native wireframes or as line meshes tracing
+//! each top face, which also work on WASM; see [`HexEdgeStyle`].
 
+use std::collections::HashMap;
+use std::ops::Range;
+
+use bevy::ecs::system::SystemParam;
 #[cfg(not(target_arch = "wasm32"))]
 use bevy::pbr::wireframe::Wireframe;
 use bevy::prelude::*;
 use bevy::render::{
     mesh::{Indices, PrimitiveTopology},
+    primitives::Aabb,
     render_asset::RenderAssetUsages,
 };
-use hexx::{ColumnMeshBuilder, HexLayout};
+use hexx::{
+    ColumnMeshBuilder, FaceOptions, Hex, HexLayout, InsetOptions, InsetScaleMode, MeshInfo,
+    PlaneMeshBuilder, UVOptions,
+};
 use tracing::info;
 
+use super::chunks::{HexChunk, SpawnedGrid, hex_chunks};
+use super::render_config::{HexGridRenderConfig, SurfaceMaterial, VoidRendering, VoidStyle};
 use super::{Level, LevelsResource};
-use crate::colors::HEX_SURFACE_GRAY;
+use crate::colors::{
+    HEX_EDGE_GREEN, HEX_IMPASSABLE_RED, HEX_SUBMERGED_SAND, HEX_SURFACE_GRAY, HEX_VOID_SLATE,
+    WATER_BLUE, terrain_color,
+};
 
 /// Component to mark entities that are part of the hex grid
 #[derive(Component)]
 pub struct HexGridEntity;
 
+/// Component marking the line meshes of [`HexEdgeStyle::Outline`]
+#[derive(Component)]
+pub struct HexEdgeOutline;
+
+/// Hex drawn by a grid entity that renders a single hex, such as an overlay slab
+///
+/// Merged column entities cover many hexes; look those up in [`HexGridIndex`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexCoord(pub Hex);
+
+/// Entity drawing each hex's column, with the height it was spawned at
+///
+/// Replaced whenever the whole grid is spawned and updated as chunks are
+/// respawned, so it always matches the rendered grid.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct HexGridIndex(HashMap<Hex, (Entity, f32)>);
+
+impl HexGridIndex {
+    /// Merged column entity containing `hex`
+    pub fn get(&self, hex: Hex) -> Option<Entity> {
+        self.0.get(&hex).map(|&(entity, _)| entity)
+    }
+
+    /// Height the column of `hex` was spawned at
+    pub fn height(&self, hex: Hex) -> Option<f32> {
+        self.0.get(&hex).map(|&(_, height)| height)
+    }
+
+    /// Number of indexed hexes
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn insert(&mut self, hex: Hex, entity: Entity, height: f32) {
+        self.0.insert(hex, (entity, height));
+    }
+}
+
+/// Height of outlines above the faces they trace, avoiding z-fighting
+const OUTLINE_LIFT: f32 = 0.005;
+
+/// Vertex range of each hex within a merged grid mesh
+///
+/// Attached to every merged column entity so highlight and edit systems can
+/// address individual hexes.
+#[derive(Component, Debug, Clone, Default)]
+pub struct HexVertexRanges(HashMap<Hex, Range<u32>>);
+
+impl HexVertexRanges {
+    /// Vertices belonging to `hex`, if it is part of this mesh
+    pub fn get(&self, hex: Hex) -> Option<Range<u32>> {
+        self.0.get(&hex).cloned()
+    }
+
+    /// Number of hexes in the mesh
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Accumulates hex columns into shared vertex and index buffers
+///
+/// Indices are `u32`, since a merged grid easily exceeds the `u16` vertex limit
+/// of a single column mesh.
+#[derive(Debug, Default)]
+pub struct MergedColumnMesh {
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    /// Linear RGBA per vertex; empty unless columns were pushed with a color
+    colors: Vec<[f32; 4]>,
+    indices: Vec<u32>,
+    ranges: HexVertexRanges,
+}
+
+impl MergedColumnMesh {
+    /// Append the column geometry for `hex`, translated by `offset`
+    pub fn push_column(&mut self, hex: Hex, geometry: ColumnGeometry, offset: Vec3) {
+        let start = self.positions.len() as u32;
+        self.positions.extend(
+            geometry
+                .vertices
+                .iter()
+                .map(|vertex| (*vertex + offset).to_array()),
+        );
+        self.normals
+            .extend(geometry.normals.iter().map(|normal| normal.to_array()));
+        self.uvs.extend(geometry.uvs.iter().map(|uv| uv.to_array()));
+        self.indices
+            .extend(geometry.indices.iter().map(|&index| start + index));
+        let end = self.positions.len() as u32;
+        self.ranges.0.insert(hex, start..end);
+    }
+
+    /// Append a column like [`Self::push_column`], with every vertex in `color`
+    ///
+    /// Either all or none of a mesh's columns should carry a color.
+    pub fn push_colored_column(
+        &mut self,
+        hex: Hex,
+        geometry: ColumnGeometry,
+        offset: Vec3,
+        color: Color,
+    ) {
+        let vertex_count = geometry.vertices.len();
+        self.push_column(hex, geometry, offset);
+        let color = color.to_linear().to_f32_array();
+        self.colors.extend(std::iter::repeat_n(color, vertex_count));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Finish into a render mesh and the per-hex vertex lookup
+    pub fn build(self) -> (Mesh, HexVertexRanges) {
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::RENDER_WORLD,
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, self.positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, self.normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, self.uvs)
+        .with_inserted_indices(Indices::U32(self.indices));
+        if !self.colors.is_empty() {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, self.colors);
+        }
+        (mesh, self.ranges)
+    }
+}
+
+/// Key for sharing one mesh asset between columns or slabs of the same height,
+/// at millimeter precision
+pub fn mesh_height_key(height: f32) -> i32 {
+    (height * 1000.0).round() as i32
+}
+
+/// Geometry options for hex column meshes
+///
+/// The default is the cheap open-bottom column the game renders. Inserting a
+/// different value as a resource changes how the grid is spawned.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct HexMeshOptions {
+    /// Close the column with a bottom face, e.g. for exported geometry
+    pub bottom_face: bool,
+    /// Quads stacked on each side wall; values below 1 count as 1
+    pub side_subdivisions: usize,
+    /// Fraction by which the cap faces are inset, adding a bevel ring of
+    /// triangles around them; 0.0 keeps plain caps
+    pub cap_inset: f32,
+    /// Average the side wall normals where walls meet, rounding the shading
+    /// of the column; the caps stay flat
+    pub smooth_sides: bool,
+}
+
+impl Default for HexMeshOptions {
+    fn default() -> Self {
+        Self {
+            bottom_face: false,
+            side_subdivisions: 1,
+            cap_inset: 0.0,
+            smooth_sides: false,
+        }
+    }
+}
+
+/// Side quads built per hexx column part
+///
+/// hexx meshes use `u16` indices, so tall subdivided columns are assembled from
+/// stacked parts that each stay far below 65,536 vertices.
+const SUBDIVISIONS_PER_PART: usize = 1024;
+
+/// Raw hex column geometry with `u32` indices
+///
+/// Field names follow [`MeshInfo`], which this is assembled from.
+#[derive(Debug, Clone, Default)]
+pub struct ColumnGeometry {
+    pub vertices: Vec<Vec3>,
+    pub normals: Vec<Vec3>,
+    pub uvs: Vec<Vec2>,
+    pub indices: Vec<u32>,
+}
+
+impl ColumnGeometry {
+    /// Replace each side wall normal by the average of all side wall normals at
+    /// the same position, leaving cap normals untouched
+    fn smooth_side_normals(&mut self) {
+        let is_side = |normal: Vec3| normal.y.abs() < 1e-4;
+        let position_key = |vertex: Vec3| (vertex * 1e4).round().as_ivec3();
+
+        let mut sums: HashMap<IVec3, Vec3> = HashMap::new();
+        for (vertex, normal) in self.vertices.iter().zip(&self.normals) {
+            if is_side(*normal) {
+                *sums.entry(position_key(*vertex)).or_default() += *normal;
+            }
+        }
+        for (vertex, normal) in self.vertices.iter().zip(&mut self.normals) {
+            if is_side(*normal) {
+                *normal = sums[&position_key(*vertex)].normalize_or(*normal);
+            }
+        }
+    }
+
+    fn append(&mut self, part: MeshInfo) {
+        let start = self.vertices.len() as u32;
+        self.vertices.extend(part.vertices);
+        self.normals.extend(part.normals);
+        self.uvs.extend(part.uvs);
+        self.indices
+            .extend(part.indices.iter().map(|&index| start + u32::from(index)));
+    }
+}
+
+/// Flat top face of a hex, as [`ColumnGeometry`] so it merges like a column
+pub fn hex_face_geometry(layout: &HexLayout) -> ColumnGeometry {
+    let mut geometry = ColumnGeometry::default();
+    geometry.append(PlaneMeshBuilder::new(layout).center_aligned().build());
+    geometry
+}
+
+/// Index buffer in the narrowest format that can address `vertex_count` vertices
+pub fn mesh_indices(indices: Vec<u32>, vertex_count: usize) -> Indices {
+    if vertex_count <= usize::from(u16::MAX) + 1 {
+        Indices::U16(indices.into_iter().map(|index| index as u16).collect())
+    } else {
+        Indices::U32(indices)
+    }
+}
+
 /// Create a hex column mesh using the hexx library
-pub fn create_hex_column_mesh(layout: &HexLayout, height: f32) -> Mesh {
-    let mesh_info = ColumnMeshBuilder::new(layout, height)
-        .without_bottom_face()
+///
+/// The column footprint follows `layout`'s orientation and scale, so pass the
+/// owning level's [`Level::hex_layout`].
+///
+/// Positive heights produce a column rising from the origin to `height`. Negative
+/// heights produce a column extending downward, spanning `height..0`, built from the
+/// absolute height so side normals still face outward.
+///
+/// Indices are `u16` unless the column has more vertices than they can address.
+pub fn create_hex_column_mesh(layout: &HexLayout, height: f32, options: &HexMeshOptions) -> Mesh {
+    let geometry = hex_column_mesh_info(layout, height, options);
+    let indices = mesh_indices(geometry.indices, geometry.vertices.len());
+
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, geometry.vertices)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, geometry.normals)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, geometry.uvs)
+    .with_inserted_indices(indices)
+}
+
+/// Build the raw column geometry used by [`create_hex_column_mesh`]
+///
+/// Exposed so exporters can reproduce exactly what the game renders. Top-face
+/// UVs span the hexagon regardless of height, while side-wall UVs tile
+/// vertically once per hex edge length of height, so textures keep the same
+/// scale on short and tall columns.
+pub fn hex_column_mesh_info(
+    layout: &HexLayout,
+    height: f32,
+    options: &HexMeshOptions,
+) -> ColumnGeometry {
+    let (column_height, vertical_offset) = if height < 0.0 {
+        (-height, height)
+    } else {
+        (height, 0.0)
+    };
+    let subdivisions = options.side_subdivisions.max(1);
+    let quad_height = column_height / subdivisions as f32;
+    // The edge of a regular hexagon is as long as its radius. Each stacked side
+    // quad maps its own 0..1 UV range, so the scale is split between them.
+    let sides = FaceOptions {
+        uv: UVOptions::new().with_scale_factor(Vec2::new(1.0, quad_height / layout.scale.x)),
+        ..FaceOptions::new()
+    };
+
+    let mut geometry = ColumnGeometry::default();
+    let mut built = 0;
+    while built < subdivisions {
+        let part_subdivisions = SUBDIVISIONS_PER_PART.min(subdivisions - built);
+        let is_first = built == 0;
+        let is_last = built + part_subdivisions == subdivisions;
+
+        let mut builder = ColumnMeshBuilder::new(layout, quad_height * part_subdivisions as f32)
+            .with_subdivisions(part_subdivisions)
+            .with_sides_options(sides);
+        if !is_last {
+            builder = builder.without_top_face();
+        }
+        if !(is_first && options.bottom_face) {
+            builder = builder.without_bottom_face();
+        }
+        if options.cap_inset > 0.0 {
+            builder = builder.with_caps_inset_options(InsetOptions {
+                keep_inner_face: true,
+                scale: options.cap_inset,
+                mode: InsetScaleMode::Centroid,
+            });
+        }
+        let part_base = vertical_offset + quad_height * built as f32;
+        geometry.append(
+            builder
+                .center_aligned()
+                .with_offset(Vec3::Y * part_base)
+                .build(),
+        );
+        built += part_subdivisions;
+    }
+    if options.smooth_sides {
+        geometry.smooth_side_normals();
+    }
+    geometry
+}
+
+/// Create a floating slab mesh for an overlay hex, spanning `0..thickness`
+///
+/// Unlike ground columns the bottom face is kept, since the underside of a bridge
+/// is visible from low camera angles.
+pub fn create_overlay_mesh(layout: &HexLayout, thickness: f32) -> Mesh {
+    let mesh_info = ColumnMeshBuilder::new(layout, thickness)
         .center_aligned()
         .build();
 
@@ -37,24 +383,445 @@ pub fn create_hex_column_mesh(layout: &HexLayout, height: f32) -> Mesh {
     .with_inserted_indices(Indices::U16(mesh_info.indices))
 }
 
+/// Create a wedge mesh sloping from `low` up to `high`, both in world space
+///
+/// The wedge has a sloped top face of the given `width` and two triangular side
+/// faces; the back is left open since it rests against the taller column.
+pub fn create_ramp_mesh(low: Vec3, high: Vec3, width: f32) -> Mesh {
+    let forward = Vec3::new(high.x - low.x, 0.0, high.z - low.z).normalize_or_zero();
+    let side = Vec3::new(-forward.z, 0.0, forward.x) * (width / 2.0);
+    let high_base = Vec3::new(high.x, low.y, high.z);
+
+    let mut top_normal = (high - low).cross(side).normalize_or_zero();
+    if top_normal.y < 0.0 {
+        top_normal = -top_normal;
+    }
+    let side_normal = side.normalize_or_zero();
+
+    let positions: Vec<[f32; 3]> = [
+        // Sloped top face
+        low - side,
+        low + side,
+        high + side,
+        high - side,
+        // Side faces
+        low + side,
+        high_base + side,
+        high + side,
+        low - side,
+        high - side,
+        high_base - side,
+    ]
+    .iter()
+    .map(|v| v.to_array())
+    .collect();
+    let normals: Vec<[f32; 3]> = [
+        [top_normal; 4].as_slice(),
+        [side_normal; 3].as_slice(),
+        [-side_normal; 3].as_slice(),
+    ]
+    .concat()
+    .iter()
+    .map(|n| n.to_array())
+    .collect();
+
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+    .with_inserted_indices(Indices::U16(vec![0, 1, 2, 0, 2, 3, 4, 5, 6, 7, 8, 9]))
+}
+
+/// Append the six edges of `hex`'s top face at `top` as line-list segments
+fn push_hex_outline(positions: &mut Vec<[f32; 3]>, layout: &HexLayout, hex: Hex, top: f32) {
+    let y = top + OUTLINE_LIFT;
+    for [start, end] in layout.hex_edge_corners(hex) {
+        positions.push([start.x, y, start.y]);
+        positions.push([end.x, y, end.y]);
+    }
+}
+
+/// Line-list mesh from segment endpoint pairs
+fn create_outline_mesh(positions: Vec<[f32; 3]>) -> Mesh {
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+    Mesh::new(PrimitiveTopology::LineList, RenderAssetUsages::RENDER_WORLD)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+}
+
+/// How hex edges are drawn
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HexEdgeStyle {
+    /// Line meshes tracing the top face of every column and overlay
+    Outline,
+    /// Wireframes over every triangle; native only, since WebGL lacks line
+    /// polygon mode
+    #[cfg(not(target_arch = "wasm32"))]
+    Wireframe,
+}
+
+impl Default for HexEdgeStyle {
+    fn default() -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        return Self::Wireframe;
+        #[cfg(target_arch = "wasm32")]
+        return Self::Outline;
+    }
+}
+
+/// Add tactical green wireframe edges to `entity` if `edges` asks for them
+#[cfg(not(target_arch = "wasm32"))]
+fn insert_wireframe(entity: &mut EntityCommands, edges: HexEdgeStyle) {
+    if edges == HexEdgeStyle::Wireframe {
+        entity.insert(Wireframe);
+    }
+}
+
+/// WebGL has no wireframes, so there is nothing to add
+#[cfg(target_arch = "wasm32")]
+fn insert_wireframe(_entity: &mut EntityCommands, _edges: HexEdgeStyle) {}
+
+/// How walkable hex columns are colored
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TerrainColoring {
+    /// Vertex colors from [`terrain_color`], shading columns by relative height
+    #[default]
+    HeightGradient,
+    /// Every walkable column in the flat tactical gray
+    Flat,
+}
+
+/// Surface category of a hex column, choosing its material
+///
+/// Until levels carry terrain types, categories come from walkability and the
+/// water level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TerrainCategory {
+    /// Walkable column above any water
+    Ground,
+    /// Walkable column whose top lies below the water level
+    Submerged,
+    /// Column units cannot enter
+    Impassable,
+}
+
+impl TerrainCategory {
+    pub const ALL: [Self; 3] = [Self::Ground, Self::Submerged, Self::Impassable];
+
+    /// Category of the column at `hex`
+    pub fn of(level: &Level, hex: Hex) -> Self {
+        if !level.is_walkable(hex) {
+            Self::Impassable
+        } else if level
+            .water_level
+            .is_some_and(|water_level| level.get_height(hex) < water_level)
+        {
+            Self::Submerged
+        } else {
+            Self::Ground
+        }
+    }
+}
+
+/// Materials for hex columns and the other grid geometry
+///
+/// Built once when [`LevelPlugin`](super::LevelPlugin) is added and shared by
+/// every spawned grid, so level switches add no material assets.
+#[derive(Resource, Debug, Clone)]
+pub struct HexMaterials {
+    /// Tactical gray for flat-colored ground, overlays, and ramps
+    pub surface: Handle<StandardMaterial>,
+    /// White ground whose vertex colors show unchanged under
+    /// [`TerrainColoring::HeightGradient`]
+    pub vertex_colored: Handle<StandardMaterial>,
+    pub submerged: Handle<StandardMaterial>,
+    pub impassable: Handle<StandardMaterial>,
+    pub water: Handle<StandardMaterial>,
+    /// Unlit tactical green for [`HexEdgeStyle::Outline`] lines
+    pub edge: Handle<StandardMaterial>,
+    /// Translucent tiles of [`VoidStyle::Tile`]
+    pub void: Handle<StandardMaterial>,
+}
+
+impl HexMaterials {
+    /// Material for columns of `category` under `coloring`
+    pub fn column(
+        &self,
+        category: TerrainCategory,
+        coloring: TerrainColoring,
+    ) -> &Handle<StandardMaterial> {
+        match (category, coloring) {
+            (TerrainCategory::Ground, TerrainColoring::HeightGradient) => &self.vertex_colored,
+            (TerrainCategory::Ground, TerrainColoring::Flat) => &self.surface,
+            (TerrainCategory::Submerged, _) => &self.submerged,
+            (TerrainCategory::Impassable, _) => &self.impassable,
+        }
+    }
+}
+
+impl FromWorld for HexMaterials {
+    fn from_world(world: &mut World) -> Self {
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        // Shading follows HexGridRenderConfig once apply_surface_material_system runs
+        let hex_material = |base_color| {
+            let mut material = StandardMaterial {
+                base_color,
+                ..default()
+            };
+            SurfaceMaterial::default().apply_to(&mut material);
+            material
+        };
+
+        Self {
+            // Create tactical gray material for hex surfaces
+            surface: materials.add(hex_material(HEX_SURFACE_GRAY)),
+            vertex_colored: materials.add(hex_material(Color::WHITE)),
+            submerged: materials.add(hex_material(HEX_SUBMERGED_SAND)),
+            // Create distinct material for impassable hexes so designers can spot them
+            impassable: materials.add(hex_material(HEX_IMPASSABLE_RED)),
+            water: materials.add(StandardMaterial {
+                base_color: WATER_BLUE,
+                alpha_mode: AlphaMode::Blend,
+                perceptual_roughness: 0.1,
+                reflectance: 0.5,
+                double_sided: true,
+                cull_mode: None,
+                ..default()
+            }),
+            edge: materials.add(StandardMaterial {
+                base_color: HEX_EDGE_GREEN,
+                unlit: true,
+                ..default()
+            }),
+            void: materials.add(StandardMaterial {
+                base_color: HEX_VOID_SLATE,
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }),
+        }
+    }
+}
+
+/// Settings shaping how merged hex columns are built, colored, and edged
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ColumnStyle {
+    pub coloring: TerrainColoring,
+    pub mesh_options: HexMeshOptions,
+    pub edges: HexEdgeStyle,
+    /// Hexes that get no column, if any
+    pub void: Option<VoidRendering>,
+}
+
+/// Asset stores and settings the grid spawning systems share
+#[derive(SystemParam)]
+pub struct GridSpawnAssets<'w, 's> {
+    pub meshes: ResMut<'w, Assets<Mesh>>,
+    pub hex_materials: Res<'w, HexMaterials>,
+    pub coloring: Res<'w, TerrainColoring>,
+    pub mesh_options: Res<'w, HexMeshOptions>,
+    pub edge_style: Res<'w, HexEdgeStyle>,
+    pub render_config: Res<'w, HexGridRenderConfig>,
+    /// Void rendering as of the last [`Self::mark_style_checked`] call, so
+    /// unrelated render config changes like texture toggles are ignored
+    checked_void: Local<'s, Option<VoidRendering>>,
+}
+
+impl GridSpawnAssets<'_, '_> {
+    /// Current column style from the coloring, mesh option, and render config
+    /// resources
+    pub fn style(&self) -> ColumnStyle {
+        ColumnStyle {
+            coloring: *self.coloring,
+            mesh_options: *self.mesh_options,
+            edges: *self.edge_style,
+            void: self.render_config.active_void(),
+        }
+    }
+
+    /// Whether the column style changed since the system last ran
+    pub fn is_style_changed(&self) -> bool {
+        self.coloring.is_changed()
+            || self.mesh_options.is_changed()
+            || self.edge_style.is_changed()
+            || *self.checked_void != self.render_config.active_void()
+    }
+
+    /// Remember the current void rendering for the next
+    /// [`Self::is_style_changed`] check
+    pub fn mark_style_checked(&mut self) {
+        *self.checked_void = self.render_config.active_void();
+    }
+}
+
+/// World-space bounds of everything [`spawn_chunk_columns`] draws for `chunk`
+///
+/// Spans the hex corners horizontally and the column base up to the highest
+/// column top, void tile, or outline, so frustum culling never drops a chunk
+/// that is partly on screen.
+pub fn chunk_aabb(level: &Level, chunk: HexChunk, void: Option<VoidRendering>) -> Aabb {
+    let hex_layout = level.hex_layout();
+    let mut min = Vec3::new(f32::MAX, level.column_base(), f32::MAX);
+    let mut max = Vec3::new(f32::MIN, f32::MIN, f32::MIN);
+    for hex in chunk.hexes() {
+        let height = level.get_height(hex);
+        let top = void.map_or(height, |void| height.max(void.threshold));
+        max.y = max.y.max(top + OUTLINE_LIFT);
+        for corner in hex_layout.hex_corners(hex) {
+            min.x = min.x.min(corner.x);
+            min.z = min.z.min(corner.y);
+            max.x = max.x.max(corner.x);
+            max.z = max.z.max(corner.y);
+        }
+    }
+    Aabb::from_min_max(min, max.max(min))
+}
+
+/// Spawn the merged column meshes of one chunk, one entity per
+/// [`TerrainCategory`] present, plus the chunk's outlines if edges are drawn
+/// as [`HexEdgeStyle::Outline`]
+///
+/// Void hexes get no column, only a merged translucent tile under
+/// [`VoidStyle::Tile`]. Every entity carries the [`chunk_aabb`] for frustum
+/// culling.
+///
+/// Every entity is tagged with `chunk` so the chunk can be rebuilt on its own,
+/// and the chunk's hexes are recorded in `grid_index`.
+pub fn spawn_chunk_columns(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    level: &Level,
+    chunk: HexChunk,
+    hex_materials: &HexMaterials,
+    style: ColumnStyle,
+    grid_index: &mut HexGridIndex,
+) {
+    let ColumnStyle {
+        coloring,
+        mesh_options,
+        edges,
+        void,
+    } = style;
+    let hex_layout = level.hex_layout();
+    // Columns are extruded from the level base so trenches stay closed off
+    let column_base = level.column_base();
+
+    // Gradient colors span the whole level so chunks blend seamlessly
+    let stats = level.stats();
+    let aabb = chunk_aabb(level, chunk, void);
+
+    // Columns of each category merge into one mesh per material
+    let mut merged: HashMap<TerrainCategory, MergedColumnMesh> = HashMap::new();
+    let mut void_tiles = MergedColumnMesh::default();
+    let mut outline = Vec::new();
+    for hex in chunk.hexes() {
+        let height = level.get_height(hex);
+        if let Some(void) = void.filter(|void| void.is_void(height)) {
+            if void.style == VoidStyle::Tile {
+                let world_pos = hex_layout.hex_to_world_pos(hex);
+                let offset = Vec3::new(world_pos.x, void.threshold, world_pos.y);
+                void_tiles.push_column(hex, hex_face_geometry(&hex_layout), offset);
+            }
+            continue;
+        }
+        if edges == HexEdgeStyle::Outline {
+            push_hex_outline(&mut outline, &hex_layout, hex, height);
+        }
+        let world_pos = hex_layout.hex_to_world_pos(hex);
+        let mesh_info = hex_column_mesh_info(&hex_layout, height - column_base, &mesh_options);
+        let offset = Vec3::new(world_pos.x, column_base, world_pos.y);
+        let category = TerrainCategory::of(level, hex);
+        let columns = merged.entry(category).or_default();
+        if category == TerrainCategory::Ground && coloring == TerrainColoring::HeightGradient {
+            let color = terrain_color(height, stats.min_height, stats.max_height);
+            columns.push_colored_column(hex, mesh_info, offset, color);
+        } else {
+            columns.push_column(hex, mesh_info, offset);
+        }
+    }
+
+    for category in TerrainCategory::ALL {
+        let Some(columns) = merged.remove(&category) else {
+            continue;
+        };
+        let material = hex_materials.column(category, coloring).clone();
+        let (mesh, ranges) = columns.build();
+        let hexes: Vec<Hex> = ranges.0.keys().copied().collect();
+
+        let mut entity = commands.spawn((
+            Mesh3d(meshes.add(mesh)),
+            MeshMaterial3d(material),
+            Transform::IDENTITY,
+            aabb,
+            ranges,
+            chunk,
+            HexGridEntity, // Mark for easy identification/cleanup
+        ));
+        insert_wireframe(&mut entity, edges);
+        let entity = entity.id();
+        for hex in hexes {
+            grid_index.insert(hex, entity, level.get_height(hex));
+        }
+    }
+
+    if !void_tiles.is_empty() {
+        let (mesh, ranges) = void_tiles.build();
+        let hexes: Vec<Hex> = ranges.0.keys().copied().collect();
+        let entity = commands
+            .spawn((
+                Mesh3d(meshes.add(mesh)),
+                MeshMaterial3d(hex_materials.void.clone()),
+                Transform::IDENTITY,
+                aabb,
+                ranges,
+                chunk,
+                HexGridEntity,
+            ))
+            .id();
+        for hex in hexes {
+            grid_index.insert(hex, entity, level.get_height(hex));
+        }
+    }
+
+    if !outline.is_empty() {
+        commands.spawn((
+            Mesh3d(meshes.add(create_outline_mesh(outline))),
+            MeshMaterial3d(hex_materials.edge.clone()),
+            Transform::IDENTITY,
+            aabb,
+            chunk,
+            HexEdgeOutline,
+            HexGridEntity,
+        ));
+    }
+}
+
 /// System to spawn hex grid based on the LevelsResource (used for initial spawn)
 pub fn spawn_hex_grid(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut assets: GridSpawnAssets,
     levels_resource: Res<LevelsResource>,
 ) {
-    spawn_hex_grid_internal(&mut commands, &mut meshes, &mut materials, &levels_resource);
+    let style = assets.style();
+    spawn_hex_grid_internal(
+        &mut commands,
+        &mut assets.meshes,
+        &assets.hex_materials,
+        levels_resource.current_level(),
+        style,
+    );
 }
 
-/// Internal function to spawn hex grid for a given level
+/// Spawn the hex grid for `level`, which need not be part of [`LevelsResource`]
 pub fn spawn_hex_grid_internal(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-    levels_resource: &Res<LevelsResource>,
+    meshes: &mut Assets<Mesh>,
+    hex_materials: &HexMaterials,
+    level: &Level,
+    style: ColumnStyle,
 ) {
-    let level = levels_resource.current_level();
     info!(
         "Spawning hex grid for level '{level_name}' ({width}x{height})",
         level_name = level.name,
@@ -63,47 +830,773 @@ pub fn spawn_hex_grid_internal(
     );
 
     // Use centralized hex layout configuration for consistency
-    let hex_layout = Level::hex_layout();
-
-    // Create tactical gray material for hex surfaces
-    let hex_material = materials.add(StandardMaterial {
-        base_color: HEX_SURFACE_GRAY,
-        metallic: 0.1,
-        perceptual_roughness: 0.8,
-        reflectance: 0.2,
-        ..default()
-    });
-
-    // Generate hex grid from Level data
-    let hex_grid = level.get_hex_grid();
+    let hex_layout = level.hex_layout();
+    let hex_material = hex_materials.surface.clone();
+
+    let chunks = hex_chunks(level.width, level.height);
     info!(
-        "Generated {count} hex coordinates for the grid",
-        count = hex_grid.len()
+        "Generating {count} hex columns in {chunk_count} chunks",
+        count = level.width * level.height,
+        chunk_count = chunks.len()
     );
+    let mut grid_index = HexGridIndex::default();
+    for chunk in chunks {
+        spawn_chunk_columns(
+            commands,
+            meshes,
+            level,
+            chunk,
+            hex_materials,
+            style,
+            &mut grid_index,
+        );
+    }
 
-    for hex in hex_grid {
-        let height = level.get_height(hex);
-        let hex_mesh = create_hex_column_mesh(&hex_layout, height);
-        let world_pos = hex_layout.hex_to_world_pos(hex);
+    // Slabs of equal thickness share one mesh asset
+    let mut overlay_meshes: HashMap<i32, Handle<Mesh>> = HashMap::new();
+    let mut overlay_outline = Vec::new();
+    for overlay in &level.overlays {
+        let world_pos = hex_layout.hex_to_world_pos(overlay.coord());
+        let overlay_mesh = overlay_meshes
+            .entry(mesh_height_key(overlay.thickness))
+            .or_insert_with(|| meshes.add(create_overlay_mesh(&hex_layout, overlay.thickness)))
+            .clone();
 
-        // Spawn hex column - with wireframes on native, without on WASM
-        #[cfg(not(target_arch = "wasm32"))]
-        commands.spawn((
-            Mesh3d(meshes.add(hex_mesh)),
+        let mut entity = commands.spawn((
+            Mesh3d(overlay_mesh),
             MeshMaterial3d(hex_material.clone()),
-            Transform::from_xyz(world_pos.x, 0.0, world_pos.y),
-            Wireframe,     // Add tactical green wireframe edges (native only)
-            HexGridEntity, // Mark for easy identification/cleanup
+            Transform::from_xyz(world_pos.x, overlay.floor, world_pos.y),
+            HexCoord(overlay.coord()),
+            HexGridEntity,
+        ));
+        insert_wireframe(&mut entity, style.edges);
+        if style.edges == HexEdgeStyle::Outline {
+            push_hex_outline(
+                &mut overlay_outline,
+                &hex_layout,
+                overlay.coord(),
+                overlay.floor + overlay.thickness,
+            );
+        }
+    }
+    if !overlay_outline.is_empty() {
+        commands.spawn((
+            Mesh3d(meshes.add(create_outline_mesh(overlay_outline))),
+            MeshMaterial3d(hex_materials.edge.clone()),
+            Transform::IDENTITY,
+            HexEdgeOutline,
+            HexGridEntity,
         ));
+    }
 
-        #[cfg(target_arch = "wasm32")]
+    if let Some(water_level) = level.water_level {
+        spawn_water_plane(commands, meshes, hex_materials, level, water_level);
+    }
+
+    for ramp in &level.ramps {
+        let (a, b) = ramp.hexes();
+        let (Some(height_a), Some(height_b)) = (level.try_get_height(a), level.try_get_height(b))
+        else {
+            continue;
+        };
+        // Lean the wedge against the cliff on the lower hex
+        let (low, low_height, high, high_height) = if height_a <= height_b {
+            (a, height_a, b, height_b)
+        } else {
+            (b, height_b, a, height_a)
+        };
+        let low_center = hex_layout.hex_to_world_pos(low);
+        let edge_mid = (low_center + hex_layout.hex_to_world_pos(high)) / 2.0;
         commands.spawn((
-            Mesh3d(meshes.add(hex_mesh)),
+            Mesh3d(meshes.add(create_ramp_mesh(
+                Vec3::new(low_center.x, low_height, low_center.y),
+                Vec3::new(edge_mid.x, high_height, edge_mid.y),
+                0.8 * level.hex_scale,
+            ))),
             MeshMaterial3d(hex_material.clone()),
-            Transform::from_xyz(world_pos.x, 0.0, world_pos.y),
-            HexGridEntity, // Mark for easy identification/cleanup
+            Transform::IDENTITY,
+            HexGridEntity,
         ));
     }
 
+    commands.insert_resource(SpawnedGrid(level.clone()));
+    commands.insert_resource(grid_index);
     info!("Hex grid spawning completed");
 }
+
+/// Spawn a translucent water plane at `water_level` covering the level bounds
+///
+/// The plane is tagged as part of the hex grid so level switching cleans it up.
+/// Camera raycasting only tests hex top surfaces, so the plane never captures focus.
+fn spawn_water_plane(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    hex_materials: &HexMaterials,
+    level: &Level,
+    water_level: f32,
+) {
+    let (min_bounds, max_bounds) = level.get_world_bounds();
+
+    // Bounds run through hex centers, so pad each side by the hex's reach
+    // from its center for the plane to end at the outer column edges
+    let hex_layout = level.hex_layout();
+    let reach = hex_layout
+        .hex_corners(Hex::ZERO)
+        .into_iter()
+        .fold(Vec2::ZERO, |reach, corner| reach.max(corner.abs()));
+    let size = Vec2::new(
+        max_bounds.x - min_bounds.x + 2.0 * reach.x,
+        max_bounds.z - min_bounds.z + 2.0 * reach.y,
+    );
+    let center = (min_bounds + max_bounds) / 2.0;
+
+    let entity = commands
+        .spawn((
+            Mesh3d(meshes.add(Plane3d::default().mesh().size(size.x, size.y))),
+            MeshMaterial3d(hex_materials.water.clone()),
+            Transform::from_xyz(center.x, water_level, center.z),
+            HexGridEntity, // Despawned together with the columns on level switch
+        ))
+        .id();
+
+    info!(
+        "Spawned water plane {entity:?} at Y={water_level:.2} ({size_x:.1}x{size_z:.1})",
+        size_x = size.x,
+        size_z = size.y
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::HexOrientation;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::overlays::OverlayHex;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::render::mesh::VertexAttributeValues;
+
+    fn positions_and_normals(mesh: &Mesh) -> (Vec<[f32; 3]>, Vec<[f32; 3]>) {
+        let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+        else {
+            panic!("Mesh should have float3 positions");
+        };
+        let Some(VertexAttributeValues::Float32x3(normals)) =
+            mesh.attribute(Mesh::ATTRIBUTE_NORMAL)
+        else {
+            panic!("Mesh should have float3 normals");
+        };
+        (positions.clone(), normals.clone())
+    }
+
+    #[test]
+    fn test_negative_column_extends_downward_with_outward_normals() {
+        let layout = Level::new("Mesh".to_string(), 1, 1).hex_layout();
+        let mesh = create_hex_column_mesh(&layout, -2.0, &HexMeshOptions::default());
+        let (positions, normals) = positions_and_normals(&mesh);
+
+        for position in &positions {
+            assert!(
+                (-2.0 - 0.001..=0.001).contains(&position[1]),
+                "Vertex {position:?} should lie within -2.0..0.0"
+            );
+        }
+
+        // Side normals must point away from the column axis, not inward
+        for (position, normal) in positions.iter().zip(&normals) {
+            if normal[1].abs() < 0.001 {
+                let outward = position[0] * normal[0] + position[2] * normal[2];
+                assert!(outward > 0.0, "Side normal {normal:?} points inward");
+            } else {
+                assert!(normal[1] > 0.0, "Cap normal {normal:?} should face up");
+            }
+        }
+    }
+
+    #[test]
+    fn test_grid_merges_columns_per_material() {
+        let mut level = Level::new("Merged".to_string(), 3, 3);
+        level.set_height(Hex::new(1, 1), 2.5).unwrap();
+        level.set_walkable(Hex::new(2, 0), false).unwrap();
+        let layout = level.hex_layout();
+
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<HexMaterials>();
+        world.init_resource::<TerrainColoring>();
+        world.init_resource::<HexMeshOptions>();
+        world.init_resource::<HexGridRenderConfig>();
+        world.init_resource::<HexEdgeStyle>();
+        world.insert_resource(LevelsResource::new(vec![level.clone()]));
+        world
+            .run_system_once(spawn_hex_grid)
+            .expect("Grid spawning runs");
+
+        let mut query = world.query::<(&Mesh3d, &HexVertexRanges)>();
+        let merged: Vec<_> = query.iter(&world).collect();
+        assert_eq!(merged.len(), 2, "One entity per material in the only chunk");
+        assert_eq!(
+            merged.iter().map(|(_, ranges)| ranges.len()).sum::<usize>(),
+            9
+        );
+
+        // Each hex's vertices match its standalone column moved into place
+        let meshes = world.resource::<Assets<Mesh>>();
+        for (mesh_handle, ranges) in merged {
+            let (positions, _) = positions_and_normals(meshes.get(&mesh_handle.0).unwrap());
+            for hex in level.hex_iter() {
+                let Some(range) = ranges.get(hex) else {
+                    continue;
+                };
+                let column_base = level.column_base();
+                let column = hex_column_mesh_info(
+                    &layout,
+                    level.get_height(hex) - column_base,
+                    &HexMeshOptions::default(),
+                );
+                let world_pos = layout.hex_to_world_pos(hex);
+                let offset = Vec3::new(world_pos.x, column_base, world_pos.y);
+                assert_eq!(range.len(), column.vertices.len());
+                for (merged_vertex, vertex) in positions[range.start as usize..range.end as usize]
+                    .iter()
+                    .zip(&column.vertices)
+                {
+                    assert!(Vec3::from(*merged_vertex).abs_diff_eq(*vertex + offset, 1e-5));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_spawns_level_outside_levels_resource() {
+        let mut level = Level::new("Preview".to_string(), 3, 3);
+        level.set_height(Hex::new(2, 1), 3.0).unwrap();
+        let layout = level.hex_layout();
+
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<HexMaterials>();
+        let preview = level.clone();
+        app.world_mut()
+            .run_system_once(
+                move |mut commands: Commands,
+                      mut meshes: ResMut<Assets<Mesh>>,
+                      hex_materials: Res<HexMaterials>| {
+                    spawn_hex_grid_internal(
+                        &mut commands,
+                        &mut meshes,
+                        &hex_materials,
+                        &preview,
+                        ColumnStyle::default(),
+                    );
+                },
+            )
+            .expect("Grid spawning runs");
+
+        let world = app.world_mut();
+        let mut query =
+            world.query_filtered::<(&Mesh3d, &HexVertexRanges, &Transform), With<HexGridEntity>>();
+        let merged: Vec<_> = query.iter(world).collect();
+        assert_eq!(
+            merged
+                .iter()
+                .map(|(_, ranges, _)| ranges.len())
+                .sum::<usize>(),
+            9
+        );
+
+        // Every column sits on its hex center and tops out at its height
+        let meshes = world.resource::<Assets<Mesh>>();
+        for (mesh_handle, ranges, transform) in merged {
+            assert_eq!(*transform, Transform::IDENTITY);
+            let (positions, _) = positions_and_normals(meshes.get(&mesh_handle.0).unwrap());
+            for hex in level.hex_iter() {
+                let range = ranges.get(hex).expect("Every hex is in the mesh");
+                let column = &positions[range.start as usize..range.end as usize];
+                let center = column.iter().map(|p| Vec2::new(p[0], p[2])).sum::<Vec2>()
+                    / column.len() as f32;
+                assert!(center.abs_diff_eq(layout.hex_to_world_pos(hex), 1e-4));
+                let top = column.iter().map(|p| p[1]).fold(f32::MIN, f32::max);
+                assert!((top - level.get_height(hex)).abs() < 1e-5);
+            }
+        }
+        assert_eq!(world.resource::<SpawnedGrid>().0.name, "Preview");
+    }
+
+    #[test]
+    fn test_void_hexes_lose_their_columns() {
+        let mut level = Level::new("Void".to_string(), 3, 3);
+        level.set_height(Hex::new(0, 0), -2.0).unwrap();
+        level.set_height(Hex::new(1, 2), -0.5).unwrap();
+
+        for style in [VoidStyle::Hole, VoidStyle::Tile] {
+            let mut world = World::new();
+            world.init_resource::<Assets<Mesh>>();
+            world.init_resource::<Assets<StandardMaterial>>();
+            world.init_resource::<HexMaterials>();
+            world.init_resource::<TerrainColoring>();
+            world.init_resource::<HexMeshOptions>();
+            world.insert_resource(HexEdgeStyle::Outline);
+            world.insert_resource(HexGridRenderConfig {
+                void: Some(VoidRendering {
+                    threshold: 0.0,
+                    style,
+                }),
+                ..default()
+            });
+            world.insert_resource(LevelsResource::new(vec![level.clone()]));
+            world
+                .run_system_once(spawn_hex_grid)
+                .expect("Grid spawning runs");
+
+            let void_material = world.resource::<HexMaterials>().void.id();
+            let mut query = world.query::<(&HexVertexRanges, &MeshMaterial3d<StandardMaterial>)>();
+            let (void_hexes, column_hexes): (Vec<_>, Vec<_>) = query
+                .iter(&world)
+                .partition(|(_, material)| material.id() == void_material);
+            let count = |entities: Vec<(&HexVertexRanges, _)>| {
+                entities
+                    .iter()
+                    .map(|(ranges, _)| ranges.len())
+                    .sum::<usize>()
+            };
+            assert_eq!(count(column_hexes), 7, "{style:?}");
+            let expected_tiles = if style == VoidStyle::Tile { 2 } else { 0 };
+            assert_eq!(count(void_hexes), expected_tiles, "{style:?}");
+
+            // Void hexes get no outline either
+            let mut outlines = world.query_filtered::<&Mesh3d, With<HexEdgeOutline>>();
+            let outline = outlines.single(&world).unwrap().0.clone();
+            let meshes = world.resource::<Assets<Mesh>>();
+            let (positions, _) = positions_and_normals(meshes.get(&outline).unwrap());
+            assert_eq!(positions.len(), 7 * 12);
+        }
+    }
+
+    #[test]
+    fn test_chunk_aabb_covers_columns() {
+        let mut level = Level::new("Bounds".to_string(), 20, 20);
+        level.set_height(Hex::new(3, 5), 4.0).unwrap();
+        level.set_height(Hex::new(18, 18), 9.0).unwrap();
+        level.set_height(Hex::new(17, 2), -3.0).unwrap();
+        let layout = level.hex_layout();
+        let chunks = hex_chunks(level.width, level.height);
+        let first = chunks[0];
+
+        let aabb = chunk_aabb(&level, first, None);
+        let (min, max) = (Vec3::from(aabb.min()), Vec3::from(aabb.max()));
+        // The trench in another chunk lowers the shared column base
+        assert_eq!(min.y, -3.0);
+        assert!((max.y - 4.0 - OUTLINE_LIFT).abs() < 1e-5);
+        for hex in first.hexes() {
+            for corner in layout.hex_corners(hex) {
+                let corner = Vec3::new(corner.x, 0.0, corner.y);
+                assert!(corner.cmpge(min - 1e-4).all() && corner.cmple(max + 1e-4).all());
+            }
+        }
+        // The far chunk holding the 9.0 column reaches higher and further out
+        let far = HexChunk::containing(Hex::new(18, 18), 20, 20).unwrap();
+        let far_aabb = chunk_aabb(&level, far, None);
+        assert!((far_aabb.max().y - 9.0 - OUTLINE_LIFT).abs() < 1e-5);
+        assert!(far_aabb.center.x > aabb.center.x);
+
+        // Void tiles above the tallest column raise the top
+        let void = VoidRendering {
+            threshold: 6.0,
+            style: VoidStyle::Tile,
+        };
+        assert!((chunk_aabb(&level, first, Some(void)).max().y - 6.0 - OUTLINE_LIFT).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_side_uvs_tile_with_column_height() {
+        let mut level = Level::new("Uvs".to_string(), 1, 1);
+        level.hex_scale = 2.0;
+        let layout = level.hex_layout();
+
+        for height in [0.5, 3.0, 8.0] {
+            let mesh_info = hex_column_mesh_info(&layout, height, &HexMeshOptions::default());
+            let max_v = mesh_info
+                .normals
+                .iter()
+                .zip(&mesh_info.uvs)
+                .filter(|(normal, _)| normal.y.abs() < 0.001)
+                .map(|(_, uv)| uv.y)
+                .fold(f32::MIN, f32::max);
+            assert!(
+                (max_v - height / 2.0).abs() < 1e-5,
+                "Height {height}: sides reach v = {max_v}"
+            );
+
+            // Top-face UVs stay within one tile whatever the height
+            for (normal, uv) in mesh_info.normals.iter().zip(&mesh_info.uvs) {
+                if normal.y > 0.5 {
+                    assert!((0.0..=1.0).contains(&uv.x) && (0.0..=1.0).contains(&uv.y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mesh_options_vertex_counts() {
+        let layout = Level::new("Options".to_string(), 1, 1).hex_layout();
+        let vertex_count =
+            |options: HexMeshOptions| hex_column_mesh_info(&layout, 2.0, &options).vertices.len();
+        let default = HexMeshOptions::default();
+        let open = vertex_count(default);
+        // Six side quads of four vertices, plus the top hexagon
+        let sides = 6 * 4;
+        let cap = open - sides;
+        assert_eq!(
+            vertex_count(HexMeshOptions {
+                side_subdivisions: 0,
+                ..default
+            }),
+            open
+        );
+
+        let closed = HexMeshOptions {
+            bottom_face: true,
+            ..default
+        };
+        assert_eq!(vertex_count(closed), open + cap);
+
+        let subdivided = HexMeshOptions {
+            side_subdivisions: 3,
+            ..default
+        };
+        assert_eq!(vertex_count(subdivided), 3 * sides + cap);
+
+        let inset = HexMeshOptions {
+            cap_inset: 0.1,
+            ..default
+        };
+        let inset_cap = vertex_count(inset) - sides;
+        assert!(inset_cap > cap, "Inset adds a bevel ring around the top");
+        assert_eq!(
+            vertex_count(HexMeshOptions {
+                cap_inset: 0.1,
+                ..closed
+            }),
+            sides + 2 * inset_cap,
+            "Both caps are inset when the bottom is closed"
+        );
+    }
+
+    #[test]
+    fn test_smoothed_sides_share_normals_at_edges() {
+        let layout = Level::new("Smooth".to_string(), 1, 1).hex_layout();
+        let side_normals_at = |options: HexMeshOptions| {
+            let geometry = hex_column_mesh_info(&layout, 2.0, &options);
+            let mut by_position: HashMap<IVec3, Vec<Vec3>> = HashMap::new();
+            for (vertex, normal) in geometry.vertices.iter().zip(&geometry.normals) {
+                if normal.y.abs() < 1e-4 {
+                    let key = (*vertex * 1e4).round().as_ivec3();
+                    by_position.entry(key).or_default().push(*normal);
+                }
+            }
+            let cap_normals: Vec<Vec3> = geometry
+                .normals
+                .iter()
+                .copied()
+                .filter(|normal| normal.y.abs() >= 1e-4)
+                .collect();
+            (by_position, cap_normals)
+        };
+
+        let (faceted, _) = side_normals_at(HexMeshOptions::default());
+        assert!(
+            faceted
+                .values()
+                .any(|normals| normals.iter().any(|normal| *normal != normals[0]))
+        );
+
+        let (smoothed, caps) = side_normals_at(HexMeshOptions {
+            smooth_sides: true,
+            side_subdivisions: 2,
+            ..default()
+        });
+        for (position, normals) in &smoothed {
+            assert!(normals.len() >= 2, "Walls meet at {position:?}");
+            assert!(normals.iter().all(|normal| *normal == normals[0]));
+            assert!((normals[0].length() - 1.0).abs() < 1e-5);
+        }
+        assert!(
+            caps.iter().all(|normal| *normal == Vec3::Y),
+            "Caps stay flat"
+        );
+    }
+
+    #[test]
+    fn test_column_indices_widen_past_u16() {
+        let layout = Level::new("Tall".to_string(), 1, 1).hex_layout();
+        // Each subdivision adds a quad of four vertices to all six sides
+        let sides = 6 * 4;
+        let cap = hex_column_mesh_info(&layout, 1.0, &HexMeshOptions::default())
+            .vertices
+            .len()
+            - sides;
+        let fitting = (usize::from(u16::MAX) + 1 - cap) / sides;
+
+        for (subdivisions, wide) in [(fitting, false), (fitting + 1, true)] {
+            let options = HexMeshOptions {
+                side_subdivisions: subdivisions,
+                ..default()
+            };
+            let mesh = create_hex_column_mesh(&layout, 3.0, &options);
+            let vertex_count = mesh.count_vertices();
+            assert_eq!(vertex_count, sides * subdivisions + cap);
+            assert_eq!(
+                matches!(mesh.indices(), Some(Indices::U32(_))),
+                wide,
+                "{vertex_count} vertices"
+            );
+
+            let indices: Vec<usize> = mesh.indices().unwrap().iter().collect();
+            assert_eq!(indices.iter().max(), Some(&(vertex_count - 1)));
+            // Every triangle stays within one side quad or the cap, which a
+            // wrapped index pointing elsewhere in the column would break
+            let (positions, _) = positions_and_normals(&mesh);
+            let quad_height = 3.0 / subdivisions as f32;
+            for triangle in indices.chunks(3) {
+                let heights = [0, 1, 2].map(|i| positions[triangle[i]][1]);
+                let spread = heights.iter().fold(f32::MIN, |a, &b| a.max(b))
+                    - heights.iter().fold(f32::MAX, |a, &b| a.min(b));
+                assert!(spread <= quad_height + 1e-4, "Triangle {triangle:?}");
+            }
+            let top = positions.iter().fold(f32::MIN, |a, p| a.max(p[1]));
+            assert!((top - 3.0).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_subdivided_side_uvs_keep_their_scale() {
+        let layout = Level::new("Uvs".to_string(), 1, 1).hex_layout();
+        let max_side_v = |options: HexMeshOptions| {
+            let mesh_info = hex_column_mesh_info(&layout, 4.0, &options);
+            mesh_info
+                .normals
+                .iter()
+                .zip(&mesh_info.uvs)
+                .filter(|(normal, _)| normal.y.abs() < 0.001)
+                .map(|(_, uv)| uv.y)
+                .fold(f32::MIN, f32::max)
+        };
+        let subdivided = HexMeshOptions {
+            side_subdivisions: 4,
+            ..default()
+        };
+        assert!(
+            (max_side_v(HexMeshOptions::default()) - 4.0 * max_side_v(subdivided)).abs() < 1e-5
+        );
+    }
+
+    #[test]
+    fn test_terrain_categories() {
+        let mut level = Level::new("Lake".to_string(), 3, 3);
+        for (hex, height) in [(Hex::new(0, 0), 0.5), (Hex::new(1, 0), 2.0)] {
+            level.set_height(hex, height).unwrap();
+        }
+        level.set_walkable(Hex::new(2, 0), false).unwrap();
+        assert_eq!(
+            TerrainCategory::of(&level, Hex::new(0, 0)),
+            TerrainCategory::Ground,
+            "No water, nothing is submerged"
+        );
+
+        level.water_level = Some(1.0);
+        assert_eq!(
+            TerrainCategory::of(&level, Hex::new(0, 0)),
+            TerrainCategory::Submerged
+        );
+        assert_eq!(
+            TerrainCategory::of(&level, Hex::new(1, 0)),
+            TerrainCategory::Ground
+        );
+        assert_eq!(
+            TerrainCategory::of(&level, Hex::new(2, 0)),
+            TerrainCategory::Impassable
+        );
+    }
+
+    #[test]
+    fn test_terrain_coloring_toggles_vertex_colors() {
+        for (coloring, colored) in [
+            (TerrainColoring::HeightGradient, true),
+            (TerrainColoring::Flat, false),
+        ] {
+            let mut world = World::new();
+            world.init_resource::<Assets<Mesh>>();
+            world.init_resource::<Assets<StandardMaterial>>();
+            world.init_resource::<HexMaterials>();
+            world.insert_resource(coloring);
+            world.init_resource::<HexMeshOptions>();
+            world.init_resource::<HexGridRenderConfig>();
+            world.init_resource::<HexEdgeStyle>();
+            world.insert_resource(LevelsResource::new(vec![Level::new(
+                "Colors".to_string(),
+                3,
+                3,
+            )]));
+            world
+                .run_system_once(spawn_hex_grid)
+                .expect("Grid spawning runs");
+
+            let meshes = world.resource::<Assets<Mesh>>();
+            let (_, mesh) = meshes.iter().next().expect("Grid mesh exists");
+            assert_eq!(
+                mesh.attribute(Mesh::ATTRIBUTE_COLOR).is_some(),
+                colored,
+                "{coloring:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_equal_overlays_share_mesh_assets() {
+        let mut level = Level::new("Bridges".to_string(), 3, 3);
+        level.overlays = vec![
+            OverlayHex::new(Hex::new(0, 1), 2.0, 0.25),
+            OverlayHex::new(Hex::new(1, 1), 2.5, 0.2501),
+            OverlayHex::new(Hex::new(2, 1), 3.0, 0.5),
+        ];
+
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<HexMaterials>();
+        world.init_resource::<TerrainColoring>();
+        world.init_resource::<HexMeshOptions>();
+        world.init_resource::<HexGridRenderConfig>();
+        world.init_resource::<HexEdgeStyle>();
+        world.insert_resource(LevelsResource::new(vec![level]));
+        world
+            .run_system_once(spawn_hex_grid)
+            .expect("Grid spawning runs");
+
+        // One merged column mesh plus one mesh per distinct slab thickness
+        assert_eq!(world.resource::<Assets<Mesh>>().len(), 3);
+        assert_eq!(mesh_height_key(0.25), mesh_height_key(0.2501));
+        assert_ne!(mesh_height_key(0.25), mesh_height_key(0.251));
+    }
+
+    #[test]
+    fn test_water_plane_ends_at_outer_column_edges() {
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            let mut level = LevelBuilder::new()
+                .dimensions(6, 4)
+                .orientation(orientation)
+                .hex_scale(2.5)
+                .build()
+                .unwrap();
+            level.water_level = Some(0.5);
+
+            let mut world = World::new();
+            world.init_resource::<Assets<Mesh>>();
+            world.init_resource::<Assets<StandardMaterial>>();
+            world.init_resource::<HexMaterials>();
+            world.init_resource::<TerrainColoring>();
+            world.init_resource::<HexMeshOptions>();
+            world.init_resource::<HexGridRenderConfig>();
+            world.insert_resource(HexEdgeStyle::Outline);
+            world.insert_resource(LevelsResource::new(vec![level.clone()]));
+            world
+                .run_system_once(spawn_hex_grid)
+                .expect("Grid spawning runs");
+
+            let water = world.resource::<HexMaterials>().water.clone();
+            let (mesh, transform) = world
+                .query::<(&Mesh3d, &MeshMaterial3d<StandardMaterial>, &Transform)>()
+                .iter(&world)
+                .find(|(_, material, _)| material.0 == water)
+                .map(|(mesh, _, transform)| (mesh.0.clone(), *transform))
+                .expect("Water plane is spawned");
+            let (positions, _) =
+                positions_and_normals(world.resource::<Assets<Mesh>>().get(&mesh).unwrap());
+            let plane = positions
+                .iter()
+                .fold((Vec2::MAX, Vec2::MIN), |(min, max), p| {
+                    let p = Vec2::new(p[0], p[2]) + transform.translation.xz();
+                    (min.min(p), max.max(p))
+                });
+
+            let layout = level.hex_layout();
+            let columns = level
+                .hex_iter()
+                .flat_map(|hex| layout.hex_corners(hex))
+                .fold((Vec2::MAX, Vec2::MIN), |(min, max), corner| {
+                    (min.min(corner), max.max(corner))
+                });
+            assert!(plane.0.abs_diff_eq(columns.0, 1e-4), "{orientation:?}");
+            assert!(plane.1.abs_diff_eq(columns.1, 1e-4), "{orientation:?}");
+        }
+    }
+
+    #[test]
+    fn test_outlines_trace_top_faces() {
+        let mut level = Level::new("Outlines".to_string(), 3, 3);
+        level.set_height(Hex::new(1, 1), 2.5).unwrap();
+        level.overlays = vec![OverlayHex::new(Hex::new(0, 1), 3.0, 0.5)];
+
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<HexMaterials>();
+        world.init_resource::<TerrainColoring>();
+        world.init_resource::<HexMeshOptions>();
+        world.init_resource::<HexGridRenderConfig>();
+        world.insert_resource(HexEdgeStyle::Outline);
+        world.insert_resource(LevelsResource::new(vec![level.clone()]));
+        world
+            .run_system_once(spawn_hex_grid)
+            .expect("Grid spawning runs");
+
+        let mut query = world.query_filtered::<(&Mesh3d, Has<HexChunk>), With<HexEdgeOutline>>();
+        let outlines: Vec<_> = query.iter(&world).collect();
+        assert_eq!(outlines.len(), 2, "One chunk outline and one for overlays");
+        let meshes = world.resource::<Assets<Mesh>>();
+        for (mesh_handle, is_chunk) in outlines {
+            let mesh = meshes.get(&mesh_handle.0).unwrap();
+            assert_eq!(mesh.primitive_topology(), PrimitiveTopology::LineList);
+            let (positions, _) = positions_and_normals(mesh);
+            let expected_hexes = if is_chunk { 9 } else { 1 };
+            assert_eq!(positions.len(), expected_hexes * 12, "Six segments per hex");
+            if !is_chunk {
+                assert!(
+                    positions
+                        .iter()
+                        .all(|p| (p[1] - 3.5 - OUTLINE_LIFT).abs() < 1e-5)
+                );
+            }
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        assert!(
+            world
+                .query_filtered::<(), With<Wireframe>>()
+                .iter(&world)
+                .next()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_ramp_mesh_winding_matches_normals() {
+        let mesh = create_ramp_mesh(Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 3.0, 1.5), 0.8);
+        let (positions, normals) = positions_and_normals(&mesh);
+        let Some(Indices::U16(indices)) = mesh.indices() else {
+            panic!("Ramp mesh should use u16 indices");
+        };
+
+        assert!(normals[0][1] > 0.0, "Top face should face upward");
+        // Counter-clockwise winding must agree with the stored normal so
+        // back-face culling keeps every face visible from outside
+        for triangle in indices.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(positions[triangle[i] as usize]));
+            let winding_normal = (b - a).cross(c - a);
+            let stored = Vec3::from(normals[triangle[0] as usize]);
+            assert!(winding_normal.dot(stored) > 0.0, "Triangle {triangle:?}");
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Level Format Migration
+//!
+//! Upgrades levels parsed from older file formats to [`CURRENT_LEVEL_FORMAT`] one
+//! version at a time, so loaders and the editor only ever handle the newest shape.
+
+use anyhow::{Result, bail};
+use ndarray::Array2;
+use tracing::info;
+
+use super::{CURRENT_LEVEL_FORMAT, Level};
+
+impl Level {
+    /// Upgrade this level in place to [`CURRENT_LEVEL_FORMAT`]
+    ///
+    /// Each known older version is migrated step by step, filling in defaults for
+    /// fields that version lacked. Levels declaring a newer version than this crate
+    /// understands are refused, since their unknown fields would be lost on save.
+    pub fn migrate(&mut self) -> Result<()> {
+        if self.format_version > CURRENT_LEVEL_FORMAT {
+            bail!(
+                "Level '{name}' declares format v{version}, newer than supported v{CURRENT_LEVEL_FORMAT}",
+                name = self.name,
+                version = self.format_version
+            );
+        }
+
+        let original_version = self.format_version;
+        while self.format_version < CURRENT_LEVEL_FORMAT {
+            match self.format_version {
+                0 => self.migrate_v0_to_v1(),
+                version => bail!("No migration defined from level format v{version}"),
+            }
+            self.format_version += 1;
+        }
+
+        if original_version != self.format_version {
+            info!(
+                "Migrated level '{name}' from format v{original_version} to v{version}",
+                name = self.name,
+                version = self.format_version
+            );
+        }
+        Ok(())
+    }
+
+    /// Version 1 added walkability, water level, and author/description metadata
+    fn migrate_v0_to_v1(&mut self) {
+        // Water and metadata default to `None` already; spell out the walkable grid
+        if self.walkable.is_empty() {
+            self.walkable = Array2::from_elem(self.heights.raw_dim(), true);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::load_levels_from_directory;
+    use hexx::Hex;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// A level written before format versioning existed
+    const V0_LEVEL: &str = r#"
+name = "Version Zero"
+width = 3
+height = 2
+
+[heights]
+v = 1
+dim = [2, 3]
+data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]
+"#;
+
+    #[test]
+    fn test_v0_migrates_to_current() {
+        let mut level: Level = toml::from_str(V0_LEVEL).expect("v0 fixture should parse");
+        assert_eq!(level.format_version, 0);
+
+        level.migrate().expect("v0 should migrate");
+
+        assert_eq!(level.format_version, CURRENT_LEVEL_FORMAT);
+        assert_eq!(level.walkable.shape(), level.heights.shape());
+        assert!(level.is_walkable(Hex::new(2, 1)));
+        assert_eq!(level.get_height(Hex::new(2, 1)), 6.0);
+        assert_eq!(level.water_level, None);
+        assert!(level.validate().is_ok());
+    }
+
+    #[test]
+    fn test_loader_migrates_and_save_writes_current_version() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        fs::write(temp_dir.path().join("v0.toml"), V0_LEVEL).unwrap();
+
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let level = loaded.current_level();
+        assert_eq!(level.name, "Version Zero");
+        assert_eq!(level.format_version, CURRENT_LEVEL_FORMAT);
+
+        // Even an unmigrated level is written at the current version
+        let unmigrated: Level = toml::from_str(V0_LEVEL).unwrap();
+        unmigrated
+            .save_to_directory(temp_path, "v0.toml")
+            .expect("Failed to save level");
+        let saved = fs::read_to_string(temp_dir.path().join("v0.toml")).unwrap();
+        assert!(
+            saved.contains(&format!("format_version = {CURRENT_LEVEL_FORMAT}")),
+            "Saved: {saved}"
+        );
+    }
+
+    #[test]
+    fn test_newer_version_is_refused() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        let future = format!(
+            "format_version = {version}\n{V0_LEVEL}",
+            version = CURRENT_LEVEL_FORMAT + 1
+        )
+        .replace("Version Zero", "From The Future");
+        fs::write(temp_dir.path().join("future.toml"), &future).unwrap();
+        fs::write(temp_dir.path().join("v0.toml"), V0_LEVEL).unwrap();
+
+        let mut level: Level = toml::from_str(&future).unwrap();
+        let error = level.migrate().expect_err("Newer format should be refused");
+        assert!(error.to_string().contains("newer than supported"));
+
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(loaded.level_count(), 1);
+        assert_eq!(loaded.current_level().name, "Version Zero");
+    }
+}
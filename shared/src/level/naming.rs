@@ -0,0 +1,192 @@
+//! Level Naming
+//!
+//! Keeps loaded level names unique so switching and saving by name stay
+//! unambiguous. Duplicates are renamed after their file, while the name written
+//! in the file is kept for saving.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use tracing::warn;
+
+use super::Level;
+
+/// A rename that kept a loaded level's name unique
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoadRename {
+    /// Name written in the level file
+    pub original_name: String,
+    /// Name the level was given instead
+    pub unique_name: String,
+}
+
+impl Level {
+    /// Name to write when saving: the name from the level file if loading
+    /// renamed the level to keep names unique, unless it was renamed again since
+    pub fn saved_name(&self) -> &str {
+        match &self.load_rename {
+            Some(rename) if rename.unique_name == self.name => &rename.original_name,
+            _ => &self.name,
+        }
+    }
+
+    /// Rename this level to `unique_name`, remembering the name to save
+    pub fn rename_unique(&mut self, unique_name: String) {
+        let original_name = self.saved_name().to_string();
+        self.name = unique_name.clone();
+        self.load_rename = Some(LoadRename {
+            original_name,
+            unique_name,
+        });
+    }
+}
+
+/// Rename levels sharing a name so every level name is unique
+///
+/// Within each group of duplicates the level from the first file by path keeps
+/// its name, and the others get their file stem appended, as in
+/// "Skirmish (skirmish_v2)". Renamed levels remember their file's name in
+/// [`Level::load_rename`].
+pub fn make_names_unique(levels: &mut [Level]) {
+    let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+    for (index, level) in levels.iter().enumerate() {
+        groups.entry(level.name.clone()).or_default().push(index);
+    }
+    let mut taken: HashSet<String> = groups.keys().cloned().collect();
+
+    for (name, mut indices) in groups {
+        if indices.len() < 2 {
+            continue;
+        }
+        indices.sort_by(|&a, &b| levels[a].source_path.cmp(&levels[b].source_path));
+
+        for &index in &indices[1..] {
+            let level = &mut levels[index];
+            let unique = unique_name(&name, level.source_path.as_deref(), &taken);
+            warn!(
+                "Renamed duplicate level '{name}' from {path} to '{unique}'",
+                path = level
+                    .source_path
+                    .as_deref()
+                    .map_or("an unknown file".into(), |path| path.display().to_string())
+            );
+            taken.insert(unique.clone());
+            level.rename_unique(unique);
+        }
+    }
+}
+
+/// First free candidate among the file stem, the relative path without
+/// extensions, and numbered suffixes
+pub(super) fn unique_name(
+    name: &str,
+    source_path: Option<&Path>,
+    taken: &HashSet<String>,
+) -> String {
+    let mut candidates = Vec::new();
+    if let Some(path) = source_path {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let stem = file_name.split('.').next().unwrap_or_default();
+        candidates.push(stem.to_string());
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            candidates.push(parent.join(stem).to_string_lossy().replace('\\', "/"));
+        }
+    }
+
+    candidates
+        .into_iter()
+        .map(|label| format!("{name} ({label})"))
+        .chain((2..).map(|number| format!("{name} ({number})")))
+        .find(|candidate| !taken.contains(candidate))
+        .expect("Numbered candidates are unbounded")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::load_levels_from_directory;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn save(temp_path: &str, name: &str, file: &str) {
+        Level::new(name.to_string(), 3, 3)
+            .save_to_directory(temp_path, file)
+            .expect("Failed to save level");
+    }
+
+    #[test]
+    fn test_duplicate_names_are_disambiguated_on_load() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        save(temp_path, "Skirmish", "skirmish_v2.toml");
+        save(temp_path, "Skirmish", "skirmish.toml");
+        save(temp_path, "Arena", "arena.toml");
+
+        let mut levels = load_levels_from_directory(temp_path).expect("Failed to load levels");
+
+        let names: Vec<_> = levels
+            .levels
+            .iter()
+            .map(|level| level.name.as_str())
+            .collect();
+        assert_eq!(names, ["Arena", "Skirmish", "Skirmish (skirmish_v2)"]);
+        levels
+            .switch_to("Skirmish (skirmish_v2)")
+            .expect("Renamed level is switchable");
+        assert_eq!(
+            levels.current_level().source_path.as_deref(),
+            Some(Path::new("skirmish_v2.toml"))
+        );
+        assert_eq!(levels.current_level().saved_name(), "Skirmish");
+
+        // Saving writes the author's name back to the file
+        levels
+            .current_level()
+            .save_to_directory(temp_path, "skirmish_v2.toml")
+            .expect("Failed to save level");
+        let saved = fs::read_to_string(temp_dir.path().join("skirmish_v2.toml")).unwrap();
+        assert!(saved.contains("name = \"Skirmish\""), "Saved: {saved}");
+    }
+
+    #[test]
+    fn test_renaming_a_disambiguated_level_saves_the_new_name() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        save(temp_path, "Skirmish", "skirmish_v2.toml");
+        save(temp_path, "Skirmish", "skirmish.toml");
+
+        let mut levels = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let index = levels
+            .index_of("Skirmish (skirmish_v2)")
+            .expect("Duplicate was renamed on load");
+        levels.levels[index].name = "Ambush".to_string();
+        assert_eq!(levels.levels[index].saved_name(), "Ambush");
+
+        levels.levels[index]
+            .save_to_directory(temp_path, "skirmish_v2.toml")
+            .expect("Failed to save level");
+        let saved = fs::read_to_string(temp_dir.path().join("skirmish_v2.toml")).unwrap();
+        assert!(saved.contains("name = \"Ambush\""), "Saved: {saved}");
+    }
+
+    #[test]
+    fn test_same_stem_in_different_directories() {
+        let mut levels = vec![
+            Level::new("Duel".to_string(), 3, 3),
+            Level::new("Duel".to_string(), 3, 3),
+            Level::new("Duel".to_string(), 3, 3),
+        ];
+        levels[0].source_path = Some("duel.toml".into());
+        levels[1].source_path = Some("pvp/duel.toml".into());
+        levels[2].source_path = Some("campaign/duel.ron".into());
+
+        make_names_unique(&mut levels);
+
+        let names: Vec<_> = levels.iter().map(|level| level.name.as_str()).collect();
+        assert_eq!(names, ["Duel (duel)", "Duel (pvp/duel)", "Duel"]);
+        assert!(levels.iter().all(|level| level.saved_name() == "Duel"));
+    }
+}
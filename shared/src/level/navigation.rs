@@ -0,0 +1,201 @@
+//! Navigation Graph
+//!
+//! A precomputed adjacency structure over a level's hexes, so pathfinding,
+//! reachability, and AI queries can share one set of traversable edges instead
+//! of re-deriving step rules on every lookup. Bevy-independent for testability.
+
+use hexx::Hex;
+
+use super::Level;
+use super::pathfinding::{PathOptions, can_step};
+
+/// Movement cost of a single step between adjacent hexes
+const STEP_COST: u32 = 1;
+
+/// A traversable edge from one hex into an adjacent hex
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NavEdge {
+    /// Hex the edge leads into
+    pub to: Hex,
+    /// Movement points spent crossing the edge
+    pub cost: u32,
+}
+
+/// Traversable neighbors of every hex in a level, built by [`Level::to_nav_graph`]
+///
+/// Edges follow the same rules as [`super::pathfinding::find_path`]: the target
+/// must be walkable, and the height difference must be at most
+/// `max_step_height` unless a ramp bridges the step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NavGraph {
+    width: i32,
+    height: i32,
+    max_step_height: f32,
+    /// Outgoing edges per hex, indexed by `r * width + q`
+    edges: Vec<Vec<NavEdge>>,
+}
+
+impl NavGraph {
+    /// Get the traversable neighbors of `hex`
+    ///
+    /// Returns a borrowed slice, so queries never allocate. Out-of-bounds hexes
+    /// have no neighbors.
+    pub fn neighbors(&self, hex: Hex) -> &[NavEdge] {
+        self.index(hex).map_or(&[], |index| &self.edges[index])
+    }
+
+    /// Step height limit the graph was built with
+    pub fn max_step_height(&self) -> f32 {
+        self.max_step_height
+    }
+
+    /// Recompute the edges touching `hex` after its height or walkability changed
+    ///
+    /// Only `hex` and its adjacent hexes are refreshed, which is far cheaper
+    /// than rebuilding the graph. The level must still have the dimensions the
+    /// graph was built with; out-of-bounds hexes are ignored.
+    pub fn update_hex(&mut self, level: &Level, hex: Hex) {
+        debug_assert_eq!(
+            (self.width, self.height),
+            (level.width, level.height),
+            "NavGraph updated against a level of different dimensions"
+        );
+        if !level.contains(hex) {
+            return;
+        }
+
+        self.rebuild_edges(level, hex);
+        for neighbor in hex.all_neighbors() {
+            if level.contains(neighbor) {
+                self.rebuild_edges(level, neighbor);
+            }
+        }
+    }
+
+    fn index(&self, hex: Hex) -> Option<usize> {
+        let in_bounds = hex.x >= 0 && hex.x < self.width && hex.y >= 0 && hex.y < self.height;
+        in_bounds.then(|| (hex.y * self.width + hex.x) as usize)
+    }
+
+    /// Refill the outgoing edge list of an in-bounds `hex` in place
+    fn rebuild_edges(&mut self, level: &Level, hex: Hex) {
+        let options = PathOptions {
+            max_step_height: self.max_step_height,
+            respect_walkability: true,
+        };
+        let Some(index) = self.index(hex) else {
+            return;
+        };
+        let edges = &mut self.edges[index];
+        edges.clear();
+        edges.extend(
+            hex.all_neighbors()
+                .into_iter()
+                .filter(|&neighbor| level.contains(neighbor))
+                .filter(|&neighbor| can_step(level, hex, neighbor, &options))
+                .map(|to| NavEdge {
+                    to,
+                    cost: STEP_COST,
+                }),
+        );
+    }
+}
+
+impl Level {
+    /// Build the navigation graph for units that can climb `max_step_height`
+    pub fn to_nav_graph(&self, max_step_height: f32) -> NavGraph {
+        let cell_count = (self.width.max(0) * self.height.max(0)) as usize;
+        let mut graph = NavGraph {
+            width: self.width,
+            height: self.height,
+            max_step_height,
+            edges: (0..cell_count).map(|_| Vec::with_capacity(6)).collect(),
+        };
+        for hex in self.hex_iter() {
+            graph.rebuild_edges(self, hex);
+        }
+        graph
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::pathfinding::reachable_hexes;
+    use std::collections::{HashMap, VecDeque};
+
+    fn large_level() -> Level {
+        LevelBuilder::new()
+            .dimensions(50, 50)
+            .gradient(0.0, 10.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_neighbors_follow_step_rules() {
+        let level = LevelBuilder::new()
+            .dimensions(5, 5)
+            .flat(1.0)
+            .with_height(Hex::new(2, 1), 4.0)
+            .build()
+            .unwrap();
+        let graph = level.to_nav_graph(1.0);
+
+        let center: Vec<Hex> = graph
+            .neighbors(Hex::new(2, 2))
+            .iter()
+            .map(|edge| edge.to)
+            .collect();
+        assert_eq!(center.len(), 5);
+        assert!(!center.contains(&Hex::new(2, 1)));
+        assert!(graph.neighbors(Hex::new(2, 1)).is_empty());
+        assert!(graph.neighbors(Hex::new(-1, 0)).is_empty());
+        assert!(
+            graph
+                .neighbors(Hex::new(0, 0))
+                .iter()
+                .all(|edge| edge.cost == 1)
+        );
+    }
+
+    #[test]
+    fn test_incremental_update_matches_rebuild() {
+        let mut level = large_level();
+        let mut graph = level.to_nav_graph(0.5);
+        let queried = graph.neighbors(Hex::new(25, 25)).as_ptr();
+
+        for (hex, height) in [(Hex::new(25, 25), 30.0), (Hex::new(0, 0), 5.0)] {
+            level.set_height(hex, height).unwrap();
+            graph.update_hex(&level, hex);
+        }
+        level.set_walkable(Hex::new(49, 49), false).unwrap();
+        graph.update_hex(&level, Hex::new(49, 49));
+
+        assert_eq!(graph, level.to_nav_graph(0.5));
+        // Updates refill edge lists in place rather than reallocating them
+        assert_eq!(graph.neighbors(Hex::new(25, 25)).as_ptr(), queried);
+    }
+
+    #[test]
+    fn test_graph_flood_matches_reachable_hexes() {
+        let level = large_level();
+        let graph = level.to_nav_graph(0.5);
+        let origin = Hex::new(10, 10);
+
+        let mut reached = HashMap::from([(origin, 0)]);
+        let mut queue = VecDeque::from([origin]);
+        while let Some(current) = queue.pop_front() {
+            let cost = reached[&current];
+            for edge in graph.neighbors(current) {
+                if cost + edge.cost <= 6 && !reached.contains_key(&edge.to) {
+                    reached.insert(edge.to, cost + edge.cost);
+                    queue.push_back(edge.to);
+                }
+            }
+        }
+
+        assert_eq!(reached, reachable_hexes(&level, origin, 6, 0.5));
+    }
+}
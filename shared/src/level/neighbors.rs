@@ -0,0 +1,148 @@
+//! Hex Neighbor Queries
+//!
+//! Adjacency, distance, and range lookups restricted to the level grid, used by
+//! pathfinding, slope rules, and editor tools.
+
+use hexx::Hex;
+
+use super::Level;
+
+impl Level {
+    /// Get the in-bounds neighbors of `hex`
+    ///
+    /// Edge and corner hexes return fewer than six neighbors. Hexes outside the
+    /// grid still report whichever of their neighbors lie inside it.
+    pub fn neighbors(&self, hex: Hex) -> Vec<Hex> {
+        hex.all_neighbors()
+            .into_iter()
+            .filter(|neighbor| self.contains(*neighbor))
+            .collect()
+    }
+
+    /// Get the in-bounds neighbors of `hex` paired with their heights
+    pub fn neighbors_with_heights(&self, hex: Hex) -> Vec<(Hex, f32)> {
+        hex.all_neighbors()
+            .into_iter()
+            .filter_map(|neighbor| {
+                self.try_get_height(neighbor)
+                    .map(|height| (neighbor, height))
+            })
+            .collect()
+    }
+
+    /// Get the hex distance between `a` and `b`
+    ///
+    /// Returns `None` if either hex lies outside the grid.
+    pub fn distance(&self, a: Hex, b: Hex) -> Option<u32> {
+        (self.contains(a) && self.contains(b)).then(|| a.unsigned_distance_to(b))
+    }
+
+    /// Get the in-bounds hexes within `radius` steps of `center`, including `center`
+    ///
+    /// Like [`Level::neighbors`], a center outside the grid still reports whichever
+    /// hexes of its range lie inside it.
+    pub fn hexes_in_range(&self, center: Hex, radius: u32) -> Vec<Hex> {
+        center
+            .range(radius)
+            .filter(|hex| self.contains(*hex))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+
+    fn level_5x5() -> Level {
+        LevelBuilder::new()
+            .dimensions(5, 5)
+            .flat(1.0)
+            .with_height(Hex::new(3, 2), 4.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_corner_hex_has_fewer_neighbors() {
+        let level = level_5x5();
+
+        let mut origin = level.neighbors(Hex::new(0, 0));
+        origin.sort_by_key(|h| (h.x, h.y));
+        assert_eq!(origin, vec![Hex::new(0, 1), Hex::new(1, 0)]);
+
+        // The opposite acute corner of the rhombus also touches only two hexes
+        assert_eq!(level.neighbors(Hex::new(4, 4)).len(), 2);
+        // The obtuse corners touch three
+        assert_eq!(level.neighbors(Hex::new(4, 0)).len(), 3);
+        assert_eq!(level.neighbors(Hex::new(0, 4)).len(), 3);
+    }
+
+    #[test]
+    fn test_center_hex_has_six_neighbors() {
+        let level = level_5x5();
+        let center = Hex::new(2, 2);
+        let neighbors = level.neighbors(center);
+
+        assert_eq!(neighbors.len(), 6);
+        assert!(neighbors.iter().all(|n| n.distance_to(center) == 1));
+    }
+
+    #[test]
+    fn test_neighbors_with_heights() {
+        let level = level_5x5();
+        let pairs = level.neighbors_with_heights(Hex::new(2, 2));
+
+        assert_eq!(pairs.len(), 6);
+        assert!(pairs.contains(&(Hex::new(3, 2), 4.0)));
+        assert_eq!(pairs.iter().filter(|(_, h)| *h == 1.0).count(), 5);
+
+        assert!(level.neighbors_with_heights(Hex::new(-5, -5)).is_empty());
+    }
+
+    #[test]
+    fn test_distance_requires_both_hexes_in_bounds() {
+        let level = level_5x5();
+
+        assert_eq!(level.distance(Hex::new(0, 0), Hex::new(0, 0)), Some(0));
+        assert_eq!(level.distance(Hex::new(0, 0), Hex::new(4, 0)), Some(4));
+        // Axial distance: (0, 4) -> (4, 0) is 4 steps, (0, 0) -> (4, 4) is 8
+        assert_eq!(level.distance(Hex::new(0, 4), Hex::new(4, 0)), Some(4));
+        assert_eq!(level.distance(Hex::new(0, 0), Hex::new(4, 4)), Some(8));
+        assert_eq!(level.distance(Hex::new(0, 0), Hex::new(5, 0)), None);
+        assert_eq!(level.distance(Hex::new(-1, 0), Hex::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_hexes_in_range_clips_to_grid() {
+        let level = level_5x5();
+
+        assert_eq!(
+            level.hexes_in_range(Hex::new(2, 2), 0),
+            vec![Hex::new(2, 2)]
+        );
+        assert_eq!(level.hexes_in_range(Hex::new(2, 2), 1).len(), 7);
+
+        // Corner range keeps only the in-bounds part of the 19-hex disc
+        let mut corner = level.hexes_in_range(Hex::new(0, 0), 2);
+        corner.sort_by_key(|h| (h.x, h.y));
+        assert_eq!(
+            corner,
+            vec![
+                Hex::new(0, 0),
+                Hex::new(0, 1),
+                Hex::new(0, 2),
+                Hex::new(1, 0),
+                Hex::new(1, 1),
+                Hex::new(2, 0),
+            ]
+        );
+
+        // Every result is in bounds and within the radius
+        for hex in level.hexes_in_range(Hex::new(4, 1), 3) {
+            assert!(level.contains(hex));
+            assert!(level.distance(Hex::new(4, 1), hex).unwrap() <= 3);
+        }
+        assert!(level.hexes_in_range(Hex::new(-5, -5), 1).is_empty());
+    }
+}
@@ -0,0 +1,94 @@
+//! Overlay Hexes
+//!
+//! Floating hex slabs above the base terrain, such as bridges crossing a ravine,
+//! which a single height per hex cannot represent.
+
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// A floating hex slab spanning `floor..floor + thickness` above `hex`
+///
+/// Overlays are rendered and camera-pickable; pathfinding and height queries
+/// still only see the base terrain.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OverlayHex {
+    /// Axial hex coordinate as (q, r)
+    pub hex: (i32, i32),
+    /// Height of the slab's underside
+    pub floor: f32,
+    /// Vertical size of the slab; must be positive
+    pub thickness: f32,
+}
+
+impl OverlayHex {
+    /// Create an overlay at `hex` spanning `floor..floor + thickness`
+    pub fn new(hex: Hex, floor: f32, thickness: f32) -> Self {
+        Self {
+            hex: (hex.x, hex.y),
+            floor,
+            thickness,
+        }
+    }
+
+    /// Get the overlay coordinate as a [`Hex`]
+    pub fn coord(&self) -> Hex {
+        Hex::new(self.hex.0, self.hex.1)
+    }
+
+    /// Height of the slab's walkable top surface
+    pub fn top(&self) -> f32 {
+        self.floor + self.thickness
+    }
+}
+
+impl Level {
+    /// Get the overlays stacked above `hex`, in file order
+    pub fn overlays_at(&self, hex: Hex) -> Vec<&OverlayHex> {
+        self.overlays
+            .iter()
+            .filter(|overlay| overlay.coord() == hex)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_overlays_roundtrip_and_invalid_dropped() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let bridge = vec![
+            OverlayHex::new(Hex::new(1, 2), 3.0, 0.25),
+            OverlayHex::new(Hex::new(2, 2), 3.0, 0.25),
+        ];
+        let mut level = LevelBuilder::new()
+            .name("Ravine")
+            .dimensions(4, 4)
+            .build()
+            .unwrap();
+        level.overlays = bridge.clone();
+        level
+            .overlays
+            .push(OverlayHex::new(Hex::new(9, 0), 3.0, 0.25));
+        level
+            .overlays
+            .push(OverlayHex::new(Hex::new(0, 0), 3.0, 0.0));
+        level
+            .save_to_directory(temp_path, "ravine.toml")
+            .expect("Failed to save level");
+
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let loaded = loaded.current_level();
+        assert_eq!(loaded.overlays, bridge);
+        assert_eq!(loaded.overlays_at(Hex::new(2, 2))[0].top(), 3.25);
+        assert!(loaded.overlays_at(Hex::new(0, 0)).is_empty());
+    }
+}
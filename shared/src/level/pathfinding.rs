@@ -0,0 +1,360 @@
+//! Hex Grid Pathfinding
+//!
+//! Pure, Bevy-independent path queries over a level's hex grid. Every step
+//! between adjacent hexes costs one movement point; steps that are too tall
+//! (unless bridged by a ramp) or that enter impassable hexes are blocked.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use hexx::Hex;
+
+use super::Level;
+
+/// Movement rules applied by [`find_path`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathOptions {
+    /// Largest absolute height difference a single step may cross, up or down
+    pub max_step_height: f32,
+    /// Whether hexes marked impassable in the level block movement
+    pub respect_walkability: bool,
+}
+
+impl Default for PathOptions {
+    fn default() -> Self {
+        Self {
+            max_step_height: f32::INFINITY,
+            respect_walkability: true,
+        }
+    }
+}
+
+/// Check whether a unit may step from `from` into the adjacent hex `to`
+///
+/// Ramps lift the step height limit but not walkability.
+pub(super) fn can_step(level: &Level, from: Hex, to: Hex, options: &PathOptions) -> bool {
+    if options.respect_walkability && !level.is_walkable(to) {
+        return false;
+    }
+    level
+        .height_difference(from, to)
+        .is_some_and(|diff| diff.abs() <= options.max_step_height || level.has_ramp(from, to))
+}
+
+/// Find the shortest path from `start` to `goal` using A*
+///
+/// The returned path includes both endpoints. Returns `None` when either endpoint
+/// lies outside the grid, the goal is impassable, or no route satisfies the
+/// movement rules. Ties are broken by coordinate so results are deterministic.
+pub fn find_path(level: &Level, start: Hex, goal: Hex, options: &PathOptions) -> Option<Vec<Hex>> {
+    if !level.contains(start) || !level.contains(goal) {
+        return None;
+    }
+    if options.respect_walkability && !level.is_walkable(goal) {
+        return None;
+    }
+
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<Hex, Hex> = HashMap::new();
+    let mut best_cost: HashMap<Hex, u32> = HashMap::new();
+
+    best_cost.insert(start, 0);
+    open.push(Reverse((
+        start.unsigned_distance_to(goal),
+        0,
+        start.x,
+        start.y,
+    )));
+
+    while let Some(Reverse((_, cost, q, r))) = open.pop() {
+        let current = Hex::new(q, r);
+        if current == goal {
+            let mut path = vec![goal];
+            let mut step = goal;
+            while let Some(&previous) = came_from.get(&step) {
+                path.push(previous);
+                step = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        // Skip stale queue entries superseded by a cheaper route
+        if best_cost.get(&current).is_some_and(|&best| cost > best) {
+            continue;
+        }
+
+        for neighbor in level.neighbors(current) {
+            if !can_step(level, current, neighbor, options) {
+                continue;
+            }
+            let next_cost = cost + 1;
+            if best_cost
+                .get(&neighbor)
+                .is_none_or(|&known| next_cost < known)
+            {
+                best_cost.insert(neighbor, next_cost);
+                came_from.insert(neighbor, current);
+                let estimate = next_cost + neighbor.unsigned_distance_to(goal);
+                open.push(Reverse((estimate, next_cost, neighbor.x, neighbor.y)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Compute every hex reachable from `origin` within `movement_points`
+///
+/// Performs a Dijkstra flood fill and maps each reachable hex to the cost of
+/// reaching it, with the origin itself at cost 0. Steps whose absolute height
+/// difference exceeds `max_step_height` and impassable hexes are blocked. An
+/// out-of-bounds origin yields an empty map.
+pub fn reachable_hexes(
+    level: &Level,
+    origin: Hex,
+    movement_points: u32,
+    max_step_height: f32,
+) -> HashMap<Hex, u32> {
+    let mut reached = HashMap::new();
+    if !level.contains(origin) {
+        return reached;
+    }
+
+    let options = PathOptions {
+        max_step_height,
+        respect_walkability: true,
+    };
+    let mut open = BinaryHeap::new();
+    reached.insert(origin, 0);
+    open.push(Reverse((0, origin.x, origin.y)));
+
+    while let Some(Reverse((cost, q, r))) = open.pop() {
+        let current = Hex::new(q, r);
+        if reached.get(&current).is_some_and(|&best| cost > best) {
+            continue;
+        }
+
+        let next_cost = cost + 1;
+        if next_cost > movement_points {
+            continue;
+        }
+        for neighbor in level.neighbors(current) {
+            if !can_step(level, current, neighbor, &options) {
+                continue;
+            }
+            if reached
+                .get(&neighbor)
+                .is_none_or(|&known| next_cost < known)
+            {
+                reached.insert(neighbor, next_cost);
+                open.push(Reverse((next_cost, neighbor.x, neighbor.y)));
+            }
+        }
+    }
+
+    reached
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+
+    /// A 7x5 flat level with a ridge of height 5 along column q = 3, open at r = 4
+    fn ridge_level() -> Level {
+        let mut builder = LevelBuilder::new().dimensions(7, 5).flat(1.0);
+        for r in 0..4 {
+            builder = builder.with_height(Hex::new(3, r), 5.0);
+        }
+        builder.build().unwrap()
+    }
+
+    fn climbing(max_step_height: f32) -> PathOptions {
+        PathOptions {
+            max_step_height,
+            ..Default::default()
+        }
+    }
+
+    fn assert_contiguous(path: &[Hex]) {
+        for pair in path.windows(2) {
+            assert_eq!(pair[0].unsigned_distance_to(pair[1]), 1, "Path {path:?}");
+        }
+    }
+
+    #[test]
+    fn test_straight_path() {
+        let level = LevelBuilder::new()
+            .dimensions(6, 6)
+            .flat(1.0)
+            .build()
+            .unwrap();
+        let path = find_path(
+            &level,
+            Hex::new(0, 2),
+            Hex::new(5, 2),
+            &PathOptions::default(),
+        )
+        .expect("Flat level should have a path");
+
+        assert_eq!(path.len(), 6);
+        assert_eq!(path.first(), Some(&Hex::new(0, 2)));
+        assert_eq!(path.last(), Some(&Hex::new(5, 2)));
+        assert!(
+            path.iter().all(|hex| hex.y == 2),
+            "Path {path:?} should be straight"
+        );
+    }
+
+    #[test]
+    fn test_start_equals_goal() {
+        let level = LevelBuilder::new().dimensions(3, 3).build().unwrap();
+        let hex = Hex::new(1, 1);
+        assert_eq!(
+            find_path(&level, hex, hex, &PathOptions::default()),
+            Some(vec![hex])
+        );
+    }
+
+    #[test]
+    fn test_path_around_ridge() {
+        let level = ridge_level();
+        let start = Hex::new(0, 0);
+        let goal = Hex::new(6, 0);
+
+        // Unlimited climbing walks straight over the ridge
+        let direct = find_path(&level, start, goal, &PathOptions::default()).unwrap();
+        assert_eq!(direct.len(), 7);
+
+        let around = find_path(&level, start, goal, &climbing(1.0)).unwrap();
+        assert_contiguous(&around);
+        assert!(
+            around.contains(&Hex::new(3, 4)),
+            "Must use the gap: {around:?}"
+        );
+        assert!(around.len() > direct.len());
+        assert!(
+            around.iter().all(|&hex| level.get_height(hex) <= 1.0),
+            "Path must not climb the ridge"
+        );
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        let mut level = ridge_level();
+        level.set_height(Hex::new(3, 4), 5.0).unwrap();
+
+        assert_eq!(
+            find_path(&level, Hex::new(0, 0), Hex::new(6, 0), &climbing(1.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_walkability_blocks_path() {
+        let mut level = ridge_level();
+        level.set_walkable(Hex::new(3, 4), false).unwrap();
+
+        assert_eq!(
+            find_path(&level, Hex::new(0, 0), Hex::new(6, 0), &climbing(1.0)),
+            None
+        );
+
+        let ignoring = PathOptions {
+            max_step_height: 1.0,
+            respect_walkability: false,
+        };
+        assert!(find_path(&level, Hex::new(0, 0), Hex::new(6, 0), &ignoring).is_some());
+
+        // Impassable goals are never reachable
+        assert_eq!(
+            find_path(
+                &level,
+                Hex::new(0, 4),
+                Hex::new(3, 4),
+                &PathOptions::default()
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_out_of_bounds_endpoints() {
+        let level = ridge_level();
+        let options = PathOptions::default();
+
+        assert_eq!(
+            find_path(&level, Hex::new(-1, 0), Hex::new(2, 2), &options),
+            None
+        );
+        assert_eq!(
+            find_path(&level, Hex::new(0, 0), Hex::new(7, 0), &options),
+            None
+        );
+    }
+
+    /// A 5x5 flat level split by a wall of height 4 along column q = 2
+    fn walled_level() -> Level {
+        let mut builder = LevelBuilder::new().dimensions(5, 5).flat(1.0);
+        for r in 0..5 {
+            builder = builder.with_height(Hex::new(2, r), 4.0);
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_reachable_hexes_stop_at_wall() {
+        let level = walled_level();
+        let origin = Hex::new(0, 2);
+        let reached = reachable_hexes(&level, origin, 10, 1.0);
+
+        // Exactly the ten hexes west of the wall, never the wall or beyond
+        assert_eq!(reached.len(), 10);
+        assert!(reached.keys().all(|hex| hex.x < 2), "Reached {reached:?}");
+        assert_eq!(reached[&origin], 0);
+        assert_eq!(reached[&Hex::new(1, 2)], 1);
+        assert_eq!(reached[&Hex::new(0, 0)], 2);
+        assert_eq!(reached[&Hex::new(1, 4)], 3);
+
+        // With enough climbing the wall is crossable and costs like any other step
+        let climbing = reachable_hexes(&level, origin, 10, 3.0);
+        assert_eq!(climbing.len(), 25);
+        assert_eq!(climbing[&Hex::new(2, 2)], 2);
+        assert_eq!(climbing[&Hex::new(4, 2)], 4);
+    }
+
+    #[test]
+    fn test_reachable_hexes_respect_movement_points() {
+        let level = walled_level();
+        let origin = Hex::new(0, 2);
+
+        let one_step = reachable_hexes(&level, origin, 1, 1.0);
+        let mut hexes: Vec<_> = one_step.keys().copied().collect();
+        hexes.sort_by_key(|h| (h.x, h.y));
+        assert_eq!(
+            hexes,
+            vec![
+                Hex::new(0, 1),
+                Hex::new(0, 2),
+                Hex::new(0, 3),
+                Hex::new(1, 1),
+                Hex::new(1, 2)
+            ]
+        );
+
+        assert_eq!(reachable_hexes(&level, origin, 0, 1.0).len(), 1);
+        assert!(reachable_hexes(&level, Hex::new(9, 9), 5, 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_reachable_hexes_skip_impassable() {
+        let mut level = walled_level();
+        level.set_walkable(Hex::new(0, 1), false).unwrap();
+        level.set_walkable(Hex::new(1, 1), false).unwrap();
+
+        let reached = reachable_hexes(&level, Hex::new(0, 2), 1, 1.0);
+        assert_eq!(reached.len(), 3);
+        assert!(!reached.contains_key(&Hex::new(0, 1)));
+    }
+}
@@ -0,0 +1,311 @@
+//! Level State Persistence
+//!
+//! Web builds keep the selected level and unsaved level edits across page
+//! reloads by storing them as JSON in the browser's `localStorage`. The stored
+//! state is read when `LevelPlugin` builds, kept current in the
+//! [`PersistedLevelState`] resource as levels change, and applied each time
+//! loaded levels are swapped in. Missing, malformed, or unwritable storage falls
+//! back to the defaults with a warning.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use super::format::LevelFormat;
+use super::{LevelsResource, parse_level_file};
+
+/// `localStorage` key holding the persisted level state
+pub const PERSISTED_STATE_KEY: &str = "system-tactics.level-state";
+
+/// Key clearing the persisted level state, for testers
+pub const CLEAR_PERSISTED_STATE_KEY: KeyCode = KeyCode::F9;
+
+/// An unsaved level edit, stored as level TOML
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PersistedLevel {
+    /// File the edited level was loaded from, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_path: Option<PathBuf>,
+    /// Level name, used to match levels without a file
+    pub name: String,
+    pub toml: String,
+}
+
+/// Selected level and unsaved level edits kept across page reloads
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PersistedLevelState {
+    /// Name of the level that was current
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_level: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub edited_levels: Vec<PersistedLevel>,
+}
+
+impl PersistedLevelState {
+    /// Record the current level and every modified level of `levels`
+    pub fn capture(levels: &LevelsResource) -> Result<Self> {
+        let mut edited_levels = Vec::new();
+        for (index, level) in levels.levels.iter().enumerate() {
+            if !levels.is_modified(index) {
+                continue;
+            }
+            edited_levels.push(PersistedLevel {
+                source_path: level.source_path.clone(),
+                name: level.name.clone(),
+                toml: LevelFormat::Toml.serialize(level)?,
+            });
+        }
+        Ok(Self {
+            current_level: Some(levels.current_level().name.clone()),
+            edited_levels,
+        })
+    }
+
+    /// Parse state written by [`PersistedLevelState::to_json`]
+    pub fn from_json(content: &str) -> Result<Self> {
+        serde_json::from_str(content).context("Failed to parse persisted level state")
+    }
+
+    /// Serialize the state as compact JSON for storage
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).context("Failed to serialize persisted level state")
+    }
+
+    /// Restore the edits and current level into freshly loaded `levels`
+    ///
+    /// Edits replace the level loaded from the same file, or with the same name
+    /// for levels without a file, and are appended otherwise. Edits that no
+    /// longer parse or validate are skipped.
+    pub fn apply(&self, levels: &mut LevelsResource) {
+        for edit in &self.edited_levels {
+            let label = edit.source_path.as_deref().unwrap_or(Path::new(&edit.name));
+            let mut level = match parse_level_file(&edit.toml, LevelFormat::Toml, label) {
+                Ok(level) => level,
+                Err(failure) => {
+                    warn!("Discarding persisted level edit {failure}");
+                    continue;
+                }
+            };
+            level.source_path = edit.source_path.clone();
+
+            let index = match &edit.source_path {
+                Some(path) => levels.index_of_source(path),
+                None => levels.index_of(&edit.name),
+            };
+            match index {
+                Some(index) => {
+                    level.load_rename = levels.levels[index].load_rename.clone();
+                    // Index came from the same resource, so it is in range
+                    let _ = levels.replace_level(index, level);
+                }
+                None => {
+                    levels.add_level(level);
+                }
+            }
+        }
+
+        if let Some(name) = &self.current_level
+            && let Err(err) = levels.switch_to(name)
+        {
+            warn!("Cannot restore the persisted current level: {err}");
+        }
+        info!(
+            "Restored persisted level state ({count} edited levels)",
+            count = self.edited_levels.len()
+        );
+    }
+}
+
+/// Read the persisted level state, or the default state if there is none or it
+/// cannot be read
+#[cfg(target_arch = "wasm32")]
+pub fn load_persisted_state() -> PersistedLevelState {
+    let Some(content) =
+        local_storage().and_then(|storage| storage.get_item(PERSISTED_STATE_KEY).ok().flatten())
+    else {
+        return PersistedLevelState::default();
+    };
+    PersistedLevelState::from_json(&content).unwrap_or_else(|err| {
+        warn!("Ignoring malformed persisted level state: {err:#}");
+        clear_persisted_state();
+        PersistedLevelState::default()
+    })
+}
+
+/// Write `state` to `localStorage`, failing when storage is unavailable or full
+#[cfg(target_arch = "wasm32")]
+pub fn save_persisted_state(state: &PersistedLevelState) -> Result<()> {
+    let storage = local_storage().context("localStorage is not available")?;
+    storage
+        .set_item(PERSISTED_STATE_KEY, &state.to_json()?)
+        .map_err(|err| anyhow::anyhow!("Failed to write localStorage: {err:?}"))
+}
+
+/// Remove the persisted level state
+#[cfg(target_arch = "wasm32")]
+pub fn clear_persisted_state() {
+    if let Some(storage) = local_storage()
+        && let Err(err) = storage.remove_item(PERSISTED_STATE_KEY)
+    {
+        warn!("Failed to clear persisted level state: {err:?}");
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+/// System recording the level state whenever [`LevelsResource`] changes
+///
+/// Later level swaps, such as the hosted levels replacing the embedded ones,
+/// then restore the latest selection and edits. The fallback level inserted at
+/// startup is not recorded, so it cannot overwrite the stored state before the
+/// loaded levels are swapped in.
+pub fn record_level_state_system(
+    levels_resource: Res<LevelsResource>,
+    mut persisted_state: ResMut<PersistedLevelState>,
+) {
+    if !levels_resource.is_changed() || levels_resource.is_added() {
+        return;
+    }
+    match PersistedLevelState::capture(&levels_resource) {
+        Ok(state) => {
+            persisted_state.set_if_neq(state);
+        }
+        Err(err) => warn!("Failed to record level state: {err:#}"),
+    }
+}
+
+/// System writing [`PersistedLevelState`] to `localStorage` whenever it
+/// changes, removing the stored state once it is reset to the default
+#[cfg(target_arch = "wasm32")]
+pub fn persist_level_state_system(persisted_state: Res<PersistedLevelState>) {
+    if !persisted_state.is_changed() || persisted_state.is_added() {
+        return;
+    }
+    if *persisted_state == PersistedLevelState::default() {
+        clear_persisted_state();
+    } else if let Err(err) = save_persisted_state(&persisted_state) {
+        warn!("Failed to persist level state: {err:#}");
+    }
+}
+
+/// System resetting the persisted level state on [`CLEAR_PERSISTED_STATE_KEY`]
+///
+/// The loaded levels are kept; the stored state is removed, and is only written
+/// again once the levels change.
+pub fn clear_persisted_state_input_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut persisted_state: ResMut<PersistedLevelState>,
+) {
+    if keyboard_input.just_pressed(CLEAR_PERSISTED_STATE_KEY) {
+        *persisted_state = PersistedLevelState::default();
+        info!("Cleared persisted level state");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::Level;
+    use hexx::Hex;
+
+    fn loaded_levels() -> LevelsResource {
+        let mut levels: Vec<_> = ["Alpha", "Bravo"]
+            .into_iter()
+            .map(|name| Level::new(name.to_string(), 3, 3))
+            .collect();
+        levels[0].source_path = Some(PathBuf::from("alpha.toml"));
+        LevelsResource::new(levels)
+    }
+
+    #[test]
+    fn test_state_round_trips_edits_and_current_level() {
+        let mut levels = loaded_levels();
+        levels.levels[0].set_height(Hex::new(1, 1), 7.0).unwrap();
+        levels.set_current_index(1);
+        levels.add_level(Level::new("Scratch".to_string(), 4, 4));
+
+        let json = PersistedLevelState::capture(&levels)
+            .and_then(|state| state.to_json())
+            .expect("Level state serializes");
+        let state = PersistedLevelState::from_json(&json).expect("Written state parses");
+        assert_eq!(
+            state.edited_levels.len(),
+            2,
+            "Only modified levels are kept"
+        );
+
+        let mut reloaded = loaded_levels();
+        state.apply(&mut reloaded);
+        assert_eq!(reloaded.level_count(), 3);
+        assert_eq!(reloaded.levels[0].get_height(Hex::new(1, 1)), 7.0);
+        assert!(reloaded.is_modified(0), "Restored edits stay unsaved");
+        assert_eq!(reloaded.levels[2].name, "Scratch");
+        assert_eq!(reloaded.current_level().name, "Bravo");
+    }
+
+    #[test]
+    fn test_bad_persisted_data_falls_back() {
+        assert!(PersistedLevelState::from_json("{\"current_level\": 4").is_err());
+
+        let state = PersistedLevelState {
+            current_level: Some("Gone".to_string()),
+            edited_levels: vec![PersistedLevel {
+                source_path: Some(PathBuf::from("alpha.toml")),
+                name: "Alpha".to_string(),
+                toml: "width = ".to_string(),
+            }],
+        };
+        let mut levels = loaded_levels();
+        let before = levels.levels[0].heights.clone();
+        state.apply(&mut levels);
+
+        assert_eq!(levels.level_count(), 2);
+        assert_eq!(levels.levels[0].heights, before);
+        assert_eq!(levels.current_level().name, "Alpha");
+    }
+
+    #[test]
+    fn test_state_follows_level_changes_and_resets_on_clear() {
+        let mut app = App::new();
+        app.insert_resource(loaded_levels())
+            .init_resource::<PersistedLevelState>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(
+                Update,
+                (
+                    record_level_state_system,
+                    clear_persisted_state_input_system,
+                )
+                    .chain(),
+            );
+        app.update();
+        assert_eq!(
+            *app.world().resource::<PersistedLevelState>(),
+            PersistedLevelState::default(),
+            "Startup levels are not recorded"
+        );
+
+        let mut levels = app.world_mut().resource_mut::<LevelsResource>();
+        levels.set_current_index(1);
+        levels.levels[0].set_height(Hex::new(1, 1), 7.0).unwrap();
+        app.update();
+        let state = app.world().resource::<PersistedLevelState>();
+        assert_eq!(state.current_level.as_deref(), Some("Bravo"));
+        assert_eq!(state.edited_levels.len(), 1);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(CLEAR_PERSISTED_STATE_KEY);
+        app.update();
+        assert_eq!(
+            *app.world().resource::<PersistedLevelState>(),
+            PersistedLevelState::default()
+        );
+    }
+}
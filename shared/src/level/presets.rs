@@ -0,0 +1,222 @@
+//! Height Presets
+//!
+//! Named heights (e.g. "ground", "wall", "tower") that level files can reference
+//! from a compact `heights_by_preset` grid instead of repeating raw floats. Presets
+//! are resolved while deserializing, so the rest of the crate only sees `heights`.
+
+use anyhow::{Context, Result};
+use ndarray::Array2;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tracing::warn;
+
+use super::Level;
+
+/// Level file contents, with the optional preset-encoded height grid split out
+#[derive(Deserialize)]
+struct LevelFile {
+    #[serde(flatten, with = "Level")]
+    level: Level,
+    /// Rows of preset names (or numeric strings), stored as [row][col]
+    #[serde(default)]
+    heights_by_preset: Option<Vec<Vec<String>>>,
+}
+
+impl Serialize for Level {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Level::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Level {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let LevelFile {
+            mut level,
+            heights_by_preset,
+        } = LevelFile::deserialize(deserializer)?;
+
+        match heights_by_preset {
+            Some(grid) => {
+                level.heights = level.resolve_preset_grid(&grid).map_err(D::Error::custom)?;
+            }
+            None if level.heights.is_empty() => return Err(D::Error::missing_field("heights")),
+            None => {}
+        }
+        Ok(level)
+    }
+}
+
+impl Level {
+    /// Turn a grid of preset names into heights
+    ///
+    /// Cells that are not preset names may hold a plain number such as "2.5", so
+    /// grids can mix presets with one-off heights.
+    fn resolve_preset_grid(&self, grid: &[Vec<String>]) -> Result<Array2<f32>, String> {
+        let width = grid.first().map_or(0, Vec::len);
+        if let Some((r, row)) = grid.iter().enumerate().find(|(_, row)| row.len() != width) {
+            return Err(format!(
+                "heights_by_preset row {r} has {len} entries, expected {width}",
+                len = row.len()
+            ));
+        }
+
+        let mut heights = Array2::zeros((grid.len(), width));
+        for (r, row) in grid.iter().enumerate() {
+            for (q, name) in row.iter().enumerate() {
+                heights[(r, q)] = match self.height_presets.get(name) {
+                    Some(&height) => height,
+                    None => name.parse().map_err(|_| {
+                        format!(
+                            "heights_by_preset references undefined preset '{name}' at ({q}, {r})"
+                        )
+                    })?,
+                };
+            }
+        }
+        Ok(heights)
+    }
+
+    /// Encode heights as rows of preset names, falling back to the number itself
+    /// for heights that match no preset
+    pub fn preset_height_grid(&self) -> Vec<Vec<String>> {
+        self.heights
+            .rows()
+            .into_iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&height| {
+                        self.height_presets
+                            .iter()
+                            .find(|&(_, &preset)| preset == height)
+                            .map_or_else(|| height.to_string(), |(name, _)| name.clone())
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Serialize to TOML with `heights_by_preset` in place of the numeric grid
+    ///
+    /// Levels without presets are written normally, with a warning.
+    pub(crate) fn to_preset_toml_string(&self) -> Result<String> {
+        if self.height_presets.is_empty() {
+            warn!(
+                "Level '{level_name}' has no height presets; saving numeric heights",
+                level_name = self.name
+            );
+            return toml::to_string(self).context("Failed to serialize level to TOML");
+        }
+
+        let table = toml::Table::try_from(self).context("Failed to serialize level to TOML")?;
+        let grid = toml::Value::try_from(self.preset_height_grid())
+            .context("Failed to serialize preset height grid")?;
+        // Swap the grid in at the same position so the file keeps its field order
+        let mut encoded = toml::Table::new();
+        for (key, value) in table {
+            if key == "heights" {
+                encoded.insert("heights_by_preset".to_string(), grid.clone());
+            } else {
+                encoded.insert(key, value);
+            }
+        }
+        toml::to_string(&encoded).context("Failed to serialize level to TOML")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::{SaveOptions, load_levels_from_directory};
+    use hexx::Hex;
+    use tempfile::TempDir;
+
+    const PRESET_LEVEL: &str = r#"
+name = "Fortress"
+width = 3
+height = 2
+heights_by_preset = [
+    ["ground", "wall", "tower"],
+    ["ground", "ground", "1.25"],
+]
+
+[height_presets]
+ground = 0.5
+wall = 2.0
+tower = 4.0
+"#;
+
+    #[test]
+    fn test_preset_grid_resolves_to_heights() {
+        let level: Level = toml::from_str(PRESET_LEVEL).expect("Preset level should parse");
+
+        assert_eq!(level.heights.shape(), &[2, 3]);
+        assert_eq!(level.get_height(Hex::new(0, 0)), 0.5);
+        assert_eq!(level.get_height(Hex::new(1, 0)), 2.0);
+        assert_eq!(level.get_height(Hex::new(2, 0)), 4.0);
+        assert_eq!(level.get_height(Hex::new(2, 1)), 1.25);
+        assert!(level.validate().is_ok());
+    }
+
+    #[test]
+    fn test_undefined_preset_is_an_error() {
+        let content = PRESET_LEVEL.replace("\"tower\"]", "\"keep\"]");
+        let error = toml::from_str::<Level>(&content)
+            .expect_err("Undefined preset should fail to parse")
+            .to_string();
+
+        assert!(
+            error.contains("undefined preset 'keep' at (2, 0)"),
+            "Unexpected error: {error}"
+        );
+    }
+
+    #[test]
+    fn test_missing_heights_is_an_error() {
+        let error = toml::from_str::<Level>("name = \"Empty\"\nwidth = 1\nheight = 1\n")
+            .expect_err("Level without heights should fail to parse")
+            .to_string();
+        assert!(error.contains("heights"), "Unexpected error: {error}");
+    }
+
+    #[test]
+    fn test_save_with_presets_roundtrip() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let mut level = LevelBuilder::new()
+            .name("Fortress")
+            .dimensions(3, 3)
+            .flat(0.5)
+            .with_height(Hex::new(1, 1), 4.0)
+            .with_height(Hex::new(2, 2), 1.75)
+            .build()
+            .unwrap();
+        level.height_presets = [("ground".to_string(), 0.5), ("tower".to_string(), 4.0)].into();
+
+        level
+            .save_to_directory_with(
+                temp_path,
+                "fortress.toml",
+                &SaveOptions {
+                    use_height_presets: true,
+                    ..Default::default()
+                },
+            )
+            .expect("Failed to save level");
+
+        let content = std::fs::read_to_string(temp_dir.path().join("fortress.toml")).unwrap();
+        assert!(content.contains("heights_by_preset"), "Saved: {content}");
+        assert!(!content.contains("[heights]"), "Saved: {content}");
+        assert!(content.contains("\"tower\""), "Saved: {content}");
+        assert!(
+            content.starts_with("name = \"Fortress\""),
+            "Saved: {content}"
+        );
+
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let loaded = loaded.current_level();
+        assert_eq!(loaded.heights, level.heights);
+        assert_eq!(loaded.height_presets, level.height_presets);
+    }
+}
@@ -0,0 +1,118 @@
+//! Ramp Connections
+//!
+//! Marked connections between adjacent hexes that units can traverse regardless
+//! of the height difference, so not every elevation change is a cliff.
+
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// A traversable connection between two adjacent hexes
+///
+/// Ramps are undirected; `from` and `to` only record how the file was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ramp {
+    /// First hex as (q, r)
+    pub from: (i32, i32),
+    /// Second hex as (q, r), adjacent to `from`
+    pub to: (i32, i32),
+}
+
+impl Ramp {
+    /// Create a ramp between two hexes
+    pub fn new(from: Hex, to: Hex) -> Self {
+        Self {
+            from: (from.x, from.y),
+            to: (to.x, to.y),
+        }
+    }
+
+    /// Get both endpoints as [`Hex`] values
+    pub fn hexes(&self) -> (Hex, Hex) {
+        (
+            Hex::new(self.from.0, self.from.1),
+            Hex::new(self.to.0, self.to.1),
+        )
+    }
+
+    /// Check whether this ramp connects `a` and `b` in either direction
+    pub fn connects(&self, a: Hex, b: Hex) -> bool {
+        let (from, to) = self.hexes();
+        (from == a && to == b) || (from == b && to == a)
+    }
+}
+
+impl Level {
+    /// Check whether a ramp connects `a` and `b`
+    pub fn has_ramp(&self, a: Hex, b: Hex) -> bool {
+        self.ramps.iter().any(|ramp| ramp.connects(a, b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use crate::level::pathfinding::{PathOptions, find_path};
+    use tempfile::TempDir;
+
+    /// A 5x1 corridor with a 3-unit cliff between q = 1 and q = 2
+    fn cliff_corridor() -> Level {
+        let mut builder = LevelBuilder::new().dimensions(5, 1).flat(1.0);
+        for q in 2..5 {
+            builder = builder.with_height(Hex::new(q, 0), 4.0);
+        }
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_has_ramp_is_symmetric() {
+        let mut level = cliff_corridor();
+        level.ramps.push(Ramp::new(Hex::new(1, 0), Hex::new(2, 0)));
+
+        assert!(level.has_ramp(Hex::new(1, 0), Hex::new(2, 0)));
+        assert!(level.has_ramp(Hex::new(2, 0), Hex::new(1, 0)));
+        assert!(!level.has_ramp(Hex::new(2, 0), Hex::new(3, 0)));
+    }
+
+    #[test]
+    fn test_pathfinding_uses_ramps() {
+        let mut level = cliff_corridor();
+        let options = PathOptions {
+            max_step_height: 1.0,
+            ..Default::default()
+        };
+        assert!(find_path(&level, Hex::new(0, 0), Hex::new(4, 0), &options).is_none());
+
+        level.ramps.push(Ramp::new(Hex::new(2, 0), Hex::new(1, 0)));
+        let path = find_path(&level, Hex::new(0, 0), Hex::new(4, 0), &options)
+            .expect("Ramp should bridge the cliff");
+        assert_eq!(path.len(), 5);
+    }
+
+    #[test]
+    fn test_ramps_roundtrip_and_invalid_dropped() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let mut level = cliff_corridor();
+        level.ramps = vec![
+            Ramp::new(Hex::new(1, 0), Hex::new(2, 0)),
+            // Not adjacent
+            Ramp::new(Hex::new(0, 0), Hex::new(3, 0)),
+            // Out of bounds
+            Ramp::new(Hex::new(4, 0), Hex::new(5, 0)),
+        ];
+        level
+            .save_to_directory(temp_path, "ramps.toml")
+            .expect("Failed to save level");
+
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(
+            loaded.current_level().ramps,
+            vec![Ramp::new(Hex::new(1, 0), Hex::new(2, 0))]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Named Regions
+//!
+//! Multi-hex zones such as deployment areas or capture points, defined per level
+//! so scenario logic can refer to them by name.
+
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// A named group of hexes, e.g. "deploy_zone_player" or "capture_point_A"
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Region {
+    /// Unique region name used to look the zone up
+    pub name: String,
+    /// Axial hex coordinates as (q, r)
+    pub hexes: Vec<(i32, i32)>,
+}
+
+impl Region {
+    /// Create a region from a list of hexes
+    pub fn new(name: impl Into<String>, hexes: impl IntoIterator<Item = Hex>) -> Self {
+        Self {
+            name: name.into(),
+            hexes: hexes.into_iter().map(|hex| (hex.x, hex.y)).collect(),
+        }
+    }
+
+    /// Check whether this region includes `hex`
+    pub fn contains(&self, hex: Hex) -> bool {
+        self.hexes.contains(&(hex.x, hex.y))
+    }
+
+    /// Iterate over the region's hexes as [`Hex`] values
+    pub fn iter_hexes(&self) -> impl Iterator<Item = Hex> + '_ {
+        self.hexes.iter().map(|&(q, r)| Hex::new(q, r))
+    }
+}
+
+impl Level {
+    /// Look up a region by name
+    pub fn region(&self, name: &str) -> Option<&Region> {
+        self.regions.iter().find(|region| region.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use tempfile::TempDir;
+
+    fn zoned_level() -> Level {
+        let mut level = LevelBuilder::new()
+            .name("Zones")
+            .dimensions(6, 6)
+            .build()
+            .unwrap();
+        level.regions = vec![
+            Region::new(
+                "deploy_zone_player",
+                [Hex::new(0, 0), Hex::new(1, 0), Hex::new(0, 1)],
+            ),
+            Region::new("capture_point_A", [Hex::new(3, 3)]),
+        ];
+        level
+    }
+
+    #[test]
+    fn test_region_lookup() {
+        let level = zoned_level();
+
+        let deploy = level.region("deploy_zone_player").expect("Region exists");
+        assert_eq!(deploy.hexes.len(), 3);
+        assert!(deploy.contains(Hex::new(1, 0)));
+        assert!(!deploy.contains(Hex::new(3, 3)));
+        assert!(level.region("capture_point_B").is_none());
+    }
+
+    #[test]
+    fn test_regions_roundtrip_and_out_of_bounds_dropped() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let level = zoned_level();
+        level
+            .save_to_directory(temp_path, "zones.toml")
+            .expect("Failed to save level");
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(loaded.current_level().regions, level.regions);
+
+        let mut corrupted = level.clone();
+        corrupted.regions[1].hexes.push((6, 0));
+        corrupted
+            .save_to_directory(temp_path, "zones.toml")
+            .expect("Failed to save level");
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(loaded.current_level().regions, level.regions);
+    }
+}
@@ -0,0 +1,194 @@
+//! Level Hot Reloading
+//!
+//! Swaps edited level files into [`LevelsResource`] while the app runs. Native
+//! builds with the `hot-reload` feature watch the levels folder through Bevy's
+//! asset watcher; entries are matched to files by [`Level::source_path`].
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use bevy::asset::AssetLoadFailedEvent;
+use bevy::prelude::*;
+use tracing::{info, warn};
+
+use super::asset::LevelAsset;
+use super::format::LevelFormat;
+use super::{Level, LevelsResource, parse_level_bytes};
+
+impl LevelsResource {
+    /// Find the level loaded from `source_path`, relative to the levels directory
+    pub fn index_of_source(&self, source_path: &Path) -> Option<usize> {
+        self.levels
+            .iter()
+            .position(|level| level.source_path.as_deref() == Some(source_path))
+    }
+
+    /// Replace the level loaded from the same file as `level`
+    ///
+    /// The new content counts as saved, since it came from disk. A load-time
+    /// rename of the old level is kept if the file still uses the same name.
+    /// Returns the replaced index, or `None` when no loaded level has that
+    /// source path.
+    pub fn replace_from_source(&mut self, mut level: Level) -> Option<usize> {
+        let index = self.index_of_source(level.source_path.as_deref()?)?;
+        let previous = &self.levels[index];
+        if previous.name != level.name && previous.saved_name() == level.name {
+            level.rename_unique(previous.name.clone());
+        }
+        self.levels[index] = level;
+        self.mark_saved(index);
+        Some(index)
+    }
+
+    /// Re-read `relative_path` inside `levels_dir` and replace its loaded level
+    ///
+    /// On any read or parse failure the in-memory level is left untouched.
+    pub fn reload_level_file(&mut self, levels_dir: &str, relative_path: &Path) -> Result<usize> {
+        let Some(format) = LevelFormat::from_path(relative_path) else {
+            bail!("{path} is not a level file", path = relative_path.display());
+        };
+        let full_path = Path::new(levels_dir).join(relative_path);
+        let display_path = full_path.display();
+        let bytes = fs::read(&full_path)
+            .with_context(|| format!("Failed to read level file {display_path}"))?;
+        let level = parse_level_bytes(&bytes, format, relative_path)
+            .with_context(|| format!("Failed to reload level file {display_path}"))?;
+
+        self.replace_from_source(level).with_context(|| {
+            format!(
+                "No loaded level came from {path}",
+                path = relative_path.display()
+            )
+        })
+    }
+}
+
+/// System applying hot-reloaded level assets to [`LevelsResource`]
+///
+/// Only reloading the current level marks the resource as changed, so the grid
+/// is rebuilt by `level_switching_system` exactly when it is on screen. Failed
+/// reloads keep the previous in-memory level, and levels with unsaved edits are
+/// left alone rather than discarding the edits.
+pub fn level_hot_reload_system(
+    mut asset_events: EventReader<AssetEvent<LevelAsset>>,
+    mut failed_events: EventReader<AssetLoadFailedEvent<LevelAsset>>,
+    level_assets: Res<Assets<LevelAsset>>,
+    mut levels_resource: ResMut<LevelsResource>,
+) {
+    for event in asset_events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        // Manifest edits only take effect on the next full load
+        let level = match level_assets.get(*id) {
+            Some(LevelAsset::Level(level)) => level,
+            Some(LevelAsset::Failed(failure)) => {
+                warn!("Failed to reload level file {failure}; keeping the loaded version");
+                continue;
+            }
+            _ => continue,
+        };
+        let Some(index) = level
+            .source_path
+            .as_deref()
+            .and_then(|path| levels_resource.index_of_source(path))
+        else {
+            continue;
+        };
+
+        let level_name = level.name.clone();
+        if levels_resource.is_modified(index) {
+            warn!("Not hot-reloading level '{level_name}' (index {index}): it has unsaved edits");
+            continue;
+        }
+        if index == levels_resource.current_level_index {
+            levels_resource.replace_from_source(Level::clone(level));
+        } else {
+            levels_resource
+                .bypass_change_detection()
+                .replace_from_source(Level::clone(level));
+        }
+        info!("Hot-reloaded level '{level_name}' (index {index})");
+    }
+
+    for failed in failed_events.read() {
+        warn!(
+            "Failed to reload level file {path}, keeping the loaded version: {error}",
+            path = failed.path,
+            error = failed.error
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use hexx::Hex;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_reload_replaces_matching_level() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        for (name, file) in [("Alpha", "alpha.toml"), ("Bravo", "bravo.toml")] {
+            LevelBuilder::new()
+                .name(name)
+                .dimensions(3, 3)
+                .flat(1.0)
+                .build()
+                .unwrap()
+                .save_to_directory(temp_path, file)
+                .expect("Failed to save level");
+        }
+        let mut levels = load_levels_from_directory(temp_path).expect("Failed to load levels");
+
+        let edited = LevelBuilder::new()
+            .name("Bravo")
+            .dimensions(3, 3)
+            .flat(1.0)
+            .with_height(Hex::new(1, 1), 5.0)
+            .build()
+            .unwrap();
+        edited.save_to_directory(temp_path, "bravo.toml").unwrap();
+
+        let index = levels
+            .reload_level_file(temp_path, Path::new("bravo.toml"))
+            .expect("Reload should succeed");
+        assert_eq!(levels.levels[index].name, "Bravo");
+        assert_eq!(levels.levels[index].get_height(Hex::new(1, 1)), 5.0);
+        assert!(!levels.is_modified(index));
+        assert_eq!(levels.levels[0].get_height(Hex::new(1, 1)), 1.0);
+    }
+
+    #[test]
+    fn test_failed_reload_keeps_old_level() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        LevelBuilder::new()
+            .name("Alpha")
+            .dimensions(3, 3)
+            .build()
+            .unwrap()
+            .save_to_directory(temp_path, "alpha.toml")
+            .unwrap();
+        let mut levels = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let before = levels.current_level().heights.clone();
+
+        fs::write(
+            temp_dir.path().join("alpha.toml"),
+            "name = \"Alpha\"\nwidth = ",
+        )
+        .unwrap();
+
+        assert!(
+            levels
+                .reload_level_file(temp_path, Path::new("alpha.toml"))
+                .is_err()
+        );
+        assert_eq!(levels.current_level().heights, before);
+        assert_eq!(levels.current_level().name, "Alpha");
+    }
+}
@@ -0,0 +1,301 @@
+//! Remote Level Loading
+//!
+//! Web builds start on the levels embedded in the binary, then fetch the level
+//! files listed in [`REMOTE_LEVEL_MANIFEST_FILE`] from the site and swap them
+//! in once they have all arrived. Bevy's default asset source reads over HTTP
+//! in the browser, so the same [`LevelAssetLoader`](super::asset::LevelAssetLoader)
+//! parses them. Any network failure keeps the embedded levels.
+
+use std::path::Path;
+
+use bevy::asset::LoadState;
+use bevy::prelude::*;
+use tracing::{info, warn};
+
+use super::asset::{LevelAsset, LevelAssetFolder, LevelLoadSettings};
+use super::manifest::{LevelManifest, REMOTE_LEVEL_MANIFEST_FILE};
+use super::naming::make_names_unique;
+use super::report::{LevelLoadErrorKind, LevelLoadFailure, LevelLoadReport};
+use super::sorting::order_levels;
+use super::{Level, LevelsResource};
+
+/// Folder on the default asset source holding the hosted level files
+pub const REMOTE_LEVELS_FOLDER: &str = "levels";
+
+/// Progress of fetching the hosted levels
+#[derive(Resource, Debug)]
+pub struct RemoteLevels {
+    manifest: Handle<LevelAsset>,
+    /// Requested level files, once the manifest has loaded
+    files: Option<Vec<Handle<LevelAsset>>>,
+    done: bool,
+}
+
+impl RemoteLevels {
+    /// Whether the fetch finished, whether or not any level was swapped in
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+/// Startup system requesting the hosted level manifest
+pub fn request_remote_levels(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let path = format!("{REMOTE_LEVELS_FOLDER}/{REMOTE_LEVEL_MANIFEST_FILE}");
+    info!("Requesting hosted level manifest '{path}'");
+    commands.insert_resource(RemoteLevels {
+        manifest: asset_server.load(path),
+        files: None,
+        done: false,
+    });
+}
+
+/// System fetching the files named by the hosted manifest and swapping them
+/// into [`LevelsResource`] once all of them have loaded or failed
+///
+/// Waits for the embedded levels to be applied first so they cannot overwrite
+/// the fetched ones. The current level is kept if the fetched set has a level
+/// with the same name.
+pub fn remote_level_loading_system(
+    asset_server: Res<AssetServer>,
+    level_assets: Res<Assets<LevelAsset>>,
+    remote: Option<ResMut<RemoteLevels>>,
+    level_folder: Option<Res<LevelAssetFolder>>,
+    settings: LevelLoadSettings,
+    mut levels_resource: ResMut<LevelsResource>,
+    mut load_report: ResMut<LevelLoadReport>,
+) {
+    let Some(mut remote) = remote else {
+        return;
+    };
+    if remote.done {
+        return;
+    }
+
+    let Some(files) = remote.files.clone() else {
+        match asset_server.load_state(&remote.manifest) {
+            LoadState::Failed(err) => {
+                warn!("Failed to fetch the hosted level manifest: {err}; keeping embedded levels");
+                remote.done = true;
+            }
+            LoadState::Loaded => {
+                let Some(LevelAsset::RemoteManifest(manifest)) = level_assets.get(&remote.manifest)
+                else {
+                    warn!(
+                        "Hosted level manifest did not load as a manifest; keeping embedded levels"
+                    );
+                    remote.done = true;
+                    return;
+                };
+                let files: Vec<_> = manifest
+                    .enabled_files()
+                    .map(|file| asset_server.load(remote_level_path(file)))
+                    .collect();
+                info!("Fetching {count} hosted level files", count = files.len());
+                remote.files = Some(files);
+            }
+            _ => {}
+        }
+        return;
+    };
+
+    let still_loading = files.iter().any(|handle| {
+        matches!(
+            asset_server.load_state(handle),
+            LoadState::NotLoaded | LoadState::Loading
+        )
+    });
+    if still_loading || level_folder.is_some_and(|folder| !folder.is_applied()) {
+        return;
+    }
+    remote.done = true;
+
+    let mut report = LevelLoadReport::default();
+    for handle in &files {
+        match (asset_server.load_state(handle), level_assets.get(handle)) {
+            (_, Some(LevelAsset::Level(level))) => report.loaded.push(Level::clone(level)),
+            (_, Some(LevelAsset::Failed(failure))) => {
+                warn!("Skipping hosted level file {failure}");
+                report.failures.push(failure.clone());
+            }
+            (LoadState::Failed(err), _) => {
+                let path = handle
+                    .path()
+                    .map(|path| path.path())
+                    .unwrap_or(Path::new(""));
+                let relative_path = path.strip_prefix(REMOTE_LEVELS_FOLDER).unwrap_or(path);
+                let failure = LevelLoadFailure::new(
+                    relative_path,
+                    LevelLoadErrorKind::Io,
+                    anyhow::anyhow!("{err}"),
+                );
+                warn!("Skipping hosted level file {failure}");
+                report.failures.push(failure);
+            }
+            _ => {}
+        }
+    }
+
+    if report.loaded.is_empty() {
+        warn!(
+            "No hosted levels could be loaded ({count} failed); keeping embedded levels",
+            count = report.failures.len()
+        );
+        return;
+    }
+
+    let manifest = match level_assets.get(&remote.manifest) {
+        Some(LevelAsset::RemoteManifest(manifest)) => Some(LevelManifest::clone(manifest)),
+        _ => None,
+    };
+    make_names_unique(&mut report.loaded);
+    report.loaded = order_levels(report.loaded, manifest, settings.sort_mode());
+
+    let current_name = levels_resource.current_level().name.clone();
+    let level_count = report.loaded.len();
+    settings.swap_in_levels(report, &mut levels_resource, &mut load_report);
+    if levels_resource.index_of(&current_name).is_some() {
+        let _ = levels_resource.switch_to(&current_name);
+    }
+    info!("Swapped in {level_count} hosted levels");
+}
+
+/// Asset path of a level file listed in the hosted manifest
+fn remote_level_path(file: &Path) -> String {
+    let file = file.to_string_lossy().replace('\\', "/");
+    format!("{REMOTE_LEVELS_FOLDER}/{file}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::asset::LevelAssetLoader;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::management::DefaultLevelConfig;
+    use crate::level::persistence::{PersistedLevelState, record_level_state_system};
+    use crate::level::sorting::LevelSortMode;
+    use hexx::Hex;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn remote_app(temp_dir: &TempDir) -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin {
+                file_path: temp_dir.path().to_str().unwrap().to_string(),
+                ..default()
+            },
+        ))
+        .init_asset::<LevelAsset>()
+        .init_asset_loader::<LevelAssetLoader>()
+        .insert_resource(LevelsResource::with_default())
+        .init_resource::<LevelLoadReport>()
+        .init_resource::<LevelSortMode>()
+        .init_resource::<DefaultLevelConfig>()
+        .add_systems(Startup, request_remote_levels)
+        .add_systems(Update, remote_level_loading_system);
+        app
+    }
+
+    fn run_until_done(app: &mut App) {
+        for _ in 0..500 {
+            app.update();
+            if app.world().resource::<RemoteLevels>().is_done() {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        panic!("Hosted levels never finished loading");
+    }
+
+    #[test]
+    fn test_hosted_levels_load_in_manifest_order() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let levels_dir = temp_dir.path().join(REMOTE_LEVELS_FOLDER);
+        let levels_path = levels_dir.to_str().expect("Failed to get temp path");
+        for (name, file) in [("Alpha", "alpha.toml"), ("Bravo", "maps/bravo.toml")] {
+            LevelBuilder::new()
+                .name(name)
+                .dimensions(3, 3)
+                .build()
+                .unwrap()
+                .save_to_directory(levels_path, file)
+                .expect("Failed to save level");
+        }
+        std::fs::write(levels_dir.join("broken.toml"), "width = ").unwrap();
+        std::fs::write(
+            levels_dir.join(REMOTE_LEVEL_MANIFEST_FILE),
+            r#"{"levels": [{"file": "maps/bravo.toml"}, {"file": "missing.toml"}, {"file": "broken.toml"}, {"file": "alpha.toml"}]}"#,
+        )
+        .unwrap();
+
+        let mut app = remote_app(&temp_dir);
+        run_until_done(&mut app);
+
+        let levels = app.world().resource::<LevelsResource>();
+        let names: Vec<_> = levels.levels.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(names, ["Bravo", "Alpha"]);
+        let report = app.world().resource::<LevelLoadReport>();
+        assert_eq!(report.failure_count(LevelLoadErrorKind::Io), 1);
+        assert_eq!(report.failure_count(LevelLoadErrorKind::Parse), 1);
+    }
+
+    #[test]
+    fn test_hosted_swap_restores_latest_level_state() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let levels_dir = temp_dir.path().join(REMOTE_LEVELS_FOLDER);
+        let levels_path = levels_dir.to_str().expect("Failed to get temp path");
+        let mut embedded = Vec::new();
+        for (name, file) in [("Alpha", "alpha.toml"), ("Bravo", "bravo.toml")] {
+            let mut level = LevelBuilder::new()
+                .name(name)
+                .dimensions(3, 3)
+                .build()
+                .unwrap();
+            level
+                .save_to_directory(levels_path, file)
+                .expect("Failed to save level");
+            level.source_path = Some(file.into());
+            embedded.push(level);
+        }
+        std::fs::write(
+            levels_dir.join(REMOTE_LEVEL_MANIFEST_FILE),
+            r#"{"levels": [{"file": "bravo.toml"}, {"file": "alpha.toml"}]}"#,
+        )
+        .unwrap();
+
+        let mut app = remote_app(&temp_dir);
+        app.insert_resource(LevelsResource::new(embedded))
+            .init_resource::<PersistedLevelState>()
+            // Recorded before the swap, as in LevelPlugin
+            .add_systems(
+                Update,
+                record_level_state_system.before(remote_level_loading_system),
+            );
+        app.update();
+        // Select and edit a level while the hosted ones are still on their way
+        let mut levels = app.world_mut().resource_mut::<LevelsResource>();
+        levels.set_current_index(1);
+        levels.levels[0].set_height(Hex::new(1, 1), 7.0).unwrap();
+        run_until_done(&mut app);
+
+        let levels = app.world().resource::<LevelsResource>();
+        let names: Vec<_> = levels.levels.iter().map(|l| l.name.as_str()).collect();
+        assert_eq!(names, ["Bravo", "Alpha"]);
+        assert_eq!(levels.current_level().name, "Bravo");
+        assert_eq!(levels.levels[1].get_height(Hex::new(1, 1)), 7.0);
+        assert!(levels.is_modified(1));
+    }
+
+    #[test]
+    fn test_missing_manifest_keeps_current_levels() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+
+        let mut app = remote_app(&temp_dir);
+        run_until_done(&mut app);
+
+        let levels = app.world().resource::<LevelsResource>();
+        assert_eq!(levels.level_count(), 1);
+        assert_eq!(levels.current_level().name, "Default Level");
+    }
+}
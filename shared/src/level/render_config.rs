@@ -0,0 +1,443 @@
+//! Hex Grid Render Configuration
+//!
+//! Options for how hex surfaces are drawn. The column materials take their
+//! shading from [`SurfaceMaterial`], which can be tweaked while running. A
+//! surface texture, by default the UV checker, can be applied to every column
+//! material and toggled with [`SURFACE_TEXTURE_TOGGLE_KEY`] to inspect the mesh
+//! UVs. Native builds can
+//! switch between wireframe and outline hex edges with
+//! [`EDGE_STYLE_TOGGLE_KEY`].
+//!
+//! Hexes below a configured height can be rendered as void, either as holes in
+//! the map or as translucent tiles at the threshold; see [`VoidRendering`].
+//! [`VOID_TOGGLE_KEY`] shows the raw heights again.
+
+use bevy::image::{ImageAddressMode, ImageLoaderSettings, ImageSampler, ImageSamplerDescriptor};
+use bevy::prelude::*;
+use hexx::Hex;
+use tracing::info;
+
+use super::Level;
+#[cfg(not(target_arch = "wasm32"))]
+use super::mesh::HexEdgeStyle;
+use super::mesh::HexMaterials;
+
+/// Asset path of the UV checker texture shipped in `assets/`
+#[cfg(not(target_arch = "wasm32"))]
+pub const UV_CHECKER_TEXTURE: &str = "uv_checker.png";
+/// Asset path of the UV checker texture embedded into the web build
+#[cfg(target_arch = "wasm32")]
+pub const UV_CHECKER_TEXTURE: &str = "embedded://game/assets/uv_checker.png";
+
+/// Key toggling between textured and flat hex surfaces
+pub const SURFACE_TEXTURE_TOGGLE_KEY: KeyCode = KeyCode::F2;
+
+/// Key switching hex edges between wireframes and outlines (native only)
+#[cfg(not(target_arch = "wasm32"))]
+pub const EDGE_STYLE_TOGGLE_KEY: KeyCode = KeyCode::F3;
+
+/// Key toggling void rendering, to inspect the raw heights of void hexes
+pub const VOID_TOGGLE_KEY: KeyCode = KeyCode::F7;
+
+/// What takes the place of a void hex's column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VoidStyle {
+    /// Nothing; the hex is a hole in the map
+    #[default]
+    Hole,
+    /// A flat translucent tile at the void threshold
+    Tile,
+}
+
+/// Hexes lower than `threshold` are void and lose their column
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VoidRendering {
+    pub threshold: f32,
+    pub style: VoidStyle,
+}
+
+impl VoidRendering {
+    pub fn is_void(&self, height: f32) -> bool {
+        height < self.threshold
+    }
+
+    /// Height of the visible ground surface of a hex at `height`, or `None` if
+    /// the hex is a hole
+    pub fn surface_height(&self, height: f32) -> Option<f32> {
+        match (self.is_void(height), self.style) {
+            (false, _) => Some(height),
+            (true, VoidStyle::Hole) => None,
+            (true, VoidStyle::Tile) => Some(self.threshold),
+        }
+    }
+}
+
+/// Height of the visible ground surface at `hex`, honoring `void`
+///
+/// Returns `None` outside the level and for holes.
+pub fn ground_surface_height(level: &Level, hex: Hex, void: Option<VoidRendering>) -> Option<f32> {
+    let height = level.try_get_height(hex)?;
+    match void {
+        Some(void) => void.surface_height(height),
+        None => Some(height),
+    }
+}
+
+/// World bounds of the rendered ground, like [`Level::get_world_bounds`] but
+/// leaving out holes and using the threshold height for void tiles
+pub fn visible_world_bounds(level: &Level, void: Option<VoidRendering>) -> (Vec3, Vec3) {
+    level.world_bounds_of(|hex| ground_surface_height(level, hex, void))
+}
+
+/// 3D diagonal of [`visible_world_bounds`], like
+/// [`Level::get_level_diagonal_extent`] but for the rendered ground
+///
+/// Falls back to the full level's cached diagonal without void rendering or
+/// when every hex is a hole.
+pub fn visible_diagonal_extent(level: &Level, void: Option<VoidRendering>) -> f32 {
+    let full_extent = level.geometry().diagonal_extent;
+    if void.is_none() {
+        return full_extent;
+    }
+    let (min_bounds, max_bounds) = visible_world_bounds(level, void);
+    if min_bounds.x > max_bounds.x {
+        return full_extent;
+    }
+    min_bounds.distance(max_bounds)
+}
+
+/// Shading parameters shared by the column materials
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SurfaceMaterial {
+    pub metallic: f32,
+    pub perceptual_roughness: f32,
+    pub reflectance: f32,
+}
+
+impl Default for SurfaceMaterial {
+    fn default() -> Self {
+        Self {
+            metallic: 0.1,
+            perceptual_roughness: 0.8,
+            reflectance: 0.2,
+        }
+    }
+}
+
+impl SurfaceMaterial {
+    /// Copy these parameters onto `material`
+    pub fn apply_to(&self, material: &mut StandardMaterial) {
+        material.metallic = self.metallic;
+        material.perceptual_roughness = self.perceptual_roughness;
+        material.reflectance = self.reflectance;
+    }
+}
+
+/// How the hex grid surfaces are rendered
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct HexGridRenderConfig {
+    /// Shading of the column materials
+    pub surface_material: SurfaceMaterial,
+    /// Asset path of the texture applied to hex surfaces, if any
+    pub surface_texture: Option<String>,
+    /// Whether `surface_texture` is currently applied
+    pub textured: bool,
+    /// Which hexes render as void, if any
+    pub void: Option<VoidRendering>,
+    /// Whether `void` is currently applied
+    pub show_void: bool,
+}
+
+impl Default for HexGridRenderConfig {
+    fn default() -> Self {
+        Self {
+            surface_material: SurfaceMaterial::default(),
+            surface_texture: Some(UV_CHECKER_TEXTURE.to_string()),
+            textured: false,
+            void: None,
+            show_void: true,
+        }
+    }
+}
+
+impl HexGridRenderConfig {
+    /// Texture to apply right now, if texturing is on and one is configured
+    pub fn active_texture(&self) -> Option<&str> {
+        self.surface_texture.as_deref().filter(|_| self.textured)
+    }
+
+    /// Void rendering to apply right now, if it is on and configured
+    pub fn active_void(&self) -> Option<VoidRendering> {
+        self.void.filter(|_| self.show_void)
+    }
+}
+
+/// System applying the configured shading and surface texture to the column
+/// materials whenever [`HexGridRenderConfig`] changes
+///
+/// The materials are edited in place, so no material assets are added. The
+/// texture is sampled with repeat addressing so side walls taller than one
+/// tile keep tiling.
+pub fn apply_surface_material_system(
+    config: Res<HexGridRenderConfig>,
+    hex_materials: Res<HexMaterials>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    asset_server: Res<AssetServer>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+
+    let texture = config.active_texture().map(|path| {
+        asset_server.load_with_settings(path.to_string(), |settings: &mut ImageLoaderSettings| {
+            settings.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+                address_mode_u: ImageAddressMode::Repeat,
+                address_mode_v: ImageAddressMode::Repeat,
+                ..default()
+            });
+        })
+    });
+    for handle in [
+        &hex_materials.surface,
+        &hex_materials.vertex_colored,
+        &hex_materials.submerged,
+        &hex_materials.impassable,
+    ] {
+        if let Some(material) = materials.get_mut(handle) {
+            config.surface_material.apply_to(material);
+            material.base_color_texture = texture.clone();
+        }
+    }
+}
+
+/// System toggling the surface texture on [`SURFACE_TEXTURE_TOGGLE_KEY`]
+pub fn surface_texture_toggle_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<HexGridRenderConfig>,
+) {
+    if !keyboard_input.just_pressed(SURFACE_TEXTURE_TOGGLE_KEY) {
+        return;
+    }
+
+    let Some(path) = config.surface_texture.clone() else {
+        info!("No surface texture configured; hex surfaces stay flat");
+        return;
+    };
+    config.textured = !config.textured;
+    info!(
+        "Hex surface texture {state}: {path}",
+        state = if config.textured { "ON" } else { "OFF" }
+    );
+}
+
+/// System toggling void rendering on [`VOID_TOGGLE_KEY`]; the grid is
+/// respawned with or without void hexes
+pub fn void_toggle_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<HexGridRenderConfig>,
+) {
+    if !keyboard_input.just_pressed(VOID_TOGGLE_KEY) {
+        return;
+    }
+
+    let Some(void) = config.void else {
+        info!("No void threshold configured; every hex keeps its column");
+        return;
+    };
+    config.show_void = !config.show_void;
+    info!(
+        "Void rendering {state} below {threshold}",
+        state = if config.show_void { "ON" } else { "OFF" },
+        threshold = void.threshold
+    );
+}
+
+/// System switching [`HexEdgeStyle`] on [`EDGE_STYLE_TOGGLE_KEY`]; the grid is
+/// respawned with the new edges
+#[cfg(not(target_arch = "wasm32"))]
+pub fn edge_style_toggle_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut edge_style: ResMut<HexEdgeStyle>,
+) {
+    if !keyboard_input.just_pressed(EDGE_STYLE_TOGGLE_KEY) {
+        return;
+    }
+
+    *edge_style = match *edge_style {
+        HexEdgeStyle::Outline => HexEdgeStyle::Wireframe,
+        HexEdgeStyle::Wireframe => HexEdgeStyle::Outline,
+    };
+    info!("Hex edge style: {edge_style:?}", edge_style = *edge_style);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_surface_material_edits_existing_materials() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<StandardMaterial>()
+            .init_resource::<HexMaterials>()
+            .init_resource::<HexGridRenderConfig>()
+            .add_systems(Update, apply_surface_material_system);
+        app.update();
+        let material_count = app.world().resource::<Assets<StandardMaterial>>().len();
+
+        app.world_mut()
+            .resource_mut::<HexGridRenderConfig>()
+            .surface_material = SurfaceMaterial {
+            metallic: 0.6,
+            perceptual_roughness: 0.3,
+            reflectance: 0.5,
+        };
+        app.update();
+
+        let hex_materials = app.world().resource::<HexMaterials>().clone();
+        let materials = app.world().resource::<Assets<StandardMaterial>>();
+        assert_eq!(materials.len(), material_count);
+        for handle in [&hex_materials.surface, &hex_materials.impassable] {
+            let material = materials.get(handle).unwrap();
+            assert_eq!(
+                (material.metallic, material.perceptual_roughness),
+                (0.6, 0.3)
+            );
+        }
+        // Water keeps its own glossy shading
+        assert_eq!(
+            materials
+                .get(&hex_materials.water)
+                .unwrap()
+                .perceptual_roughness,
+            0.1
+        );
+    }
+
+    #[test]
+    fn test_void_predicate_and_surface_heights() {
+        let hole = VoidRendering {
+            threshold: 0.5,
+            style: VoidStyle::Hole,
+        };
+        assert!(hole.is_void(0.49));
+        assert!(!hole.is_void(0.5), "The threshold itself is solid ground");
+        assert_eq!(hole.surface_height(-1.0), None);
+        assert_eq!(hole.surface_height(2.0), Some(2.0));
+
+        let tile = VoidRendering {
+            style: VoidStyle::Tile,
+            ..hole
+        };
+        assert_eq!(tile.surface_height(-1.0), Some(0.5));
+
+        let mut config = HexGridRenderConfig {
+            void: Some(hole),
+            ..default()
+        };
+        assert_eq!(config.active_void(), Some(hole));
+        config.show_void = false;
+        assert_eq!(config.active_void(), None);
+    }
+
+    #[test]
+    fn test_visible_bounds_exclude_holes() {
+        let mut level = Level::new("Void".to_string(), 3, 3);
+        for hex in level.hex_iter().collect::<Vec<_>>() {
+            level.set_height(hex, 1.0).unwrap();
+        }
+        level.set_height(Hex::new(0, 0), -4.0).unwrap();
+        level.set_height(Hex::new(2, 2), 3.0).unwrap();
+        let layout = level.hex_layout();
+        let corner = layout.hex_to_world_pos(Hex::new(0, 0));
+
+        assert_eq!(visible_world_bounds(&level, None), level.get_world_bounds());
+
+        let hole = VoidRendering {
+            threshold: 0.0,
+            style: VoidStyle::Hole,
+        };
+        let (min, max) = visible_world_bounds(&level, Some(hole));
+        assert_eq!((min.y, max.y), (1.0, 3.0));
+        // The hole no longer reaches the corner of the grid
+        let (full_min, _) = level.get_world_bounds();
+        assert!(full_min.x <= corner.x && full_min.z <= corner.y);
+        assert!(min.x > corner.x || min.z > corner.y);
+
+        let tile = VoidRendering {
+            style: VoidStyle::Tile,
+            ..hole
+        };
+        let (min, _) = visible_world_bounds(&level, Some(tile));
+        assert_eq!(min.y, 0.0);
+        assert_eq!((min.x, min.z), (full_min.x, full_min.z));
+    }
+
+    #[test]
+    fn test_visible_diagonal_leaves_out_holes() {
+        let mut level = Level::new("Void".to_string(), 4, 4);
+        for hex in level.hex_iter().collect::<Vec<_>>() {
+            let height = if hex.x == 3 { -2.0 } else { 1.0 };
+            level.set_height(hex, height).unwrap();
+        }
+        let full = level.get_level_diagonal_extent();
+        assert_eq!(visible_diagonal_extent(&level, None), full);
+
+        let hole = VoidRendering {
+            threshold: 0.0,
+            style: VoidStyle::Hole,
+        };
+        assert!(visible_diagonal_extent(&level, Some(hole)) < full);
+
+        // With nothing left to show the full level is framed
+        let everything = VoidRendering {
+            threshold: 5.0,
+            ..hole
+        };
+        assert_eq!(visible_diagonal_extent(&level, Some(everything)), full);
+    }
+
+    #[test]
+    fn test_toggle_key_switches_texture() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<HexGridRenderConfig>()
+            .add_systems(Update, surface_texture_toggle_system);
+        let press = |app: &mut App| {
+            let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keyboard.release(SURFACE_TEXTURE_TOGGLE_KEY);
+            keyboard.clear();
+            keyboard.press(SURFACE_TEXTURE_TOGGLE_KEY);
+            app.update();
+        };
+        assert_eq!(
+            app.world()
+                .resource::<HexGridRenderConfig>()
+                .active_texture(),
+            None
+        );
+
+        press(&mut app);
+        assert_eq!(
+            app.world()
+                .resource::<HexGridRenderConfig>()
+                .active_texture(),
+            Some(UV_CHECKER_TEXTURE)
+        );
+
+        press(&mut app);
+        assert_eq!(
+            app.world()
+                .resource::<HexGridRenderConfig>()
+                .active_texture(),
+            None
+        );
+
+        // Without a texture the toggle is a no-op
+        app.world_mut()
+            .resource_mut::<HexGridRenderConfig>()
+            .surface_texture = None;
+        press(&mut app);
+        assert!(!app.world().resource::<HexGridRenderConfig>().textured);
+    }
+}
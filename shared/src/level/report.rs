@@ -0,0 +1,222 @@
+//! Level Load Reports
+//!
+//! Collects the outcome of loading a set of level files, so failures can be
+//! shown to the player or designer instead of only being scattered through the
+//! log. The latest report is kept as a resource by `LevelPlugin`.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use tracing::{info, warn};
+
+use super::management::DefaultLevelConfig;
+use super::{Level, LevelsResource};
+
+/// Stage at which a level file failed to load
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LevelLoadErrorKind {
+    /// The file could not be read, decompressed, or decoded as text
+    Io,
+    /// The file is not a well-formed level in its format
+    Parse,
+    /// The level parsed but is unusable: bad grid shapes, out-of-range heights,
+    /// or a format version newer than this build supports
+    Validation,
+}
+
+impl fmt::Display for LevelLoadErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Io => "read error",
+            Self::Parse => "parse error",
+            Self::Validation => "validation error",
+        };
+        f.write_str(label)
+    }
+}
+
+/// A level file that failed to load
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LevelLoadFailure {
+    /// File path relative to the levels directory
+    pub path: PathBuf,
+    pub kind: LevelLoadErrorKind,
+    /// Full error chain, suitable for display
+    pub message: String,
+}
+
+impl LevelLoadFailure {
+    pub fn new(
+        path: impl Into<PathBuf>,
+        kind: LevelLoadErrorKind,
+        error: impl Into<anyhow::Error>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            kind,
+            message: format!("{:#}", error.into()),
+        }
+    }
+}
+
+impl fmt::Display for LevelLoadFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{path}: {kind}: {message}",
+            path = self.path.display(),
+            kind = self.kind,
+            message = self.message
+        )
+    }
+}
+
+impl std::error::Error for LevelLoadFailure {}
+
+/// Outcome of loading a set of level files
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LevelLoadReport {
+    /// Successfully loaded levels, in cycling order
+    pub loaded: Vec<Level>,
+    /// Files that failed to load, in the order they were tried
+    pub failures: Vec<LevelLoadFailure>,
+}
+
+impl LevelLoadReport {
+    /// Add the result of loading one level file
+    pub fn record(&mut self, result: Result<Level, LevelLoadFailure>) {
+        match result {
+            Ok(level) => {
+                info!(
+                    "Successfully loaded level: '{level_name}' ({width}x{height})",
+                    level_name = level.name,
+                    width = level.width,
+                    height = level.height
+                );
+                self.loaded.push(level);
+            }
+            Err(failure) => {
+                warn!("Skipping level file {failure}");
+                self.failures.push(failure);
+            }
+        }
+    }
+
+    /// Whether any level file failed to load
+    pub fn has_failures(&self) -> bool {
+        !self.failures.is_empty()
+    }
+
+    /// Number of failures of the given kind
+    pub fn failure_count(&self, kind: LevelLoadErrorKind) -> usize {
+        self.failures
+            .iter()
+            .filter(|failure| failure.kind == kind)
+            .count()
+    }
+
+    /// Build the levels resource, using the default level if nothing loaded
+    pub fn into_levels_resource(self) -> LevelsResource {
+        self.into_levels_resource_or(&DefaultLevelConfig::default())
+    }
+
+    /// Build the levels resource, using the level described by `default_level`
+    /// if nothing loaded
+    pub fn into_levels_resource_or(self, default_level: &DefaultLevelConfig) -> LevelsResource {
+        if self.loaded.is_empty() {
+            warn!("No valid level files found, using default level");
+            return LevelsResource::with_config(default_level);
+        }
+
+        info!(
+            "Successfully loaded {count} levels",
+            count = self.loaded.len()
+        );
+        LevelsResource::new(self.loaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_level_report;
+    use crate::level::sorting::LevelSortMode;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_report_captures_failures_by_kind() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        LevelBuilder::new()
+            .name("Good")
+            .dimensions(3, 3)
+            .build()
+            .unwrap()
+            .save_to_directory(temp_path, "good.toml")
+            .expect("Failed to save level");
+
+        let mut spiked = LevelBuilder::new()
+            .name("Spiked")
+            .dimensions(3, 3)
+            .build()
+            .unwrap();
+        spiked.heights[[0, 0]] = 500.0;
+        spiked
+            .save_to_directory(temp_path, "campaign/spiked.toml")
+            .expect("Failed to save level");
+
+        fs::write(
+            temp_dir.path().join("broken.toml"),
+            "name = \"Broken\"\nwidth = ",
+        )
+        .unwrap();
+        fs::write(temp_dir.path().join("binary.toml"), [0xff, 0xfe, 0x00]).unwrap();
+
+        let report =
+            load_level_report(temp_path, LevelSortMode::default()).expect("Failed to load levels");
+
+        assert_eq!(report.loaded.len(), 1);
+        assert_eq!(report.failures.len(), 3);
+        let kind_of = |path: &str| {
+            report
+                .failures
+                .iter()
+                .find(|failure| failure.path == Path::new(path))
+                .map(|failure| failure.kind)
+        };
+        assert_eq!(kind_of("binary.toml"), Some(LevelLoadErrorKind::Io));
+        assert_eq!(kind_of("broken.toml"), Some(LevelLoadErrorKind::Parse));
+        assert_eq!(
+            kind_of("campaign/spiked.toml"),
+            Some(LevelLoadErrorKind::Validation)
+        );
+
+        let levels = report.into_levels_resource();
+        assert_eq!(levels.current_level().name, "Good");
+    }
+
+    #[test]
+    fn test_report_without_levels_falls_back_to_default() {
+        let report = LevelLoadReport {
+            loaded: Vec::new(),
+            failures: vec![LevelLoadFailure::new(
+                "broken.toml",
+                LevelLoadErrorKind::Parse,
+                anyhow::anyhow!("unexpected end of input"),
+            )],
+        };
+        assert!(report.has_failures());
+        assert_eq!(report.failure_count(LevelLoadErrorKind::Parse), 1);
+        assert_eq!(
+            report.failures[0].to_string(),
+            "broken.toml: parse error: unexpected end of input"
+        );
+
+        let levels = report.into_levels_resource();
+        assert_eq!(levels.current_level().name, "Default Level");
+    }
+}
@@ -0,0 +1,128 @@
+//! Terrain Height Sampling
+//!
+//! Height lookups at arbitrary world positions for unit movement and camera
+//! terrain-following, using the same layout as rendering so samples always
+//! match the visible column tops.
+
+use bevy::prelude::*;
+use hexx::Hex;
+
+use super::Level;
+
+impl Level {
+    /// Get the terrain height at a world-space XZ position
+    ///
+    /// Returns the top of the column containing the point. Points outside the map
+    /// return the height of the nearest edge hex, so callers following terrain
+    /// never see a sudden drop to 0.0 at the border.
+    pub fn sample_height(&self, world_xz: Vec2) -> f32 {
+        self.get_height(self.world_to_hex_clamped(world_xz))
+    }
+
+    /// Get a smoothly interpolated terrain height at a world-space XZ position
+    ///
+    /// Blends the heights of the three hex centers surrounding the point using
+    /// barycentric weights, so the result equals [`Level::sample_height`] at hex
+    /// centers and changes continuously across edges. Neighbors outside the map
+    /// contribute the height of the hex containing the point (or nearest edge hex).
+    pub fn sample_height_blended(&self, world_xz: Vec2) -> f32 {
+        let fallback = self.sample_height(world_xz);
+        let fract = self.hex_layout().world_pos_to_fract_hex(world_xz);
+        let base = fract.floor();
+        let (u, v) = (fract.x - base.x, fract.y - base.y);
+        let base = Hex::new(base.x as i32, base.y as i32);
+
+        // Axial coordinates are an affine map of world space, so each rhombus cell
+        // splits into two triangles of hex centers with exact barycentric weights
+        let (corners, weights) = if u + v <= 1.0 {
+            (
+                [base, base + Hex::new(1, 0), base + Hex::new(0, 1)],
+                [1.0 - u - v, u, v],
+            )
+        } else {
+            (
+                [
+                    base + Hex::new(1, 1),
+                    base + Hex::new(1, 0),
+                    base + Hex::new(0, 1),
+                ],
+                [u + v - 1.0, 1.0 - v, 1.0 - u],
+            )
+        };
+
+        corners
+            .iter()
+            .zip(weights)
+            .map(|(&hex, weight)| self.try_get_height(hex).unwrap_or(fallback) * weight)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+
+    /// 5x5 gradient where height = 1.0 + (q / 4 + r / 4) / 2 * 3.0
+    fn gradient_level() -> Level {
+        LevelBuilder::new()
+            .dimensions(5, 5)
+            .gradient(1.0, 4.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_sample_height_matches_column_tops() {
+        let level = gradient_level();
+        let layout = level.hex_layout();
+
+        for hex in level.hex_iter() {
+            let center = layout.hex_to_world_pos(hex);
+            assert_eq!(level.sample_height(center), level.get_height(hex));
+            assert!((level.sample_height_blended(center) - level.get_height(hex)).abs() < 0.0001);
+        }
+
+        assert_eq!(
+            level.sample_height(layout.hex_to_world_pos(Hex::new(0, 0))),
+            1.0
+        );
+        assert_eq!(
+            level.sample_height(layout.hex_to_world_pos(Hex::new(4, 4))),
+            4.0
+        );
+        assert_eq!(
+            level.sample_height(layout.hex_to_world_pos(Hex::new(2, 2))),
+            2.5
+        );
+    }
+
+    #[test]
+    fn test_blended_height_interpolates_between_centers() {
+        let level = gradient_level();
+        let layout = level.hex_layout();
+        let a = layout.hex_to_world_pos(Hex::new(1, 1));
+        let b = layout.hex_to_world_pos(Hex::new(2, 1));
+        let midpoint = (a + b) / 2.0;
+
+        // Stepped sampling picks one column; blended sampling averages them
+        let stepped = level.sample_height(midpoint);
+        assert!(
+            stepped == level.get_height(Hex::new(1, 1))
+                || stepped == level.get_height(Hex::new(2, 1))
+        );
+        let expected = (level.get_height(Hex::new(1, 1)) + level.get_height(Hex::new(2, 1))) / 2.0;
+        assert!((level.sample_height_blended(midpoint) - expected).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_out_of_map_samples_use_nearest_edge_hex() {
+        let level = gradient_level();
+        let layout = level.hex_layout();
+        let corner = layout.hex_to_world_pos(Hex::new(4, 4));
+        let beyond = corner + (corner - layout.hex_to_world_pos(Hex::new(2, 2)));
+
+        assert_eq!(level.sample_height(beyond), 4.0);
+        assert!((level.sample_height_blended(beyond) - 4.0).abs() < 0.0001);
+    }
+}
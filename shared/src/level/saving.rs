@@ -0,0 +1,233 @@
+//! Saving From the Running App
+//!
+//! Ctrl+S saves the current level back to the file it was loaded from. Levels
+//! without a file, such as new or dropped levels, get a file name derived from
+//! their level name. Every attempt ends in a [`LevelSaved`] or
+//! [`LevelSaveFailed`] event for the UI to show.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use bevy::prelude::*;
+use tracing::warn;
+
+use crate::input::SHORTCUT_MODIFIERS;
+
+use super::format::LevelFormat;
+use super::{LevelDirectoryOverride, LevelsResource, levels_directory};
+
+/// The current level was written to disk
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct LevelSaved {
+    pub level_name: String,
+    /// Full path of the written file
+    pub path: PathBuf,
+}
+
+/// Saving the current level failed
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct LevelSaveFailed {
+    pub level_name: String,
+    /// Full error chain, suitable for display
+    pub message: String,
+}
+
+/// Turn a level name into a file stem: lowercase letters and digits (Unicode
+/// included) joined by single underscores, or `"level"` if nothing is left
+pub fn slugify_level_name(name: &str) -> String {
+    let mut slug = String::with_capacity(name.len());
+    for character in name.chars() {
+        if character.is_alphanumeric() {
+            slug.extend(character.to_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('_') {
+            slug.push('_');
+        }
+    }
+    let slug = slug.trim_end_matches('_');
+    if slug.is_empty() {
+        "level".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// First `<slug>.toml`, `<slug>_2.toml`, ... that is neither in `taken` nor
+/// already a file in `levels_dir`
+pub fn unique_level_filename(name: &str, levels_dir: &Path, taken: &HashSet<PathBuf>) -> PathBuf {
+    let slug = slugify_level_name(name);
+    let extension = LevelFormat::Toml.extension();
+    std::iter::once(format!("{slug}.{extension}"))
+        .chain((2..).map(|number| format!("{slug}_{number}.{extension}")))
+        .map(PathBuf::from)
+        .find(|candidate| !taken.contains(candidate) && !levels_dir.join(candidate).exists())
+        .expect("Numbered candidates are unbounded")
+}
+
+impl LevelsResource {
+    /// Save the current level to its source file in `levels_dir`
+    ///
+    /// A level without a source file is saved under [`unique_level_filename`]
+    /// and remembers that file. The level is marked saved on success.
+    pub fn save_current_level(&mut self, levels_dir: &Path) -> Result<PathBuf> {
+        let index = self.current_level_index;
+        let relative_path = match &self.levels[index].source_path {
+            Some(path) => path.clone(),
+            None => {
+                let taken = self
+                    .levels
+                    .iter()
+                    .filter_map(|level| level.source_path.clone())
+                    .collect();
+                unique_level_filename(&self.levels[index].name, levels_dir, &taken)
+            }
+        };
+
+        let path = self.levels[index].save_to_directory(
+            &levels_dir.to_string_lossy(),
+            &relative_path.to_string_lossy(),
+        )?;
+        self.levels[index].source_path = Some(relative_path);
+        self.mark_saved(index);
+        Ok(path)
+    }
+}
+
+/// System saving the current level on Ctrl+S (Cmd+S on macOS)
+///
+/// The save bookkeeping bypasses change detection, so saving is not mistaken
+/// for a level edit or switch.
+pub fn level_save_input_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut levels_resource: ResMut<LevelsResource>,
+    directory_override: Option<Res<LevelDirectoryOverride>>,
+    mut saved: EventWriter<LevelSaved>,
+    mut failed: EventWriter<LevelSaveFailed>,
+) {
+    let modifier_held = keyboard_input.any_pressed(SHORTCUT_MODIFIERS);
+    if !modifier_held || !keyboard_input.just_pressed(KeyCode::KeyS) {
+        return;
+    }
+
+    let levels_dir = levels_directory(directory_override.as_deref());
+    let level_name = levels_resource.current_level().name.clone();
+    match levels_resource
+        .bypass_change_detection()
+        .save_current_level(&levels_dir)
+    {
+        Ok(path) => {
+            saved.write(LevelSaved { level_name, path });
+        }
+        Err(err) => {
+            warn!("Failed to save level '{level_name}': {err:#}");
+            failed.write(LevelSaveFailed {
+                level_name,
+                message: format!("{err:#}"),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::Level;
+    use crate::level::load_levels_from_directory;
+    use hexx::Hex;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_slugify_level_name() {
+        assert_eq!(slugify_level_name("Desert Ambush"), "desert_ambush");
+        assert_eq!(slugify_level_name("  Level 2 -- Final!  "), "level_2_final");
+        assert_eq!(slugify_level_name("Straße Über"), "straße_über");
+        assert_eq!(slugify_level_name("東京 Tower"), "東京_tower");
+        assert_eq!(slugify_level_name("?!/"), "level");
+        assert_eq!(slugify_level_name(""), "level");
+    }
+
+    #[test]
+    fn test_unique_level_filename_avoids_collisions() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        std::fs::write(temp_dir.path().join("arena.toml"), "").unwrap();
+        let taken = HashSet::from([PathBuf::from("arena_2.toml")]);
+
+        assert_eq!(
+            unique_level_filename("Arena", temp_dir.path(), &taken),
+            PathBuf::from("arena_3.toml")
+        );
+        assert_eq!(
+            unique_level_filename("New Map", temp_dir.path(), &taken),
+            PathBuf::from("new_map.toml")
+        );
+    }
+
+    #[test]
+    fn test_save_current_level() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        Level::new("Arena".to_string(), 3, 3)
+            .save_to_directory(temp_path, "maps/arena.toml")
+            .unwrap();
+        let mut levels = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        levels.levels[0].set_height(Hex::new(1, 1), 6.0).unwrap();
+
+        let path = levels.save_current_level(temp_dir.path()).unwrap();
+        assert_eq!(path, temp_dir.path().join("maps/arena.toml"));
+        assert!(!levels.is_modified(0));
+
+        let index = levels.add_level(Level::new("Arena".to_string(), 4, 4));
+        levels.set_current_index(index);
+        let path = levels.save_current_level(temp_dir.path()).unwrap();
+        assert_eq!(path, temp_dir.path().join("arena.toml"));
+        assert_eq!(
+            levels.current_level().source_path.as_deref(),
+            Some(Path::new("arena.toml"))
+        );
+
+        let reloaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(reloaded.level_count(), 2);
+    }
+
+    #[test]
+    fn test_save_shortcut_keeps_camera_framing() {
+        use crate::rendering::camera::{
+            CameraLimits, CameraRotationState, CameraZoomState, RotationMode,
+            on_level_change_system, setup_camera,
+        };
+
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<CameraLimits>()
+            .init_resource::<CameraZoomState>()
+            .init_resource::<CameraRotationState>()
+            .init_resource::<crate::level::render_config::HexGridRenderConfig>()
+            .insert_resource(LevelDirectoryOverride(temp_dir.path().to_path_buf()))
+            .insert_resource(LevelsResource::new(vec![Level::new(
+                "Arena".to_string(),
+                4,
+                4,
+            )]))
+            .add_event::<LevelSaved>()
+            .add_event::<LevelSaveFailed>()
+            .add_systems(Startup, setup_camera)
+            .add_systems(
+                Update,
+                (level_save_input_system, on_level_change_system).chain(),
+            );
+        app.world_mut().spawn(Window::default());
+        app.update();
+
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(KeyCode::ControlLeft);
+        keyboard.press(KeyCode::KeyS);
+        app.update();
+
+        assert!(temp_dir.path().join("arena.toml").exists());
+        assert!(matches!(
+            app.world().resource::<CameraRotationState>().rotation_mode,
+            RotationMode::Stable
+        ));
+    }
+}
@@ -0,0 +1,120 @@
+//! Slope Queries
+//!
+//! Height differences between adjacent hexes, used for movement rules and for
+//! picking out cliff edges that deserve different rendering.
+
+use hexx::Hex;
+
+use super::Level;
+
+impl Level {
+    /// Get the height difference `b - a` between two adjacent hexes
+    ///
+    /// Returns `None` when the hexes are not neighbors or either lies outside the grid.
+    pub fn height_difference(&self, a: Hex, b: Hex) -> Option<f32> {
+        if a.unsigned_distance_to(b) != 1 {
+            return None;
+        }
+        Some(self.try_get_height(b)? - self.try_get_height(a)?)
+    }
+
+    /// Get the largest absolute height difference between `hex` and any neighbor
+    ///
+    /// Returns 0.0 for hexes without in-bounds neighbors and for hexes outside the grid.
+    pub fn max_slope_at(&self, hex: Hex) -> f32 {
+        let Some(height) = self.try_get_height(hex) else {
+            return 0.0;
+        };
+        self.neighbors_with_heights(hex)
+            .into_iter()
+            .map(|(_, neighbor_height)| (neighbor_height - height).abs())
+            .fold(0.0, f32::max)
+    }
+
+    /// Iterate over adjacent hex pairs whose absolute height difference exceeds `threshold`
+    ///
+    /// Each edge is yielded once, with the pair ordered by row then column.
+    pub fn steep_edges(&self, threshold: f32) -> impl Iterator<Item = (Hex, Hex)> + '_ {
+        self.hex_iter().flat_map(move |hex| {
+            self.neighbors(hex)
+                .into_iter()
+                .filter(move |neighbor| (neighbor.y, neighbor.x) > (hex.y, hex.x))
+                .filter(move |&neighbor| {
+                    self.height_difference(hex, neighbor)
+                        .is_some_and(|diff| diff.abs() > threshold)
+                })
+                .map(move |neighbor| (hex, neighbor))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+
+    fn cliff_level() -> Level {
+        LevelBuilder::new()
+            .dimensions(4, 4)
+            .flat(1.0)
+            .with_height(Hex::new(1, 1), 3.5)
+            .with_height(Hex::new(2, 1), 1.5)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_height_difference() {
+        let level = cliff_level();
+
+        assert_eq!(
+            level.height_difference(Hex::new(0, 1), Hex::new(1, 1)),
+            Some(2.5)
+        );
+        assert_eq!(
+            level.height_difference(Hex::new(1, 1), Hex::new(0, 1)),
+            Some(-2.5)
+        );
+        assert_eq!(
+            level.height_difference(Hex::new(0, 0), Hex::new(2, 0)),
+            None,
+            "Non-adjacent pairs have no difference"
+        );
+        assert_eq!(
+            level.height_difference(Hex::new(0, 0), Hex::new(0, 0)),
+            None
+        );
+        assert_eq!(
+            level.height_difference(Hex::new(0, 0), Hex::new(-1, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_max_slope_at() {
+        let level = cliff_level();
+
+        assert_eq!(level.max_slope_at(Hex::new(1, 1)), 2.5);
+        assert_eq!(level.max_slope_at(Hex::new(3, 0)), 0.5);
+        assert_eq!(level.max_slope_at(Hex::new(3, 3)), 0.0);
+        assert_eq!(level.max_slope_at(Hex::new(10, 10)), 0.0);
+    }
+
+    #[test]
+    fn test_steep_edges() {
+        let level = cliff_level();
+
+        let edges: Vec<_> = level.steep_edges(1.0).collect();
+        // The 3.5 peak differs by more than 1.0 from all six neighbors
+        assert_eq!(edges.len(), 6);
+        assert!(
+            edges
+                .iter()
+                .all(|&(a, b)| a == Hex::new(1, 1) || b == Hex::new(1, 1))
+        );
+
+        // Lower thresholds also pick up the 1.5 hex's 0.5 steps, each edge once
+        let gentle: Vec<_> = level.steep_edges(0.25).collect();
+        assert_eq!(gentle.len(), 6 + 5);
+    }
+}
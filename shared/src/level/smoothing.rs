@@ -0,0 +1,101 @@
+//! Terrain Smoothing
+//!
+//! Neighbor-average blurring of level heights for cleaning up jagged
+//! procedurally generated or hand-painted terrain.
+
+use hexx::Hex;
+use tracing::info;
+
+use super::Level;
+
+impl Level {
+    /// Blur heights toward the average of their hex neighbors
+    ///
+    /// Each iteration moves every hex `strength` of the way (clamped to
+    /// 0.0..=1.0) toward the mean height of its in-bounds neighbors. Edge hexes
+    /// only average the neighbors that exist, so borders keep their height
+    /// instead of bleeding toward 0.
+    pub fn smooth(&mut self, iterations: u32, strength: f32) {
+        let strength = strength.clamp(0.0, 1.0);
+
+        for _ in 0..iterations {
+            let mut smoothed = self.heights.clone();
+            for ((row, col), value) in smoothed.indexed_iter_mut() {
+                let neighbors = self.neighbors_with_heights(Hex::new(col as i32, row as i32));
+                if neighbors.is_empty() {
+                    continue;
+                }
+                let average = neighbors.iter().map(|(_, height)| height).sum::<f32>()
+                    / neighbors.len() as f32;
+                *value += (average - *value) * strength;
+            }
+            self.heights = smoothed;
+        }
+        self.invalidate_geometry();
+
+        info!(
+            "Smoothed level '{name}' ({iterations} iterations, strength {strength})",
+            name = self.name
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+
+    fn spike_level() -> Level {
+        LevelBuilder::new()
+            .dimensions(7, 7)
+            .flat(1.0)
+            .with_height(Hex::new(3, 3), 8.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_smooth_reduces_spike_and_conserves_total() {
+        let mut level = spike_level();
+        let total_before: f32 = level.heights.iter().sum();
+
+        level.smooth(1, 0.5);
+
+        let spike = level.get_height(Hex::new(3, 3));
+        assert!(spike < 8.0 && spike > 1.0, "Spike height {spike}");
+        assert!(level.get_height(Hex::new(4, 3)) > 1.0);
+        assert_eq!(level.heights.shape(), &[7, 7]);
+
+        // An interior spike spreads evenly to six neighbors, so the sum is kept
+        let total_after: f32 = level.heights.iter().sum();
+        assert!(
+            (total_after - total_before).abs() < 0.001,
+            "Total changed from {total_before} to {total_after}"
+        );
+    }
+
+    #[test]
+    fn test_smooth_keeps_flat_edges_stable() {
+        let mut level = LevelBuilder::new()
+            .dimensions(4, 4)
+            .flat(3.0)
+            .build()
+            .unwrap();
+
+        level.smooth(5, 1.0);
+
+        for &height in level.heights.iter() {
+            assert!((height - 3.0).abs() < 0.0001, "Height drifted to {height}");
+        }
+    }
+
+    #[test]
+    fn test_zero_strength_is_a_no_op() {
+        let mut level = spike_level();
+        let original = level.heights.clone();
+
+        level.smooth(3, 0.0);
+
+        assert_eq!(level.heights, original);
+    }
+}
@@ -0,0 +1,180 @@
+//! Level Sort Order
+//!
+//! How loaded levels are ordered for ←/→ cycling, including a natural name
+//! order that compares embedded numbers by value ("Level 2" before "Level 10").
+
+use std::cmp::Ordering;
+
+use bevy::prelude::*;
+
+use super::Level;
+use super::manifest::LevelManifest;
+
+/// Order of loaded levels
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LevelSortMode {
+    /// Plain string order of level names
+    Name,
+    /// Level names with embedded numbers compared by value, ignoring case
+    NaturalName,
+    /// Level file paths relative to the levels directory
+    FileName,
+    /// Smallest grid first, ties broken by name
+    GridSize,
+    /// The level manifest's order, or [`LevelSortMode::Name`] without a manifest
+    #[default]
+    ManifestOrder,
+}
+
+impl LevelSortMode {
+    /// Compare two levels in this order
+    ///
+    /// [`LevelSortMode::ManifestOrder`] compares by name, as used for levels the
+    /// manifest does not list.
+    pub fn compare(self, a: &Level, b: &Level) -> Ordering {
+        match self {
+            Self::Name | Self::ManifestOrder => a.name.cmp(&b.name),
+            Self::NaturalName => natural_cmp(&a.name, &b.name),
+            Self::FileName => match (&a.source_path, &b.source_path) {
+                (Some(a_path), Some(b_path)) => a_path.cmp(b_path),
+                // Levels without a file go last
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            }
+            .then_with(|| a.name.cmp(&b.name)),
+            Self::GridSize => (a.width * a.height)
+                .cmp(&(b.width * b.height))
+                .then_with(|| a.name.cmp(&b.name)),
+        }
+    }
+}
+
+/// Compare strings treating runs of ASCII digits as numbers
+///
+/// Text runs compare case-insensitively; fully equal-looking strings fall back
+/// to plain string order so the result is total.
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chunks = Chunks(a);
+    let mut b_chunks = Chunks(b);
+    loop {
+        let ordering = match (a_chunks.next(), b_chunks.next()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_chunk), Some(b_chunk)) => compare_chunks(a_chunk, b_chunk),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+fn compare_chunks(a: &str, b: &str) -> Ordering {
+    let is_number = |chunk: &str| chunk.starts_with(|c: char| c.is_ascii_digit());
+    if is_number(a) && is_number(b) {
+        // Compare by value without parsing, so arbitrarily long numbers work
+        let a_digits = a.trim_start_matches('0');
+        let b_digits = b.trim_start_matches('0');
+        return a_digits
+            .len()
+            .cmp(&b_digits.len())
+            .then_with(|| a_digits.cmp(b_digits));
+    }
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .cmp(b.chars().flat_map(char::to_lowercase))
+}
+
+/// Splits a string into alternating digit and non-digit runs
+struct Chunks<'a>(&'a str);
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let first = self.0.chars().next()?;
+        let digits = first.is_ascii_digit();
+        let end = self
+            .0
+            .find(|c: char| c.is_ascii_digit() != digits)
+            .unwrap_or(self.0.len());
+        let (chunk, rest) = self.0.split_at(end);
+        self.0 = rest;
+        Some(chunk)
+    }
+}
+
+/// Order loaded levels by `sort_mode`
+///
+/// A manifest always filters levels; its order is only used with
+/// [`LevelSortMode::ManifestOrder`].
+pub(crate) fn order_levels(
+    mut levels: Vec<Level>,
+    manifest: Option<LevelManifest>,
+    sort_mode: LevelSortMode,
+) -> Vec<Level> {
+    if let Some(manifest) = manifest {
+        levels = manifest.apply(levels);
+        if sort_mode == LevelSortMode::ManifestOrder {
+            return levels;
+        }
+    }
+    levels.sort_by(|a, b| sort_mode.compare(a, b));
+    levels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_sort_orders_numbers_by_value() {
+        let mut names = vec!["Level 10", "level 2", "Level 1", "Level 02b", "Arena"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            names,
+            ["Arena", "Level 1", "level 2", "Level 02b", "Level 10"]
+        );
+
+        assert_eq!(natural_cmp("Level 2", "Level 10"), Ordering::Less);
+        assert_eq!(natural_cmp("Level 007", "Level 7"), Ordering::Less);
+        assert_eq!(natural_cmp("Map", "Map 1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_sort_modes() {
+        let mut small = Level::new("Level 10".to_string(), 3, 3);
+        small.source_path = Some("b.toml".into());
+        let mut large = Level::new("Level 2".to_string(), 8, 8);
+        large.source_path = Some("a.toml".into());
+        let mut medium = Level::new("Arena".to_string(), 5, 5);
+        medium.source_path = Some("c.toml".into());
+        let levels = vec![small, large, medium];
+
+        let names = |mode| {
+            order_levels(levels.clone(), None, mode)
+                .into_iter()
+                .map(|level| level.name)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(LevelSortMode::Name), ["Arena", "Level 10", "Level 2"]);
+        assert_eq!(
+            names(LevelSortMode::NaturalName),
+            ["Arena", "Level 2", "Level 10"]
+        );
+        assert_eq!(
+            names(LevelSortMode::FileName),
+            ["Level 2", "Level 10", "Arena"]
+        );
+        assert_eq!(
+            names(LevelSortMode::GridSize),
+            ["Level 10", "Arena", "Level 2"]
+        );
+        assert_eq!(
+            names(LevelSortMode::ManifestOrder),
+            names(LevelSortMode::Name),
+            "Without a manifest the default keeps sorting by name"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Spawn Points
+//!
+//! Team starting positions stored in level files so tactical scenarios can place
+//! units without hardcoding coordinates.
+
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+
+use super::Level;
+
+/// A starting position for a unit belonging to `team`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpawnPoint {
+    /// Axial hex coordinate as (q, r)
+    pub hex: (i32, i32),
+    /// Team identifier, e.g. "player" or "enemy"
+    pub team: String,
+}
+
+impl SpawnPoint {
+    /// Create a spawn point for `team` at `hex`
+    pub fn new(hex: Hex, team: impl Into<String>) -> Self {
+        Self {
+            hex: (hex.x, hex.y),
+            team: team.into(),
+        }
+    }
+
+    /// Get the spawn coordinate as a [`Hex`]
+    pub fn coord(&self) -> Hex {
+        Hex::new(self.hex.0, self.hex.1)
+    }
+}
+
+impl Level {
+    /// Get the spawn hexes assigned to `team`, in file order
+    pub fn spawn_points_for_team(&self, team: &str) -> Vec<Hex> {
+        self.spawn_points
+            .iter()
+            .filter(|spawn| spawn.team == team)
+            .map(SpawnPoint::coord)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_spawn_points_for_team() {
+        let mut level = LevelBuilder::new().dimensions(5, 5).build().unwrap();
+        level.spawn_points = vec![
+            SpawnPoint::new(Hex::new(0, 0), "player"),
+            SpawnPoint::new(Hex::new(4, 4), "enemy"),
+            SpawnPoint::new(Hex::new(1, 0), "player"),
+        ];
+
+        assert_eq!(
+            level.spawn_points_for_team("player"),
+            vec![Hex::new(0, 0), Hex::new(1, 0)]
+        );
+        assert_eq!(level.spawn_points_for_team("enemy"), vec![Hex::new(4, 4)]);
+        assert!(level.spawn_points_for_team("neutral").is_empty());
+    }
+
+    #[test]
+    fn test_spawn_points_roundtrip_and_out_of_bounds_dropped() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let mut level = LevelBuilder::new()
+            .name("Skirmish")
+            .dimensions(4, 4)
+            .build()
+            .unwrap();
+        level.spawn_points = vec![
+            SpawnPoint::new(Hex::new(0, 1), "player"),
+            SpawnPoint::new(Hex::new(9, 9), "enemy"),
+            SpawnPoint::new(Hex::new(3, 2), "enemy"),
+        ];
+        level
+            .save_to_directory(temp_path, "skirmish.toml")
+            .expect("Failed to save level");
+
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        let loaded = loaded.current_level();
+
+        assert_eq!(
+            loaded.spawn_points,
+            vec![
+                SpawnPoint::new(Hex::new(0, 1), "player"),
+                SpawnPoint::new(Hex::new(3, 2), "enemy"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Level Statistics
+//!
+//! Summary figures over a level's height field for the editor's properties
+//! panel and command-line validation output.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use super::Level;
+
+/// Summary statistics over a level's heights, produced by [`Level::stats`]
+///
+/// Per-terrain-type counts will join these once levels carry terrain types.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct LevelStats {
+    /// Number of hexes in the grid
+    pub hex_count: usize,
+    /// Lowest column height
+    pub min_height: f32,
+    /// Highest column height
+    pub max_height: f32,
+    /// Mean column height
+    pub mean_height: f32,
+    /// Population variance of column heights
+    pub height_variance: f32,
+}
+
+impl fmt::Display for LevelStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{count} hexes, height {min:.2}..{max:.2} (mean {mean:.2}, variance {variance:.2})",
+            count = self.hex_count,
+            min = self.min_height,
+            max = self.max_height,
+            mean = self.mean_height,
+            variance = self.height_variance
+        )
+    }
+}
+
+impl Level {
+    /// Compute summary statistics over this level's heights
+    ///
+    /// An empty grid reports zero for every figure.
+    pub fn stats(&self) -> LevelStats {
+        let hex_count = self.heights.len();
+        if hex_count == 0 {
+            return LevelStats {
+                hex_count,
+                min_height: 0.0,
+                max_height: 0.0,
+                mean_height: 0.0,
+                height_variance: 0.0,
+            };
+        }
+
+        // Accumulate in f64 so large maps do not lose precision in the sums
+        let (min, max, sum) = self.heights.iter().fold(
+            (f32::INFINITY, f32::NEG_INFINITY, 0.0_f64),
+            |(min, max, sum), &height| (min.min(height), max.max(height), sum + f64::from(height)),
+        );
+        let mean = sum / hex_count as f64;
+        let variance = self
+            .heights
+            .iter()
+            .map(|&height| (f64::from(height) - mean).powi(2))
+            .sum::<f64>()
+            / hex_count as f64;
+
+        LevelStats {
+            hex_count,
+            min_height: min,
+            max_height: max,
+            mean_height: mean as f32,
+            height_variance: variance as f32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+
+    #[test]
+    fn test_stats_over_default_gradient() {
+        let stats = Level::new("x".to_string(), 10, 10).stats();
+
+        assert_eq!(stats.hex_count, 100);
+        assert!((stats.min_height - 1.0).abs() < 0.0001);
+        assert!((stats.max_height - 4.0).abs() < 0.0001);
+        assert!((stats.mean_height - 2.5).abs() < 0.0001);
+        assert!(stats.height_variance > 0.0);
+        assert_eq!(
+            stats.to_string(),
+            format!(
+                "100 hexes, height 1.00..4.00 (mean 2.50, variance {variance:.2})",
+                variance = stats.height_variance
+            )
+        );
+    }
+
+    #[test]
+    fn test_flat_level_has_zero_variance() {
+        let level = LevelBuilder::new()
+            .dimensions(4, 3)
+            .flat(2.0)
+            .build()
+            .unwrap();
+        let stats = level.stats();
+
+        assert_eq!(stats.hex_count, 12);
+        assert_eq!(stats.min_height, 2.0);
+        assert_eq!(stats.max_height, 2.0);
+        assert_eq!(stats.height_variance, 0.0);
+    }
+
+    #[test]
+    fn test_stats_serialize_to_toml() {
+        let stats = Level::new("x".to_string(), 10, 10).stats();
+        let text = toml::to_string(&stats).expect("Failed to serialize stats");
+
+        assert!(text.contains("hex_count = 100"), "Serialized: {text}");
+        assert!(text.contains("min_height = 1"), "Serialized: {text}");
+    }
+}
@@ -0,0 +1,96 @@
+//! Hex Tags
+//!
+//! Free-form designer annotations ("treasure", "objective", "trap") attached to
+//! hexes, giving the editor and gameplay systems a shared extension point
+//! without the crate hardcoding every concept.
+
+use hexx::Hex;
+
+use super::Level;
+
+impl Level {
+    /// Get every hex carrying `tag`, in file order
+    pub fn hexes_with_tag(&self, tag: &str) -> Vec<Hex> {
+        self.tags
+            .get(tag)
+            .map(|coords| coords.iter().map(|&(q, r)| Hex::new(q, r)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Get the names of all tags attached to `hex`, sorted alphabetically
+    pub fn tags_at(&self, hex: Hex) -> Vec<&str> {
+        self.tags
+            .iter()
+            .filter(|(_, coords)| coords.contains(&(hex.x, hex.y)))
+            .map(|(tag, _)| tag.as_str())
+            .collect()
+    }
+
+    /// Attach `tag` to `hex`, ignoring duplicates
+    pub fn add_tag(&mut self, hex: Hex, tag: impl Into<String>) {
+        let coords = self.tags.entry(tag.into()).or_default();
+        if !coords.contains(&(hex.x, hex.y)) {
+            coords.push((hex.x, hex.y));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use tempfile::TempDir;
+
+    fn tagged_level() -> Level {
+        let mut level = LevelBuilder::new()
+            .name("Tagged")
+            .dimensions(5, 5)
+            .build()
+            .unwrap();
+        level.add_tag(Hex::new(1, 1), "treasure");
+        level.add_tag(Hex::new(3, 2), "treasure");
+        level.add_tag(Hex::new(3, 2), "objective");
+        level.add_tag(Hex::new(0, 4), "trap");
+        level.add_tag(Hex::new(0, 4), "trap");
+        level
+    }
+
+    #[test]
+    fn test_tag_queries() {
+        let level = tagged_level();
+
+        assert_eq!(
+            level.hexes_with_tag("treasure"),
+            vec![Hex::new(1, 1), Hex::new(3, 2)]
+        );
+        assert_eq!(level.hexes_with_tag("trap"), vec![Hex::new(0, 4)]);
+        assert!(level.hexes_with_tag("missing").is_empty());
+
+        assert_eq!(level.tags_at(Hex::new(3, 2)), vec!["objective", "treasure"]);
+        assert!(level.tags_at(Hex::new(2, 2)).is_empty());
+    }
+
+    #[test]
+    fn test_tags_roundtrip_and_out_of_bounds_dropped() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let level = tagged_level();
+        level
+            .save_to_directory(temp_path, "tagged.toml")
+            .expect("Failed to save level");
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(loaded.current_level().tags, level.tags);
+
+        // Hand-edited coordinates outside the grid are dropped on load
+        let mut corrupted = level.clone();
+        corrupted.add_tag(Hex::new(7, 0), "treasure");
+        corrupted.add_tag(Hex::new(-1, 2), "ghost");
+        corrupted
+            .save_to_directory(temp_path, "tagged.toml")
+            .expect("Failed to save level");
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(loaded.current_level().tags, level.tags);
+    }
+}
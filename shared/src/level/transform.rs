@@ -0,0 +1,589 @@
+//! Level Transform Operations
+//!
+//! Grid-reshaping operations on level data such as resizing, mirroring, and
+//! rotation, used by the level editor to rework existing maps without
+//! hand-editing TOML.
+
+use anyhow::{Context, Result, bail};
+use ndarray::{Array2, Axis, concatenate};
+use tracing::info;
+
+use super::Level;
+
+/// Which part of the existing grid stays fixed when a level is resized
+///
+/// "Top" refers to row 0 and "left" to column 0 of the height grid. Rows and
+/// columns are added or cropped on the opposite side of the anchor; `Center`
+/// splits the change between both sides (the extra odd row/column goes to the end).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizeAnchor {
+    #[default]
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Center,
+}
+
+impl ResizeAnchor {
+    /// Offset of the old grid's origin inside the new grid, as (row, col)
+    fn offsets(self, old_shape: (i32, i32), new_shape: (i32, i32)) -> (i32, i32) {
+        let row_diff = new_shape.0 - old_shape.0;
+        let col_diff = new_shape.1 - old_shape.1;
+        match self {
+            ResizeAnchor::TopLeft => (0, 0),
+            ResizeAnchor::TopRight => (0, col_diff),
+            ResizeAnchor::BottomLeft => (row_diff, 0),
+            ResizeAnchor::BottomRight => (row_diff, col_diff),
+            ResizeAnchor::Center => (row_diff / 2, col_diff / 2),
+        }
+    }
+}
+
+/// Direction along which [`Level::stitch`] joins two levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StitchAxis {
+    /// Place the second level to the right (columns are appended; heights must match)
+    Horizontal,
+    /// Place the second level below (rows are appended; widths must match)
+    Vertical,
+}
+
+/// Copy `grid` into a new grid of `new_shape`, placing the old origin at `offset`
+///
+/// Cells not covered by the old grid are filled with `fill`; old cells that fall
+/// outside the new shape are dropped.
+fn reframe_grid<T: Clone>(
+    grid: &Array2<T>,
+    new_shape: (usize, usize),
+    offset: (i32, i32),
+    fill: T,
+) -> Array2<T> {
+    let mut reframed = Array2::from_elem(new_shape, fill);
+    for ((row, col), value) in grid.indexed_iter() {
+        let new_row = row as i32 + offset.0;
+        let new_col = col as i32 + offset.1;
+        if new_row >= 0
+            && new_col >= 0
+            && (new_row as usize) < new_shape.0
+            && (new_col as usize) < new_shape.1
+        {
+            reframed[(new_row as usize, new_col as usize)] = value.clone();
+        }
+    }
+    reframed
+}
+
+/// Reverse `grid` along `axis`, returning a standard-layout copy
+fn flip_grid<T: Clone>(grid: &Array2<T>, axis: Axis) -> Array2<T> {
+    let mut flipped = grid.view();
+    flipped.invert_axis(axis);
+    flipped.as_standard_layout().into_owned()
+}
+
+/// Rotate `grid` by 90 degrees, returning a standard-layout copy
+///
+/// With row 0 drawn at the top, clockwise moves the top-left cell to the top-right.
+fn rotate_grid<T: Clone>(grid: &Array2<T>, clockwise: bool) -> Array2<T> {
+    let mut rotated = grid.t();
+    // Transposing then reversing columns rotates clockwise; reversing rows rotates
+    // counter-clockwise
+    rotated.invert_axis(if clockwise { Axis(1) } else { Axis(0) });
+    rotated.as_standard_layout().into_owned()
+}
+
+impl Level {
+    /// Apply `flip_grid` along `axis` to every per-hex grid stored on this level
+    fn flip_all_grids(&mut self, axis: Axis) {
+        self.heights = flip_grid(&self.heights, axis);
+        self.walkable = flip_grid(&self.walkable, axis);
+        self.invalidate_geometry();
+
+        let (width, height) = (self.width, self.height);
+        if axis == Axis(1) {
+            self.remap_coordinates(|(q, r)| (width - 1 - q, r));
+        } else {
+            self.remap_coordinates(|(q, r)| (q, height - 1 - r));
+        }
+    }
+
+    /// Move every coordinate annotation (spawn points etc.) through `map`
+    ///
+    /// Grid operations call this so annotations keep pointing at the same terrain.
+    fn remap_coordinates(&mut self, map: impl Fn((i32, i32)) -> (i32, i32)) {
+        for spawn in &mut self.spawn_points {
+            spawn.hex = map(spawn.hex);
+        }
+        let region_hexes = self.regions.iter_mut().flat_map(|region| &mut region.hexes);
+        let ramp_ends = self
+            .ramps
+            .iter_mut()
+            .flat_map(|ramp| [&mut ramp.from, &mut ramp.to]);
+        let trigger_hexes = self.triggers.iter_mut().map(|trigger| &mut trigger.hex);
+        let overlay_hexes = self.overlays.iter_mut().map(|overlay| &mut overlay.hex);
+        for coord in self
+            .tags
+            .values_mut()
+            .flatten()
+            .chain(region_hexes)
+            .chain(ramp_ends)
+            .chain(trigger_hexes)
+            .chain(overlay_hexes)
+        {
+            *coord = map(*coord);
+        }
+    }
+
+    /// Mirror the level left-to-right in place (column `q` swaps with `width - 1 - q`)
+    ///
+    /// This flips the stored grid, not the world-space hex layout, so the result is
+    /// the same rhombus with its columns reversed.
+    pub fn mirror_horizontal(&mut self) {
+        self.flip_all_grids(Axis(1));
+    }
+
+    /// Mirror the level front-to-back in place (row `r` swaps with `height - 1 - r`)
+    pub fn mirror_vertical(&mut self) {
+        self.flip_all_grids(Axis(0));
+    }
+
+    /// Return a copy of this level mirrored left-to-right
+    pub fn mirrored_horizontal(&self) -> Level {
+        let mut mirrored = self.clone();
+        mirrored.mirror_horizontal();
+        mirrored
+    }
+
+    /// Return a copy of this level mirrored front-to-back
+    pub fn mirrored_vertical(&self) -> Level {
+        let mut mirrored = self.clone();
+        mirrored.mirror_vertical();
+        mirrored
+    }
+
+    /// Rotate every per-hex grid by 90 degrees and swap the level dimensions
+    fn rotate_all_grids(&mut self, clockwise: bool) {
+        self.heights = rotate_grid(&self.heights, clockwise);
+        self.walkable = rotate_grid(&self.walkable, clockwise);
+        self.invalidate_geometry();
+
+        let (width, height) = (self.width, self.height);
+        if clockwise {
+            self.remap_coordinates(|(q, r)| (height - 1 - r, q));
+        } else {
+            self.remap_coordinates(|(q, r)| (r, width - 1 - q));
+        }
+        std::mem::swap(&mut self.width, &mut self.height);
+    }
+
+    /// Rotate the level grid 90 degrees clockwise, swapping `width` and `height`
+    ///
+    /// Like mirroring, this operates on the stored grid (row 0 at the top), so the
+    /// top-left hex ends up at the top-right.
+    pub fn rotate_cw(&mut self) {
+        self.rotate_all_grids(true);
+    }
+
+    /// Rotate the level grid 90 degrees counter-clockwise, swapping `width` and `height`
+    pub fn rotate_ccw(&mut self) {
+        self.rotate_all_grids(false);
+    }
+
+    /// Grow or crop this level to `new_width` x `new_height`
+    ///
+    /// Existing height data is preserved relative to `anchor`, new cells are set to
+    /// `fill_height` (and walkable), and cells outside the new bounds are cropped.
+    pub fn resize(
+        &mut self,
+        new_width: i32,
+        new_height: i32,
+        anchor: ResizeAnchor,
+        fill_height: f32,
+    ) -> Result<()> {
+        if new_width <= 0 || new_height <= 0 {
+            bail!(
+                "Cannot resize level '{name}' to {new_width}x{new_height}: dimensions must be positive",
+                name = self.name
+            );
+        }
+        if !fill_height.is_finite() {
+            bail!(
+                "Cannot resize level '{name}': fill height {fill_height} is not finite",
+                name = self.name
+            );
+        }
+
+        let new_shape = (new_height as usize, new_width as usize);
+        let offset = anchor.offsets((self.height, self.width), (new_height, new_width));
+
+        self.heights = reframe_grid(&self.heights, new_shape, offset, fill_height);
+        // Levels without a walkability grid treat every hex as walkable already
+        if !self.walkable.is_empty() {
+            self.walkable = reframe_grid(&self.walkable, new_shape, offset, true);
+        }
+
+        info!(
+            "Resized level '{name}' from {old_width}x{old_height} to {new_width}x{new_height} ({anchor:?})",
+            name = self.name,
+            old_width = self.width,
+            old_height = self.height
+        );
+        self.width = new_width;
+        self.height = new_height;
+        self.invalidate_geometry();
+
+        // Annotations on cropped hexes have nothing left to point at
+        self.remap_coordinates(|(q, r)| (q + offset.1, r + offset.0));
+        self.prune_out_of_bounds();
+        Ok(())
+    }
+
+    /// Join two levels into one, placing `second` after `first` along `axis`
+    ///
+    /// The levels must have the same size along the shared edge. Annotations from
+    /// `second` are offset to its new position, tags and same-named regions are
+    /// merged, and all other settings (layout, lighting, water) come from `first`.
+    pub fn stitch(first: &Level, second: &Level, axis: StitchAxis) -> Result<Level> {
+        let (grid_axis, offset) = match axis {
+            StitchAxis::Horizontal => {
+                if first.height != second.height {
+                    bail!(
+                        "Cannot stitch '{first_name}' and '{second_name}' horizontally: heights {first_height} and {second_height} differ",
+                        first_name = first.name,
+                        second_name = second.name,
+                        first_height = first.height,
+                        second_height = second.height
+                    );
+                }
+                (Axis(1), (first.width, 0))
+            }
+            StitchAxis::Vertical => {
+                if first.width != second.width {
+                    bail!(
+                        "Cannot stitch '{first_name}' and '{second_name}' vertically: widths {first_width} and {second_width} differ",
+                        first_name = first.name,
+                        second_name = second.name,
+                        first_width = first.width,
+                        second_width = second.width
+                    );
+                }
+                (Axis(0), (0, first.height))
+            }
+        };
+
+        let mut stitched = first.clone();
+        stitched.name = format!(
+            "{first_name} + {second_name}",
+            first_name = first.name,
+            second_name = second.name
+        );
+        stitched.heights = concatenate(grid_axis, &[first.heights.view(), second.heights.view()])
+            .context("Height grids do not match the level dimensions")?;
+        // Expand implicit (empty) walkability so both halves line up
+        let full_walkable = |level: &Level| {
+            if level.walkable.is_empty() {
+                Array2::from_elem(level.heights.raw_dim(), true)
+            } else {
+                level.walkable.clone()
+            }
+        };
+        stitched.walkable = concatenate(
+            grid_axis,
+            &[full_walkable(first).view(), full_walkable(second).view()],
+        )
+        .context("Walkability grids do not match the level dimensions")?;
+        stitched.width = stitched.heights.ncols() as i32;
+        stitched.height = stitched.heights.nrows() as i32;
+        stitched.invalidate_geometry();
+
+        let mut moved = second.clone();
+        moved.remap_coordinates(|(q, r)| (q + offset.0, r + offset.1));
+        stitched.spawn_points.extend(moved.spawn_points);
+        for (tag, coords) in moved.tags {
+            stitched.tags.entry(tag).or_default().extend(coords);
+        }
+        for region in moved.regions {
+            match stitched.regions.iter_mut().find(|r| r.name == region.name) {
+                Some(existing) => existing.hexes.extend(region.hexes),
+                None => stitched.regions.push(region),
+            }
+        }
+        stitched.ramps.extend(moved.ramps);
+        stitched.triggers.extend(moved.triggers);
+        stitched.overlays.extend(moved.overlays);
+
+        info!(
+            "Stitched '{first_name}' and '{second_name}' ({axis:?}) into {width}x{height}",
+            first_name = first.name,
+            second_name = second.name,
+            width = stitched.width,
+            height = stitched.height
+        );
+        Ok(stitched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::spawn::SpawnPoint;
+    use hexx::Hex;
+
+    /// Level whose height encodes the original (row, col) as row * 10 + col
+    fn indexed_level(width: i32, height: i32) -> Level {
+        let mut level = Level::new("Indexed".to_string(), width, height);
+        for ((row, col), value) in level.heights.indexed_iter_mut() {
+            *value = (row * 10 + col) as f32;
+        }
+        level
+    }
+
+    #[test]
+    fn test_mirror_twice_returns_original() {
+        for (width, height) in [(4, 4), (5, 3), (3, 6)] {
+            let original = indexed_level(width, height);
+
+            let mut level = original.clone();
+            level.mirror_horizontal();
+            level.mirror_horizontal();
+            assert_eq!(level.heights, original.heights);
+
+            level.mirror_vertical();
+            level.mirror_vertical();
+            assert_eq!(level.heights, original.heights);
+            assert_eq!((level.width, level.height), (width, height));
+        }
+    }
+
+    #[test]
+    fn test_mirror_flips_gradient() {
+        // Gradient rises along both axes: (0,0) = 1.0, (4,0) = 2.5, (0,4) = 2.5
+        let level = Level::new("Gradient".to_string(), 5, 5);
+
+        let horizontal = level.mirrored_horizontal();
+        assert_eq!(horizontal.heights[(0, 0)], level.heights[(0, 4)]);
+        assert_eq!(horizontal.heights[(2, 1)], level.heights[(2, 3)]);
+        assert_eq!(horizontal.heights[(2, 2)], level.heights[(2, 2)]);
+
+        let vertical = level.mirrored_vertical();
+        assert_eq!(vertical.heights[(0, 0)], level.heights[(4, 0)]);
+        assert_eq!(vertical.heights[(1, 3)], level.heights[(3, 3)]);
+        assert_eq!(vertical.heights[(2, 2)], level.heights[(2, 2)]);
+    }
+
+    #[test]
+    fn test_mirror_flips_walkability_with_heights() {
+        let mut level = indexed_level(4, 3);
+        level.set_walkable(Hex::new(0, 0), false).unwrap();
+
+        level.mirror_horizontal();
+        assert!(!level.is_walkable(Hex::new(3, 0)));
+        level.mirror_vertical();
+        assert!(!level.is_walkable(Hex::new(3, 2)));
+        assert!(level.is_walkable(Hex::new(0, 0)));
+    }
+
+    #[test]
+    fn test_rotate_cw_moves_cells_predictably() {
+        // 3 columns x 5 rows
+        let mut level = indexed_level(3, 5);
+        level.set_walkable(Hex::new(0, 0), false).unwrap();
+        level.rotate_cw();
+
+        assert_eq!((level.width, level.height), (5, 3));
+        assert_eq!(level.heights.shape(), &[3, 5]);
+        assert_eq!(level.walkable.shape(), &[3, 5]);
+        // Top-left -> top-right, bottom-left -> top-left, top-right -> bottom-right
+        assert_eq!(level.heights[(0, 4)], 0.0);
+        assert_eq!(level.heights[(0, 0)], 40.0);
+        assert_eq!(level.heights[(2, 4)], 2.0);
+        assert_eq!(level.heights[(1, 2)], 21.0);
+        assert!(!level.is_walkable(Hex::new(4, 0)));
+    }
+
+    #[test]
+    fn test_rotate_ccw_moves_cells_predictably() {
+        let mut level = indexed_level(3, 5);
+        level.rotate_ccw();
+
+        assert_eq!((level.width, level.height), (5, 3));
+        // Top-left -> bottom-left, top-right -> top-left
+        assert_eq!(level.heights[(2, 0)], 0.0);
+        assert_eq!(level.heights[(0, 0)], 2.0);
+        assert_eq!(level.heights[(0, 4)], 42.0);
+    }
+
+    #[test]
+    fn test_four_rotations_return_original() {
+        let original = indexed_level(3, 5);
+
+        let mut clockwise = original.clone();
+        let mut counter_clockwise = original.clone();
+        for _ in 0..4 {
+            clockwise.rotate_cw();
+            counter_clockwise.rotate_ccw();
+        }
+        assert_eq!(clockwise.heights, original.heights);
+        assert_eq!(counter_clockwise.heights, original.heights);
+        assert_eq!((clockwise.width, clockwise.height), (3, 5));
+
+        let mut round_trip = original.clone();
+        round_trip.rotate_cw();
+        round_trip.rotate_ccw();
+        assert_eq!(round_trip.heights, original.heights);
+    }
+
+    #[test]
+    fn test_resize_grow_top_left_pads_bottom_right() {
+        let mut level = indexed_level(3, 2);
+        level
+            .resize(5, 4, ResizeAnchor::TopLeft, -1.0)
+            .expect("Grow should succeed");
+
+        assert_eq!((level.width, level.height), (5, 4));
+        assert_eq!(level.heights.shape(), &[4, 5]);
+        assert_eq!(level.walkable.shape(), &[4, 5]);
+        assert_eq!(level.heights[(0, 0)], 0.0);
+        assert_eq!(level.heights[(1, 2)], 12.0);
+        assert_eq!(level.heights[(0, 3)], -1.0, "New column is padded");
+        assert_eq!(level.heights[(3, 0)], -1.0, "New row is padded");
+        assert!(
+            level.is_walkable(Hex::new(4, 3)),
+            "Padded hexes are walkable"
+        );
+    }
+
+    #[test]
+    fn test_resize_grow_bottom_right_pads_top_left() {
+        let mut level = indexed_level(3, 2);
+        level
+            .resize(4, 3, ResizeAnchor::BottomRight, 0.5)
+            .expect("Grow should succeed");
+
+        assert_eq!(level.heights[(0, 0)], 0.5);
+        assert_eq!(
+            level.heights[(1, 1)],
+            0.0,
+            "Old origin moved by one row/col"
+        );
+        assert_eq!(level.heights[(2, 3)], 12.0);
+    }
+
+    #[test]
+    fn test_resize_shrink_crops() {
+        let mut level = indexed_level(5, 5);
+        level
+            .resize(3, 2, ResizeAnchor::Center, 0.0)
+            .expect("Shrink should succeed");
+
+        assert_eq!(level.heights.shape(), &[2, 3]);
+        // Center crop removes 1 row from the top and 1 column from the left
+        assert_eq!(level.heights[(0, 0)], 11.0);
+        assert_eq!(level.heights[(1, 2)], 23.0);
+    }
+
+    #[test]
+    fn test_resize_rejects_invalid_dimensions() {
+        let mut level = indexed_level(3, 3);
+        assert!(level.resize(0, 3, ResizeAnchor::TopLeft, 0.0).is_err());
+        assert!(level.resize(3, -2, ResizeAnchor::TopLeft, 0.0).is_err());
+        assert!(level.resize(3, 3, ResizeAnchor::TopLeft, f32::NAN).is_err());
+        assert_eq!(level.heights.shape(), &[3, 3], "Failed resize keeps data");
+    }
+
+    #[test]
+    fn test_resize_keeps_center_hexes_and_bounds_consistent() {
+        let mut level = indexed_level(3, 3);
+
+        // Odd x Odd -> Even x Even
+        level.resize(4, 4, ResizeAnchor::TopLeft, 1.0).unwrap();
+        assert_eq!(level.get_center_hexes().len(), 4);
+        for hex in level.get_center_hexes() {
+            assert!(level.contains(hex), "Center hex {hex:?} must be in bounds");
+        }
+
+        // Even x Even -> Even x Odd
+        level.resize(4, 5, ResizeAnchor::TopLeft, 1.0).unwrap();
+        assert_eq!(
+            level.get_center_hexes(),
+            vec![Hex::new(1, 2), Hex::new(2, 2)]
+        );
+
+        // Bounds cover the full new grid
+        let (min_bounds, max_bounds) = level.get_world_bounds();
+        let layout = level.hex_layout();
+        let far_corner = layout.hex_to_world_pos(Hex::new(3, 4));
+        assert!(max_bounds.z >= far_corner.y - 0.001);
+        assert!(min_bounds.x <= layout.hex_to_world_pos(Hex::new(0, 4)).x + 0.001);
+    }
+
+    #[test]
+    fn test_transforms_keep_spawn_points_on_their_hex() {
+        let mut level = indexed_level(4, 3);
+        level.spawn_points = vec![SpawnPoint::new(Hex::new(1, 2), "player")];
+        let height_at_spawn = |level: &Level| level.get_height(level.spawn_points[0].coord());
+        let original = height_at_spawn(&level);
+
+        level.mirror_horizontal();
+        assert_eq!(height_at_spawn(&level), original);
+        level.mirror_vertical();
+        assert_eq!(height_at_spawn(&level), original);
+        level.rotate_cw();
+        assert_eq!(height_at_spawn(&level), original);
+        level.rotate_ccw();
+        level.rotate_ccw();
+        assert_eq!(height_at_spawn(&level), original);
+
+        level.resize(6, 6, ResizeAnchor::BottomRight, 0.0).unwrap();
+        assert_eq!(height_at_spawn(&level), original);
+
+        // Cropping away the spawn hex drops the spawn point
+        level.resize(1, 1, ResizeAnchor::TopLeft, 0.0).unwrap();
+        assert!(level.spawn_points.is_empty());
+    }
+
+    #[test]
+    fn test_stitch_horizontal_places_second_level_at_offset_columns() {
+        let left = indexed_level(3, 2);
+        let mut right = indexed_level(2, 2);
+        right.heights.mapv_inplace(|height| height + 50.0);
+        right.spawn_points = vec![SpawnPoint::new(Hex::new(1, 1), "enemy")];
+        right.add_tag(Hex::new(0, 0), "cover");
+
+        let stitched = Level::stitch(&left, &right, StitchAxis::Horizontal).unwrap();
+
+        assert_eq!((stitched.width, stitched.height), (5, 2));
+        assert_eq!(stitched.heights.shape(), &[2, 5]);
+        assert_eq!(stitched.walkable.shape(), &[2, 5]);
+        assert_eq!(stitched.name, "Indexed + Indexed");
+        assert_eq!(stitched.heights[(1, 2)], 12.0);
+        assert_eq!(stitched.heights[(0, 3)], 50.0);
+        assert_eq!(stitched.heights[(1, 4)], 61.0);
+        assert_eq!(stitched.spawn_points[0].coord(), Hex::new(4, 1));
+        assert_eq!(stitched.hexes_with_tag("cover"), vec![Hex::new(3, 0)]);
+        assert!(stitched.validate().is_ok());
+    }
+
+    #[test]
+    fn test_stitch_vertical_offsets_rows() {
+        let top = indexed_level(3, 2);
+        let bottom = indexed_level(3, 4);
+
+        let stitched = Level::stitch(&top, &bottom, StitchAxis::Vertical).unwrap();
+
+        assert_eq!((stitched.width, stitched.height), (3, 6));
+        assert_eq!(stitched.heights[(1, 2)], 12.0);
+        assert_eq!(stitched.heights[(2, 0)], 0.0);
+        assert_eq!(stitched.heights[(5, 1)], 31.0);
+    }
+
+    #[test]
+    fn test_stitch_rejects_mismatched_edges() {
+        let a = indexed_level(3, 2);
+        let b = indexed_level(3, 3);
+
+        assert!(Level::stitch(&a, &b, StitchAxis::Horizontal).is_err());
+        assert!(Level::stitch(&a, &indexed_level(4, 2), StitchAxis::Vertical).is_err());
+        assert!(Level::stitch(&a, &b, StitchAxis::Vertical).is_ok());
+    }
+}
@@ -0,0 +1,183 @@
+//! Hex Triggers
+//!
+//! Named events attached to hexes (traps, dialogue, level exits) that fire when
+//! a unit enters the hex.
+
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+use hexx::Hex;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use super::{Level, LevelsResource};
+
+/// A named event fired when a unit enters `hex`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HexTrigger {
+    /// Axial hex coordinate as (q, r)
+    pub hex: (i32, i32),
+    /// Event name passed on to gameplay code, e.g. "spike_trap" or "exit_north"
+    pub event: String,
+    /// Fire only the first time the hex is entered
+    #[serde(default)]
+    pub once: bool,
+}
+
+impl HexTrigger {
+    /// Create a trigger firing `event` at `hex`
+    pub fn new(hex: Hex, event: impl Into<String>, once: bool) -> Self {
+        Self {
+            hex: (hex.x, hex.y),
+            event: event.into(),
+            once,
+        }
+    }
+
+    /// Get the trigger coordinate as a [`Hex`]
+    pub fn coord(&self) -> Hex {
+        Hex::new(self.hex.0, self.hex.1)
+    }
+}
+
+impl Level {
+    /// Get the triggers placed on `hex`, in file order
+    pub fn triggers_at(&self, hex: Hex) -> Vec<&HexTrigger> {
+        self.triggers
+            .iter()
+            .filter(|trigger| trigger.coord() == hex)
+            .collect()
+    }
+}
+
+/// Sent by movement code when a unit enters a hex of the current level
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexEntered {
+    pub hex: Hex,
+}
+
+/// Sent for each trigger that fires, for gameplay systems to react to
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct HexTriggerFired {
+    pub level_name: String,
+    pub hex: Hex,
+    pub event: String,
+}
+
+/// One-shot triggers that already fired, as (level name, trigger index)
+#[derive(Resource, Debug, Default)]
+pub struct FiredTriggers(HashSet<(String, usize)>);
+
+/// System turning [`HexEntered`] events into [`HexTriggerFired`] events for the
+/// current level, skipping one-shot triggers that already fired
+pub fn hex_trigger_system(
+    mut entered_events: EventReader<HexEntered>,
+    levels_resource: Res<LevelsResource>,
+    mut fired_triggers: ResMut<FiredTriggers>,
+    mut fired_events: EventWriter<HexTriggerFired>,
+) {
+    let level = levels_resource.current_level();
+    for entered in entered_events.read() {
+        for (index, trigger) in level.triggers.iter().enumerate() {
+            if trigger.coord() != entered.hex {
+                continue;
+            }
+            if trigger.once && !fired_triggers.0.insert((level.name.clone(), index)) {
+                continue;
+            }
+
+            info!(
+                "Trigger '{event}' fired at ({q}, {r}) in level '{level_name}'",
+                event = trigger.event,
+                q = entered.hex.x,
+                r = entered.hex.y,
+                level_name = level.name
+            );
+            fired_events.write(HexTriggerFired {
+                level_name: level.name.clone(),
+                hex: entered.hex,
+                event: trigger.event.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use tempfile::TempDir;
+
+    fn trap_level() -> Level {
+        let mut level = LevelBuilder::new()
+            .name("Traps")
+            .dimensions(4, 4)
+            .build()
+            .unwrap();
+        level.triggers = vec![
+            HexTrigger::new(Hex::new(1, 1), "spike_trap", false),
+            HexTrigger::new(Hex::new(1, 1), "ambush_dialogue", true),
+            HexTrigger::new(Hex::new(3, 0), "exit_north", false),
+        ];
+        level
+    }
+
+    #[test]
+    fn test_triggers_at() {
+        let level = trap_level();
+
+        let events: Vec<_> = level
+            .triggers_at(Hex::new(1, 1))
+            .iter()
+            .map(|trigger| trigger.event.as_str())
+            .collect();
+        assert_eq!(events, vec!["spike_trap", "ambush_dialogue"]);
+        assert!(level.triggers_at(Hex::new(2, 2)).is_empty());
+    }
+
+    #[test]
+    fn test_triggers_roundtrip_and_out_of_bounds_dropped() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+
+        let mut level = trap_level();
+        level
+            .triggers
+            .push(HexTrigger::new(Hex::new(7, 1), "nowhere", false));
+        level
+            .save_to_directory(temp_path, "traps.toml")
+            .expect("Failed to save level");
+
+        let loaded = load_levels_from_directory(temp_path).expect("Failed to load levels");
+        assert_eq!(loaded.current_level().triggers, trap_level().triggers);
+    }
+
+    #[test]
+    fn test_once_triggers_fire_a_single_time() {
+        let mut app = App::new();
+        app.add_event::<HexEntered>()
+            .add_event::<HexTriggerFired>()
+            .init_resource::<FiredTriggers>()
+            .insert_resource(LevelsResource::new(vec![trap_level()]))
+            .add_systems(Update, hex_trigger_system);
+
+        fn enter_trap_hex(app: &mut App) -> Vec<String> {
+            app.world_mut().send_event(HexEntered {
+                hex: Hex::new(1, 1),
+            });
+            app.update();
+            app.world_mut()
+                .resource_mut::<Events<HexTriggerFired>>()
+                .drain()
+                .map(|fired| fired.event)
+                .collect()
+        }
+
+        assert_eq!(
+            enter_trap_hex(&mut app),
+            vec!["spike_trap", "ambush_dialogue"]
+        );
+        assert_eq!(enter_trap_hex(&mut app), vec!["spike_trap"]);
+    }
+}
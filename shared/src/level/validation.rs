@@ -0,0 +1,334 @@
+//! Level Validation
+//!
+//! Sanity checks applied to levels after loading so corrupted or hand-edited
+//! files are rejected before they can break rendering or camera math.
+
+use anyhow::{Result, bail};
+use tracing::warn;
+
+use super::Level;
+
+/// Allowed range for hex heights in a valid level
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeightRange {
+    /// Lowest allowed height (inclusive)
+    pub min: f32,
+    /// Highest allowed height (inclusive)
+    pub max: f32,
+}
+
+impl Default for HeightRange {
+    fn default() -> Self {
+        // Generous enough for deep trenches and tall towers while still catching
+        // typos like a 10000.0 spike that would blow up camera framing
+        Self {
+            min: -100.0,
+            max: 100.0,
+        }
+    }
+}
+
+impl Level {
+    /// Validate this level using the default [`HeightRange`]
+    pub fn validate(&self) -> Result<()> {
+        self.validate_with(&HeightRange::default())
+    }
+
+    /// Validate grid shapes and that every height is finite and inside `range`
+    ///
+    /// Grid shapes are checked first since a hand-edited `heights` array with a
+    /// missing row would otherwise index out of bounds during spawning. The error
+    /// names the first offending hex so designers can find it in the file.
+    pub fn validate_with(&self, range: &HeightRange) -> Result<()> {
+        if self.width <= 0 || self.height <= 0 {
+            bail!(
+                "Level '{name}' must have positive dimensions, got {width}x{height}",
+                name = self.name,
+                width = self.width,
+                height = self.height
+            );
+        }
+
+        if !self.hex_scale.is_finite() || self.hex_scale <= 0.0 {
+            bail!(
+                "Level '{name}' has invalid hex scale {hex_scale}; it must be a positive number",
+                name = self.name,
+                hex_scale = self.hex_scale
+            );
+        }
+
+        let expected_shape = [self.height as usize, self.width as usize];
+        if self.heights.shape() != expected_shape {
+            bail!(
+                "Level '{name}' declares {width}x{height} but heights array has {rows} rows of {cols} values",
+                name = self.name,
+                width = self.width,
+                height = self.height,
+                rows = self.heights.nrows(),
+                cols = self.heights.ncols()
+            );
+        }
+        if !self.walkable.is_empty() && self.walkable.shape() != expected_shape {
+            bail!(
+                "Level '{name}' declares {width}x{height} but walkable array has {rows} rows of {cols} values",
+                name = self.name,
+                width = self.width,
+                height = self.height,
+                rows = self.walkable.nrows(),
+                cols = self.walkable.ncols()
+            );
+        }
+
+        for ((row, col), &height) in self.heights.indexed_iter() {
+            if !height.is_finite() {
+                bail!(
+                    "Level '{name}' has non-finite height {height} at hex ({col}, {row})",
+                    name = self.name
+                );
+            }
+            if height < range.min || height > range.max {
+                bail!(
+                    "Level '{name}' has height {height} at hex ({col}, {row}) outside allowed range {min}..={max}",
+                    name = self.name,
+                    min = range.min,
+                    max = range.max
+                );
+            }
+        }
+
+        if let Some(water_level) = self.water_level
+            && !water_level.is_finite()
+        {
+            bail!(
+                "Level '{name}' has non-finite water level {water_level}",
+                name = self.name
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Drop coordinate annotations that point outside the grid, warning for each
+    ///
+    /// Unlike height problems these do not make a level unusable, so loaders repair
+    /// them instead of skipping the file. Returns the number of entries removed.
+    pub fn prune_out_of_bounds(&mut self) -> usize {
+        let (width, height) = (self.width, self.height);
+        let in_bounds = |(q, r): (i32, i32)| q >= 0 && q < width && r >= 0 && r < height;
+        let level_name = &self.name;
+
+        let before = self.spawn_points.len();
+        self.spawn_points.retain(|spawn| {
+            let keep = in_bounds(spawn.hex);
+            if !keep {
+                warn!(
+                    "Dropping out-of-bounds spawn point ({q}, {r}) for team '{team}' in level '{level_name}'",
+                    q = spawn.hex.0,
+                    r = spawn.hex.1,
+                    team = spawn.team
+                );
+            }
+            keep
+        });
+
+        let mut removed = before - self.spawn_points.len();
+        for (tag, coords) in &mut self.tags {
+            coords.retain(|&(q, r)| {
+                let keep = in_bounds((q, r));
+                if !keep {
+                    warn!(
+                        "Dropping out-of-bounds tag '{tag}' at ({q}, {r}) in level '{level_name}'"
+                    );
+                    removed += 1;
+                }
+                keep
+            });
+        }
+        self.tags.retain(|_, coords| !coords.is_empty());
+
+        // Regions keep their name even if every hex is dropped so lookups still work
+        for region in &mut self.regions {
+            let region_name = &region.name;
+            region.hexes.retain(|&(q, r)| {
+                let keep = in_bounds((q, r));
+                if !keep {
+                    warn!(
+                        "Dropping out-of-bounds hex ({q}, {r}) from region '{region_name}' in level '{level_name}'"
+                    );
+                    removed += 1;
+                }
+                keep
+            });
+        }
+
+        let before = self.ramps.len();
+        self.ramps.retain(|ramp| {
+            let (from, to) = ramp.hexes();
+            let keep = in_bounds(ramp.from)
+                && in_bounds(ramp.to)
+                && from.unsigned_distance_to(to) == 1;
+            if !keep {
+                warn!(
+                    "Dropping ramp ({from_q}, {from_r}) -> ({to_q}, {to_r}) in level '{level_name}': endpoints must be adjacent and in bounds",
+                    from_q = ramp.from.0,
+                    from_r = ramp.from.1,
+                    to_q = ramp.to.0,
+                    to_r = ramp.to.1
+                );
+            }
+            keep
+        });
+        removed += before - self.ramps.len();
+
+        let before = self.triggers.len();
+        self.triggers.retain(|trigger| {
+            let keep = in_bounds(trigger.hex);
+            if !keep {
+                warn!(
+                    "Dropping out-of-bounds trigger '{event}' at ({q}, {r}) in level '{level_name}'",
+                    event = trigger.event,
+                    q = trigger.hex.0,
+                    r = trigger.hex.1
+                );
+            }
+            keep
+        });
+        removed += before - self.triggers.len();
+
+        let before = self.overlays.len();
+        self.overlays.retain(|overlay| {
+            let keep = in_bounds(overlay.hex)
+                && overlay.floor.is_finite()
+                && overlay.thickness.is_finite()
+                && overlay.thickness > 0.0;
+            if !keep {
+                warn!(
+                    "Dropping overlay at ({q}, {r}) in level '{level_name}': must be in bounds with finite floor and positive thickness",
+                    q = overlay.hex.0,
+                    r = overlay.hex.1
+                );
+            }
+            keep
+        });
+        removed += before - self.overlays.len();
+
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::load_levels_from_directory;
+    use hexx::Hex;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// Write a 2x2 level file with the given height data literal
+    fn write_fixture(dir: &TempDir, file_name: &str, name: &str, data: &str) {
+        let content = format!(
+            "name = \"{name}\"\nwidth = 2\nheight = 2\n\n[heights]\nv = 1\ndim = [2, 2]\ndata = [{data}]\n"
+        );
+        fs::write(dir.path().join(file_name), content).expect("Failed to write fixture");
+    }
+
+    #[test]
+    fn test_validate_accepts_normal_levels() {
+        let level = LevelBuilder::new()
+            .dimensions(5, 5)
+            .with_height(Hex::new(2, 2), -3.0)
+            .build()
+            .unwrap();
+        assert!(level.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_names_offending_hex() {
+        let mut level = LevelBuilder::new()
+            .dimensions(4, 3)
+            .flat(1.0)
+            .build()
+            .unwrap();
+        level.heights[(2, 1)] = f32::NAN;
+
+        let err = level.validate().expect_err("NaN height should fail");
+        assert!(err.to_string().contains("(1, 2)"), "Error was: {err}");
+
+        level.heights[(2, 1)] = 10000.0;
+        let err = level.validate().expect_err("Spike should fail");
+        assert!(err.to_string().contains("10000"), "Error was: {err}");
+
+        let tight = HeightRange { min: 0.0, max: 2.0 };
+        level.heights[(2, 1)] = 1.5;
+        assert!(level.validate_with(&tight).is_ok());
+        level.heights[(0, 0)] = -0.5;
+        assert!(level.validate_with(&tight).is_err());
+    }
+
+    #[test]
+    fn test_loader_skips_corrupted_fixtures() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        write_fixture(&temp_dir, "good.toml", "Good", "1.0, 2.0, 3.0, 4.0");
+        write_fixture(&temp_dir, "nan.toml", "NaN Level", "1.0, nan, 3.0, 4.0");
+        write_fixture(&temp_dir, "inf.toml", "Inf Level", "1.0, 2.0, inf, 4.0");
+        write_fixture(&temp_dir, "spike.toml", "Spike", "1.0, 2.0, 3.0, 10000.0");
+
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        let levels_resource = load_levels_from_directory(temp_path).expect("Failed to load levels");
+
+        assert_eq!(levels_resource.level_count(), 1);
+        assert_eq!(levels_resource.current_level().name, "Good");
+    }
+
+    #[test]
+    fn test_validate_rejects_shape_mismatch() {
+        let mut level = LevelBuilder::new().dimensions(3, 3).build().unwrap();
+        level.width = 4;
+        let err = level.validate().expect_err("Shape mismatch should fail");
+        assert!(err.to_string().contains("3 rows of 3"), "Error was: {err}");
+
+        let mut level = LevelBuilder::new().dimensions(3, 3).build().unwrap();
+        level.walkable = ndarray::Array2::from_elem((2, 3), true);
+        assert!(level.validate().is_err());
+        level.walkable = ndarray::Array2::from_elem((0, 0), true);
+        assert!(
+            level.validate().is_ok(),
+            "Empty walkable grid means all walkable"
+        );
+    }
+
+    #[test]
+    fn test_loader_skips_level_with_missing_row() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        write_fixture(&temp_dir, "good.toml", "Good", "1.0, 2.0, 3.0, 4.0");
+        // Declares 2x3 but the heights array only has two rows
+        let missing_row = "name = \"Missing Row\"\nwidth = 2\nheight = 3\n\n[heights]\nv = 1\ndim = [2, 2]\ndata = [1.0, 2.0, 3.0, 4.0]\n";
+        fs::write(temp_dir.path().join("missing_row.toml"), missing_row)
+            .expect("Failed to write fixture");
+
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        let levels_resource = load_levels_from_directory(temp_path).expect("Failed to load levels");
+
+        assert_eq!(levels_resource.level_count(), 1);
+        assert_eq!(levels_resource.current_level().name, "Good");
+    }
+
+    #[test]
+    fn test_loader_falls_back_when_all_levels_invalid() {
+        let temp_dir = TempDir::new().expect("Failed to create temporary directory");
+        write_fixture(&temp_dir, "nan.toml", "NaN Level", "nan, nan, nan, nan");
+
+        let temp_path = temp_dir.path().to_str().expect("Failed to get temp path");
+        let levels_resource = load_levels_from_directory(temp_path).expect("Failed to load levels");
+
+        assert_eq!(levels_resource.current_level().name, "Default Level");
+        assert!(
+            levels_resource
+                .current_level()
+                .heights
+                .iter()
+                .all(|h| h.is_finite())
+        );
+    }
+}
@@ -0,0 +1,94 @@
+//! Line of Sight
+//!
+//! Terrain occlusion checks between hexes for targeting and fog-of-war rules.
+
+use hexx::Hex;
+
+use super::Level;
+
+/// Check whether a viewer standing on `from` can see a target standing on `to`
+///
+/// The sight ray runs from `from`'s surface plus `eye_height` to `to`'s surface
+/// plus `eye_height`, sampled at each hex on the straight line between them. An
+/// intervening hex blocks sight when its top surface reaches the ray; a surface
+/// exactly level with the ray counts as blocking. Returns `false` when either
+/// endpoint lies outside the grid.
+pub fn line_of_sight(level: &Level, from: Hex, to: Hex, eye_height: f32) -> bool {
+    let (Some(from_height), Some(to_height)) =
+        (level.try_get_height(from), level.try_get_height(to))
+    else {
+        return false;
+    };
+
+    let start_eye = from_height + eye_height;
+    let end_eye = to_height + eye_height;
+    let distance = from.unsigned_distance_to(to);
+
+    from.line_to(to)
+        .enumerate()
+        .skip(1)
+        .take(distance.saturating_sub(1) as usize)
+        .all(|(step, hex)| {
+            let t = step as f32 / distance as f32;
+            let ray_height = start_eye + (end_eye - start_eye) * t;
+            level
+                .try_get_height(hex)
+                .is_none_or(|surface| surface < ray_height)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+
+    #[test]
+    fn test_tall_hex_blocks_sight() {
+        let level = LevelBuilder::new()
+            .dimensions(7, 3)
+            .flat(1.0)
+            .with_height(Hex::new(3, 1), 4.0)
+            .build()
+            .unwrap();
+
+        assert!(!line_of_sight(&level, Hex::new(0, 1), Hex::new(6, 1), 1.5));
+        assert!(!line_of_sight(&level, Hex::new(6, 1), Hex::new(0, 1), 1.5));
+        // A tall enough viewer sees over the pillar
+        assert!(line_of_sight(&level, Hex::new(0, 1), Hex::new(6, 1), 3.5));
+        // Parallel rows are unaffected
+        assert!(line_of_sight(&level, Hex::new(0, 0), Hex::new(6, 0), 1.5));
+    }
+
+    #[test]
+    fn test_valley_does_not_block_sight() {
+        let mut builder = LevelBuilder::new().dimensions(7, 1).flat(3.0);
+        for q in 1..6 {
+            builder = builder.with_height(Hex::new(q, 0), 0.5);
+        }
+        let level = builder.build().unwrap();
+
+        assert!(line_of_sight(&level, Hex::new(0, 0), Hex::new(6, 0), 0.0));
+    }
+
+    #[test]
+    fn test_tie_blocks_sight() {
+        let level = LevelBuilder::new()
+            .dimensions(3, 1)
+            .flat(1.0)
+            .with_height(Hex::new(1, 0), 2.0)
+            .build()
+            .unwrap();
+
+        assert!(!line_of_sight(&level, Hex::new(0, 0), Hex::new(2, 0), 1.0));
+        assert!(line_of_sight(&level, Hex::new(0, 0), Hex::new(2, 0), 1.01));
+    }
+
+    #[test]
+    fn test_adjacent_and_invalid_endpoints() {
+        let level = LevelBuilder::new().dimensions(3, 3).build().unwrap();
+
+        assert!(line_of_sight(&level, Hex::new(1, 1), Hex::new(1, 1), 0.0));
+        assert!(line_of_sight(&level, Hex::new(0, 0), Hex::new(1, 0), 0.0));
+        assert!(!line_of_sight(&level, Hex::new(0, 0), Hex::new(5, 0), 1.0));
+    }
+}
@@ -6,63 +6,143 @@
 use bevy::prelude::*;
 use tracing::debug;
 
+use crate::level::LevelsResource;
+use crate::level::drop::LevelFileRejected;
+use crate::level::lighting::LevelLighting;
+use crate::level::management::level_switching_system;
+
+use crate::rendering::background::{RenderingConfig, apply_background_system, fit_backdrop_system};
 use crate::rendering::camera::{
-    CameraLimits, CameraRotationState, camera_rotation_animation_system, on_level_change_system,
-    on_rotation_complete_system, on_window_resize_system, on_zoom_change_system, setup_camera,
+    CameraLimits, CameraRotationState, CameraZoomState, camera_framing_animation_system,
+    camera_rotation_animation_system, on_level_change_system, on_rotation_complete_system,
+    on_window_resize_system, on_zoom_change_system, setup_camera,
 };
 use crate::rendering::debug_aids::{
     DebugAidVisibility, camera_intersection_debug_system, debug_crosshair_system,
-    debug_text_spawn_system, debug_text_update_system,
+    debug_text_spawn_system, debug_text_update_system, hex_grid_overlay_system,
+    hex_label_position_system, hex_label_spawn_system, region_debug_system,
+};
+use crate::rendering::highlight::{
+    HexDeselected, HexSelected, HoveredHex, SelectedHex, hex_selection_system,
+    hover_highlight_system, hovered_hex_system, selection_highlight_system, spawn_hover_highlight,
+    spawn_selection_highlight,
+};
+use crate::rendering::lod::{GridLod, grid_lod_system, lod_terrain_system};
+use crate::rendering::quality::{
+    RenderQualityConfig, apply_render_quality_system, render_quality_keys_system,
+};
+use crate::rendering::range_overlay::{
+    RangeOverlay, RangeOverlayMaterials, range_overlay_debug_system, range_overlay_system,
 };
 use crate::rendering::ui::{
-    spawn_fps_counter, spawn_level_name_ui, update_fps_display, update_level_name_display,
+    spawn_fps_counter, spawn_level_name_ui, spawn_level_warning_ui, update_fps_display,
+    update_level_name_display, update_level_warning_display,
 };
 
+pub mod background;
 pub mod camera;
 pub mod debug_aids;
+pub mod highlight;
+pub mod lod;
+pub mod quality;
+pub mod range_overlay;
 pub mod ui;
 
+/// Marker for the directional light driven by the current level's lighting
+#[derive(Component)]
+pub struct SunLight;
+
 /// System to setup tactical lighting
-pub fn setup_lighting(mut commands: Commands) {
-    // Add directional light
-    let light_pos = Vec3::new(4.0, 8.0, 4.0);
-    let light_target = Vec3::ZERO;
-    let light_illuminance = 10000.0;
+///
+/// Spawns the sun with default settings and shadows per [`RenderQualityConfig`];
+/// [`apply_level_lighting_system`] then applies the active level's
+/// configuration.
+pub fn setup_lighting(mut commands: Commands, quality: Res<RenderQualityConfig>) {
+    let lighting = LevelLighting::default();
+    let light_transform = lighting.sun_transform();
     debug!(
-        "Adding directional light at position {} with illuminance {}",
-        light_pos, light_illuminance
+        "Adding directional light at position {light_pos} with illuminance {illuminance}",
+        light_pos = light_transform.translation,
+        illuminance = lighting.illuminance
     );
     commands.spawn((
         DirectionalLight {
-            color: Color::WHITE,
-            illuminance: light_illuminance,
+            color: lighting.sun_color(),
+            illuminance: lighting.illuminance,
+            shadows_enabled: quality.shadows,
             ..default()
         },
-        Transform::from_xyz(light_pos.x, light_pos.y, light_pos.z)
-            .looking_at(light_target, Vec3::Y),
+        light_transform,
+        SunLight,
     ));
 
-    // Add ambient lighting
-    let ambient_brightness = 300.0;
     debug!(
-        "Setting up ambient lighting with brightness {}",
-        ambient_brightness
+        "Setting up ambient lighting with brightness {ambient_brightness}",
+        ambient_brightness = lighting.ambient_brightness
     );
     commands.insert_resource(AmbientLight {
         color: Color::WHITE,
-        brightness: ambient_brightness,
+        brightness: lighting.ambient_brightness,
         affects_lightmapped_meshes: true,
     });
 }
 
+/// System to apply the current level's lighting whenever the level changes
+pub fn apply_level_lighting_system(
+    levels_resource: Res<LevelsResource>,
+    mut sun_query: Query<(&mut DirectionalLight, &mut Transform), With<SunLight>>,
+    mut ambient_light: ResMut<AmbientLight>,
+) {
+    if !levels_resource.is_changed() {
+        return;
+    }
+
+    let level = levels_resource.current_level();
+    let lighting = level.lighting;
+    for (mut light, mut transform) in &mut sun_query {
+        light.illuminance = lighting.illuminance;
+        light.color = lighting.sun_color();
+        *transform = lighting.sun_transform();
+    }
+    ambient_light.brightness = lighting.ambient_brightness;
+
+    debug!(
+        "Applied lighting for level '{level_name}': {illuminance} lux sun, ambient {ambient}",
+        level_name = level.name,
+        illuminance = lighting.illuminance,
+        ambient = lighting.ambient_brightness
+    );
+}
+
 /// Plugin for rendering setup (lighting and UI)
-pub struct RenderingPlugin;
+#[derive(Debug, Clone, Default)]
+pub struct RenderingPlugin {
+    config: RenderingConfig,
+}
+
+impl RenderingPlugin {
+    /// Render with `config` instead of the defaults
+    pub fn new(config: RenderingConfig) -> Self {
+        Self { config }
+    }
+}
 
 impl Plugin for RenderingPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<CameraRotationState>()
+        app.insert_resource(self.config.clone())
+            .init_resource::<CameraRotationState>()
             .init_resource::<CameraLimits>()
+            .init_resource::<CameraZoomState>()
             .init_resource::<DebugAidVisibility>()
+            .init_resource::<HoveredHex>()
+            .init_resource::<SelectedHex>()
+            .add_event::<HexSelected>()
+            .add_event::<HexDeselected>()
+            .init_resource::<RangeOverlay>()
+            .init_resource::<RangeOverlayMaterials>()
+            .init_resource::<RenderQualityConfig>()
+            .init_resource::<GridLod>()
+            .add_event::<LevelFileRejected>()
             .add_plugins(bevy::diagnostic::FrameTimeDiagnosticsPlugin::default())
             .add_systems(
                 Startup,
@@ -71,24 +151,108 @@ impl Plugin for RenderingPlugin {
                     setup_lighting,
                     spawn_fps_counter,
                     spawn_level_name_ui,
+                    spawn_level_warning_ui,
+                    spawn_hover_highlight,
+                    spawn_selection_highlight,
                 ),
             )
             .add_systems(
                 Update,
                 (
                     camera_rotation_animation_system,
+                    camera_framing_animation_system,
                     on_level_change_system,
                     on_zoom_change_system,
                     on_rotation_complete_system,
                     on_window_resize_system,
                     update_fps_display,
                     update_level_name_display,
-                    // Debug aid systems - run after camera updates
+                    update_level_warning_display,
+                    apply_level_lighting_system,
+                    apply_background_system,
+                    fit_backdrop_system.after(apply_background_system),
+                    render_quality_keys_system,
+                    apply_render_quality_system.after(render_quality_keys_system),
+                ),
+            )
+            // Debug aid systems - run after camera updates
+            .add_systems(
+                Update,
+                (
                     camera_intersection_debug_system,
                     debug_crosshair_system,
                     debug_text_spawn_system,
                     debug_text_update_system,
+                    region_debug_system,
+                    hex_grid_overlay_system,
+                    hex_label_spawn_system,
+                    hex_label_position_system.after(hex_label_spawn_system),
+                ),
+            )
+            // Hover, selection, and range markers on the hex grid
+            .add_systems(
+                Update,
+                (
+                    hovered_hex_system,
+                    hover_highlight_system.after(hovered_hex_system),
+                    hex_selection_system.after(hovered_hex_system),
+                    selection_highlight_system.after(hex_selection_system),
+                    range_overlay_debug_system.after(hex_selection_system),
+                    range_overlay_system.after(range_overlay_debug_system),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    grid_lod_system.after(on_zoom_change_system),
+                    lod_terrain_system
+                        .after(grid_lod_system)
+                        .after(level_switching_system),
                 ),
             );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+
+    #[test]
+    fn test_level_switch_changes_light() {
+        let day = LevelBuilder::new().name("Day").build().unwrap();
+        let mut night = LevelBuilder::new().name("Night").build().unwrap();
+        night.lighting.illuminance = 800.0;
+        night.lighting.ambient_brightness = 40.0;
+        night.lighting.sun_yaw = 200.0;
+
+        let mut app = App::new();
+        app.insert_resource(LevelsResource::new(vec![day, night]))
+            .init_resource::<RenderQualityConfig>()
+            .add_systems(Startup, setup_lighting)
+            .add_systems(Update, apply_level_lighting_system);
+        app.update();
+
+        let sun = |app: &mut App| {
+            let mut query = app
+                .world_mut()
+                .query_filtered::<(&DirectionalLight, &Transform), With<SunLight>>();
+            let (light, transform) = query.single(app.world()).expect("Sun should exist");
+            (light.illuminance, transform.translation)
+        };
+
+        let (day_lux, day_pos) = sun(&mut app);
+        assert_eq!(day_lux, 10000.0);
+        assert_eq!(app.world().resource::<AmbientLight>().brightness, 300.0);
+
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .current_level_index = 1;
+        app.update();
+
+        let (night_lux, night_pos) = sun(&mut app);
+        assert_eq!(night_lux, 800.0);
+        assert_ne!(night_pos, day_pos);
+        assert_eq!(app.world().resource::<AmbientLight>().brightness, 40.0);
+    }
+}
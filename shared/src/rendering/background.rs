@@ -0,0 +1,303 @@
+//! Scene Background
+//!
+//! What shows behind the tactical scene, chosen per app through
+//! [`RenderingConfig`]. Flat backgrounds only set the [`ClearColor`]; gradients
+//! and the sky are drawn on a backdrop quad parented to the tactical camera at
+//! the far end of its view, sized to cover the whole viewport. Levels with their
+//! own background color show it in place of a gradient or the sky.
+
+use bevy::pbr::{NotShadowCaster, NotShadowReceiver};
+use bevy::prelude::*;
+use bevy::render::{mesh::PrimitiveTopology, render_asset::RenderAssetUsages};
+use tracing::debug;
+
+use crate::colors::{SKY_HAZE, SKY_HORIZON, SKY_ZENITH};
+use crate::level::mesh::mesh_indices;
+use crate::level::{Level, LevelsResource};
+use crate::rendering::camera::TacticalCamera;
+
+/// Fraction of the camera's far plane the backdrop sits at
+const BACKDROP_DEPTH_FRACTION: f32 = 0.99;
+
+/// Backdrop size relative to the visible area, hiding edges during resizes
+const BACKDROP_MARGIN: f32 = 1.1;
+
+/// Background drawn behind the tactical scene
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SceneBackground {
+    /// The current level's background color, see [`Level::clear_color`]
+    #[default]
+    LevelColor,
+    /// One color for every level
+    Solid(Color),
+    /// Vertical gradient across the view, unless the level sets a background color
+    Gradient { top: Color, bottom: Color },
+    /// Haze at the bottom fading through a pale horizon into a blue zenith,
+    /// unless the level sets a background color
+    Sky,
+}
+
+impl SceneBackground {
+    /// Backdrop colors behind `level` from the bottom of the view to the top,
+    /// each at its height as a fraction of the view; `None` when the clear color
+    /// suffices
+    pub fn backdrop_stops(self, level: &Level) -> Option<Vec<(f32, Color)>> {
+        if level.background_color.is_some() {
+            return None;
+        }
+        match self {
+            Self::LevelColor | Self::Solid(_) => None,
+            Self::Gradient { top, bottom } => Some(vec![(0.0, bottom), (1.0, top)]),
+            Self::Sky => Some(vec![(0.0, SKY_HAZE), (0.4, SKY_HORIZON), (1.0, SKY_ZENITH)]),
+        }
+    }
+
+    /// Clear color while showing `level`, matching the bottom of any backdrop
+    pub fn clear_color(self, level: &Level) -> Color {
+        match self {
+            Self::Solid(color) => color,
+            _ if level.background_color.is_some() => level.clear_color(),
+            Self::LevelColor => level.clear_color(),
+            Self::Gradient { bottom, .. } => bottom,
+            Self::Sky => SKY_HAZE,
+        }
+    }
+}
+
+/// Rendering options chosen by each app when adding
+/// [`RenderingPlugin`](crate::rendering::RenderingPlugin)
+///
+/// Inserted as a resource; changing it at runtime applies immediately.
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub struct RenderingConfig {
+    pub background: SceneBackground,
+}
+
+/// Marker for the backdrop quad behind the tactical scene
+#[derive(Component)]
+pub struct SceneBackdrop;
+
+/// Unit quad facing +Z with one row of vertices per color stop
+pub fn create_backdrop_mesh(stops: &[(f32, Color)]) -> Mesh {
+    let mut positions = Vec::new();
+    let mut colors = Vec::new();
+    for &(height, color) in stops {
+        for x in [-0.5, 0.5] {
+            positions.push([x, height - 0.5, 0.0]);
+            colors.push(color.to_linear().to_f32_array());
+        }
+    }
+    let indices: Vec<u32> = (0..stops.len().saturating_sub(1) as u32)
+        .flat_map(|row| {
+            let base = row * 2;
+            [base, base + 1, base + 3, base, base + 3, base + 2]
+        })
+        .collect();
+    let vertex_count = positions.len();
+    let normals = vec![[0.0, 0.0, 1.0]; vertex_count];
+
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors)
+    .with_inserted_indices(mesh_indices(indices, vertex_count))
+}
+
+/// System applying [`RenderingConfig::background`] whenever it or the current
+/// level changes
+///
+/// Sets the clear color and replaces the backdrop quad on the tactical camera,
+/// removing it while a level shows its own background color.
+pub fn apply_background_system(
+    mut commands: Commands,
+    config: Res<RenderingConfig>,
+    levels_resource: Res<LevelsResource>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    camera_query: Query<Entity, With<TacticalCamera>>,
+    backdrop_query: Query<Entity, With<SceneBackdrop>>,
+) {
+    let level = levels_resource.current_level();
+    let stops = config.background.backdrop_stops(level);
+    // Missing, or left over from a level without its own background color
+    let backdrop_stale = stops.is_some() == backdrop_query.is_empty();
+    if !config.is_changed() && !levels_resource.is_changed() && !backdrop_stale {
+        return;
+    }
+
+    commands.insert_resource(ClearColor(config.background.clear_color(level)));
+    if !config.is_changed() && !backdrop_stale {
+        return;
+    }
+
+    for entity in &backdrop_query {
+        commands.entity(entity).despawn();
+    }
+    let (Some(stops), Ok(camera)) = (stops, camera_query.single()) else {
+        return;
+    };
+    commands.entity(camera).with_child((
+        Mesh3d(meshes.add(create_backdrop_mesh(&stops))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            unlit: true,
+            fog_enabled: false,
+            ..default()
+        })),
+        Transform::default(),
+        NotShadowCaster,
+        NotShadowReceiver,
+        SceneBackdrop,
+    ));
+    debug!(
+        "Spawned scene backdrop for {background:?}",
+        background = config.background
+    );
+}
+
+/// System keeping the backdrop at the far end of the camera's view and large
+/// enough to cover it at any zoom
+pub fn fit_backdrop_system(
+    camera_query: Query<&Projection, With<TacticalCamera>>,
+    mut backdrop_query: Query<&mut Transform, With<SceneBackdrop>>,
+) {
+    let Ok(Projection::Orthographic(ortho)) = camera_query.single() else {
+        return;
+    };
+
+    let size = ortho.area.size() * BACKDROP_MARGIN;
+    let fitted = Transform::from_xyz(0.0, 0.0, -ortho.far * BACKDROP_DEPTH_FRACTION)
+        .with_scale(size.extend(1.0));
+    for mut transform in &mut backdrop_query {
+        transform.set_if_neq(fitted);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+
+    fn background_app() -> App {
+        let day = LevelBuilder::new().name("Day").build().unwrap();
+        let mut night = LevelBuilder::new().name("Night").build().unwrap();
+        night.background_color = Some([0.0, 0.0, 0.1]);
+
+        let mut app = App::new();
+        app.insert_resource(LevelsResource::new(vec![day, night]))
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<RenderingConfig>()
+            .add_systems(
+                Update,
+                (apply_background_system, fit_backdrop_system).chain(),
+            );
+        app
+    }
+
+    #[test]
+    fn test_level_switch_changes_clear_color() {
+        let mut app = background_app();
+        app.update();
+        assert_eq!(
+            app.world().resource::<ClearColor>().0,
+            crate::colors::LIGHT_BACKGROUND
+        );
+
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .current_level_index = 1;
+        app.update();
+        assert_eq!(
+            app.world().resource::<ClearColor>().0,
+            Color::srgb(0.0, 0.0, 0.1)
+        );
+    }
+
+    #[test]
+    fn test_background_switches_at_runtime() {
+        let mut app = background_app();
+        let camera = app
+            .world_mut()
+            .spawn((
+                Projection::Orthographic(OrthographicProjection::default_3d()),
+                TacticalCamera,
+            ))
+            .id();
+        let backdrops = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(&ChildOf, &Mesh3d), With<SceneBackdrop>>()
+                .iter(app.world())
+                .map(|(parent, mesh)| (parent.parent(), mesh.0.id()))
+                .collect::<Vec<_>>()
+        };
+        app.update();
+        assert!(backdrops(&mut app).is_empty());
+
+        app.world_mut().resource_mut::<RenderingConfig>().background = SceneBackground::Sky;
+        app.update();
+        let sky = backdrops(&mut app);
+        assert_eq!(sky.len(), 1);
+        assert_eq!(sky[0].0, camera, "The backdrop follows the camera");
+        assert_eq!(app.world().resource::<ClearColor>().0, SKY_HAZE);
+        let mesh = app
+            .world()
+            .resource::<Assets<Mesh>>()
+            .get(sky[0].1)
+            .unwrap();
+        assert_eq!(mesh.count_vertices(), 6, "Two vertices per sky color stop");
+
+        let (top, bottom) = (Color::srgb(0.3, 0.3, 0.4), Color::srgb(0.1, 0.1, 0.2));
+        app.world_mut().resource_mut::<RenderingConfig>().background =
+            SceneBackground::Gradient { top, bottom };
+        app.update();
+        let gradient = backdrops(&mut app);
+        assert_eq!(gradient.len(), 1);
+        assert_ne!(gradient, sky);
+        assert_eq!(app.world().resource::<ClearColor>().0, bottom);
+
+        let solid = Color::srgb(0.5, 0.2, 0.2);
+        app.world_mut().resource_mut::<RenderingConfig>().background =
+            SceneBackground::Solid(solid);
+        app.update();
+        assert!(backdrops(&mut app).is_empty());
+        assert_eq!(app.world().resource::<ClearColor>().0, solid);
+    }
+
+    #[test]
+    fn test_level_background_color_replaces_sky() {
+        let mut app = background_app();
+        app.world_mut().spawn((
+            Projection::Orthographic(OrthographicProjection::default_3d()),
+            TacticalCamera,
+        ));
+        app.world_mut().resource_mut::<RenderingConfig>().background = SceneBackground::Sky;
+        let backdrop_count = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), With<SceneBackdrop>>()
+                .iter(app.world())
+                .count()
+        };
+        app.update();
+        assert_eq!(backdrop_count(&mut app), 1);
+
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .current_level_index = 1;
+        app.update();
+        assert_eq!(backdrop_count(&mut app), 0, "Night shows its own color");
+        assert_eq!(
+            app.world().resource::<ClearColor>().0,
+            Color::srgb(0.0, 0.0, 0.1)
+        );
+
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .current_level_index = 0;
+        app.update();
+        assert_eq!(backdrop_count(&mut app), 1);
+        assert_eq!(app.world().resource::<ClearColor>().0, SKY_HAZE);
+    }
+}
@@ -2,11 +2,17 @@
 //!
 //! Camera setup, positioning, rotation, and management for optimal tactical RPG viewing.
 
+use bevy::ecs::system::SystemParam;
+use bevy::input::mouse::MouseScrollUnit;
 use bevy::prelude::*;
 use bevy::window::WindowResized;
 use tracing::{debug, info, warn};
 
 use crate::level::LevelsResource;
+use crate::level::geometry::LevelGeometry;
+use crate::level::render_config::{
+    HexGridRenderConfig, VoidRendering, ground_surface_height, visible_diagonal_extent,
+};
 
 /// Component to mark the tactical camera for movement controls
 #[derive(Component)]
@@ -26,6 +32,99 @@ pub enum RotationMode {
     Stable,
     Clockwise(f32),        // f32 = remaining rotation in radians
     CounterClockwise(f32), // f32 = remaining rotation in radians
+    Framing(CameraTween),  // Animated move to a new position, rotation, and zoom
+}
+
+impl RotationMode {
+    /// Camera orientation once the current rotation or framing move ends,
+    /// given the orientation `current` it has now
+    pub fn settled_rotation(&self, current: Quat) -> Quat {
+        match self {
+            Self::Stable => current,
+            Self::Clockwise(remaining) => Quat::from_rotation_y(-remaining) * current,
+            Self::CounterClockwise(remaining) => Quat::from_rotation_y(*remaining) * current,
+            Self::Framing(tween) => tween.to.rotation,
+        }
+    }
+}
+
+/// Seconds an animated move to a new framing takes
+pub const CAMERA_FRAMING_DURATION: f32 = 0.5;
+
+/// Camera position, orientation, and orthographic scale
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraFraming {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: f32,
+}
+
+impl CameraFraming {
+    /// Current framing of a camera; `None` for perspective projections
+    pub fn of_camera(transform: &Transform, projection: &Projection) -> Option<Self> {
+        let Projection::Orthographic(ortho) = projection else {
+            return None;
+        };
+        Some(Self {
+            translation: transform.translation,
+            rotation: transform.rotation,
+            scale: ortho.scale,
+        })
+    }
+
+    /// Move a camera to this framing
+    pub fn apply(self, transform: &mut Transform, projection: &mut Projection) {
+        transform.translation = self.translation;
+        transform.rotation = self.rotation;
+        if let Projection::Orthographic(ortho) = projection {
+            ortho.scale = self.scale;
+        }
+    }
+
+    /// Framing a fraction `t` of the way from `self` to `to`
+    pub fn lerp(self, to: Self, t: f32) -> Self {
+        Self {
+            translation: self.translation.lerp(to.translation, t),
+            rotation: self.rotation.slerp(to.rotation, t),
+            scale: self.scale + (to.scale - self.scale) * t,
+        }
+    }
+}
+
+/// Animated move between two camera framings, easing in and out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraTween {
+    pub from: CameraFraming,
+    pub to: CameraFraming,
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl CameraTween {
+    /// Move from `from` to `to` over [`CAMERA_FRAMING_DURATION`]
+    pub fn new(from: CameraFraming, to: CameraFraming) -> Self {
+        Self {
+            from,
+            to,
+            elapsed: 0.0,
+            duration: CAMERA_FRAMING_DURATION,
+        }
+    }
+
+    /// Advance by `delta_secs` and return the framing to show; exactly `to`
+    /// once finished
+    pub fn advance(&mut self, delta_secs: f32) -> CameraFraming {
+        self.elapsed = (self.elapsed + delta_secs).min(self.duration);
+        if self.is_finished() {
+            return self.to;
+        }
+        let t = self.elapsed / self.duration;
+        self.from.lerp(self.to, t * t * (3.0 - 2.0 * t))
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
 }
 
 /// Resource to track dynamic camera zoom limits and movement bounds
@@ -54,48 +153,95 @@ impl Default for CameraLimits {
     }
 }
 
-/// Check if a 2D point is inside a regular hexagon using optimized symmetry algorithm
-fn is_inside_regular_hexagon(point: Vec2, center: Vec2, radius: f32) -> bool {
-    let dx = (point.x - center.x).abs();
-    let dy = (point.y - center.y).abs();
+/// Resource holding the orthographic scale the camera is easing toward
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct CameraZoomState {
+    /// Scale to reach; `None` once the camera has arrived
+    pub target: Option<f32>,
+    /// Easing rate per second; higher values reach the target sooner
+    pub speed: f32,
+}
 
-    // Use hexagon's symmetry - only check 3 edges
-    if dx > radius * 0.866 {
-        // sqrt(3)/2
-        return false;
+impl Default for CameraZoomState {
+    fn default() -> Self {
+        Self {
+            target: None,
+            speed: 12.0,
+        }
     }
-    if dy > radius {
-        return false;
+}
+
+/// Distance from the target, relative to it, within which zoom easing snaps
+/// onto the target
+const ZOOM_SNAP_FRACTION: f32 = 0.001;
+
+/// Orthographic scale after easing `scale` toward `target` for `delta_secs`
+///
+/// The gap shrinks exponentially at `speed` and closes exactly once it is
+/// within [`ZOOM_SNAP_FRACTION`] of the target, so the animation ends.
+pub fn ease_zoom_scale(scale: f32, target: f32, speed: f32, delta_secs: f32) -> f32 {
+    let eased = target + (scale - target) * (-speed * delta_secs).exp();
+    if (eased - target).abs() <= target.abs() * ZOOM_SNAP_FRACTION {
+        target
+    } else {
+        eased
     }
-    radius * 1.5 - 0.866 * dx - 0.5 * dy >= 0.0
 }
 
 /// Raycast against hex top surfaces to find the intersection point
 ///
-/// Iterates through all hexes in the level and performs ray-plane intersection
-/// with each hex's top surface. Returns the first valid intersection found.
+/// Only the hexes along the ray's ground projection are tested: the segment
+/// between where the ray crosses the highest and the lowest surface of the
+/// level, widened by one hex so corner clips are not missed. Overlay tops
+/// (e.g. bridges) are tested too. Returns the highest surface hit, which is
+/// the one visible to a camera looking down on the level. Void hexes are
+/// tested at their tile height or, if they are holes, not at all.
 pub fn raycast_hex_surfaces(
     camera_pos: Vec3,
     direction: Vec3,
     level: &crate::level::Level,
+    void: Option<VoidRendering>,
 ) -> Option<Vec3> {
-    use crate::level::Level;
-
     // If camera is looking parallel to XZ plane, skip raycasting
     if direction.y.abs() < 0.001 {
         return None;
     }
 
-    let hex_layout = Level::hex_layout();
-
-    // For pointy orientation with scale 1.0, the radius (center to vertex) is 1.0
-    let hex_radius = 1.0;
-
-    // Iterate through all hexes and find first intersection
-    for hex in level.get_hex_grid() {
-        let height = level.get_height(hex);
+    // Height range every surface lies in: the ground, void tiles, and overlays
+    let geometry = level.geometry();
+    let (bottom, top) = level.overlays.iter().map(|overlay| overlay.top()).fold(
+        (geometry.min_bounds.y, geometry.max_bounds.y),
+        |(bottom, top), height| (bottom.min(height), top.max(height)),
+    );
+    let top = void.map_or(top, |void| top.max(void.threshold));
+
+    // Ray parameters where it enters and leaves that range, clipped to the
+    // part in front of the camera
+    let t_top = (top - camera_pos.y) / direction.y;
+    let t_bottom = (bottom - camera_pos.y) / direction.y;
+    let t_exit = t_top.max(t_bottom);
+    if t_exit < 0.0 {
+        return None;
+    }
+    let t_entry = t_top.min(t_bottom).max(0.0);
 
-        // Calculate ray-plane intersection at this hex's height
+    let hex_layout = level.hex_layout();
+    let ground_hex = |t: f32| {
+        let point = camera_pos + direction * t;
+        hex_layout.world_pos_to_hex(Vec2::new(point.x, point.z))
+    };
+    let ground_tops = ground_hex(t_entry)
+        .line_to(ground_hex(t_exit))
+        .flat_map(|hex| hex.range(1))
+        .filter_map(|hex| ground_surface_height(level, hex, void).map(|height| (hex, height)));
+    let overlay_tops = level
+        .overlays
+        .iter()
+        .map(|overlay| (overlay.coord(), overlay.top()));
+
+    let mut best_hit: Option<Vec3> = None;
+    for (hex, height) in ground_tops.chain(overlay_tops) {
+        // Calculate ray-plane intersection at this surface's height
         // Ray equation: point = camera_pos + t * direction
         // Plane equation: y = height
         // Intersection: height = camera_pos.y + t * direction.y
@@ -108,18 +254,17 @@ pub fn raycast_hex_surfaces(
 
         let intersection = camera_pos + direction * t;
 
-        // Get hex center in world space
-        let hex_world_pos = hex_layout.hex_to_world_pos(hex);
-        let hex_center_2d = Vec2::new(hex_world_pos.x, hex_world_pos.y);
+        // Check if the intersection point lies inside this hex using the same
+        // layout as rendering, so every orientation and scale is handled
         let intersection_2d = Vec2::new(intersection.x, intersection.z);
-
-        // Check if intersection point is inside this hex
-        if is_inside_regular_hexagon(intersection_2d, hex_center_2d, hex_radius) {
-            return Some(Vec3::new(intersection.x, height, intersection.z));
+        if level.world_to_hex(intersection_2d) == Some(hex)
+            && best_hit.is_none_or(|best| height > best.y)
+        {
+            best_hit = Some(Vec3::new(intersection.x, height, intersection.z));
         }
     }
 
-    None
+    best_hit
 }
 
 /// Calculate where the camera's forward ray intersects hex surfaces
@@ -133,12 +278,16 @@ pub fn raycast_hex_surfaces(
 /// could be inaccurate for the viewed hex.
 ///
 /// TODO: Add cylinder side raycasting for edge cases where camera looks at hex sides.
-pub fn calculate_camera_focus_point(transform: &Transform, level: &crate::level::Level) -> Vec3 {
+pub fn calculate_camera_focus_point(
+    transform: &Transform,
+    level: &crate::level::Level,
+    void: Option<VoidRendering>,
+) -> Vec3 {
     let camera_pos = transform.translation;
     let forward_dir = transform.forward();
 
     // Try hex raycasting first
-    if let Some(hex_intersection) = raycast_hex_surfaces(camera_pos, *forward_dir, level) {
+    if let Some(hex_intersection) = raycast_hex_surfaces(camera_pos, *forward_dir, level, void) {
         return hex_intersection;
     }
 
@@ -194,6 +343,114 @@ pub fn calculate_movement_radius(camera_limits: &CameraLimits, current_scale: f3
     zoom_factor * (camera_limits.level_diagonal / 2.0)
 }
 
+/// Fraction of the movement radius, inward from its edge, over which panning
+/// slows to a stop
+pub const PAN_SOFT_EDGE_FRACTION: f32 = 0.25;
+
+/// How fast a camera outside the movement radius is pulled back, per second
+pub const PAN_PULL_BACK_RATE: f32 = 6.0;
+
+/// Ground-plane `movement` allowed for a camera at `offset` from the optimal
+/// position
+///
+/// Movement away from the optimal position slows down across the outer
+/// [`PAN_SOFT_EDGE_FRACTION`] of `radius` and stops at the edge; movement back
+/// inward is never slowed. A camera already outside `radius` cannot move
+/// further out.
+pub fn soft_clamp_pan(offset: Vec2, movement: Vec2, radius: f32) -> Vec2 {
+    let distance = offset.length();
+    let outward = offset.normalize_or_zero();
+    let radial = movement.dot(outward);
+
+    let mut allowed = movement;
+    if radial > 0.0 {
+        let soft_edge = radius * PAN_SOFT_EDGE_FRACTION;
+        let resistance = if soft_edge > 0.0 {
+            ((radius - distance) / soft_edge).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        allowed -= outward * radial * (1.0 - resistance);
+    }
+
+    // Sideways movement along the edge must not carry the camera past it
+    let max_distance = radius.max(distance).max(0.0);
+    let target = offset + allowed;
+    if target.length() > max_distance {
+        target.normalize_or_zero() * max_distance - offset
+    } else {
+        allowed
+    }
+}
+
+/// `offset` after `delta_secs` of being pulled back inside `radius`
+///
+/// Shrinking the radius by zooming out leaves the camera outside it; the
+/// excess distance decays at [`PAN_PULL_BACK_RATE`] instead of snapping.
+pub fn pull_within_radius(offset: Vec2, radius: f32, delta_secs: f32) -> Vec2 {
+    let radius = radius.max(0.0);
+    let distance = offset.length();
+    if distance <= radius {
+        return offset;
+    }
+
+    let excess = (distance - radius) * (-PAN_PULL_BACK_RATE * delta_secs).exp();
+    if excess < 0.001 {
+        offset.normalize_or_zero() * radius
+    } else {
+        offset.normalize_or_zero() * (radius + excess)
+    }
+}
+
+/// Fraction of the current scale zoomed per mouse wheel notch
+pub const ZOOM_LINE_SENSITIVITY: f32 = 0.1;
+
+/// Fraction of the current scale zoomed per trackpad pixel
+pub const ZOOM_PIXEL_SENSITIVITY: f32 = 0.002;
+
+/// Orthographic scale after scrolling `delta` in `unit` at `scale`
+///
+/// Each step zooms by a fixed fraction of the current scale, so zooming feels
+/// the same anywhere between [`CameraLimits::min_zoom_scale`] and
+/// [`CameraLimits::max_zoom_scale`], which the result is clamped to.
+pub fn scroll_zoom_scale(
+    scale: f32,
+    unit: MouseScrollUnit,
+    delta: f32,
+    camera_limits: &CameraLimits,
+) -> f32 {
+    let sensitivity = match unit {
+        MouseScrollUnit::Line => ZOOM_LINE_SENSITIVITY,
+        MouseScrollUnit::Pixel => ZOOM_PIXEL_SENSITIVITY,
+    };
+    // Scrolling up (positive delta) zooms in, shrinking the scale
+    (scale * (-delta * sensitivity).exp())
+        .clamp(camera_limits.min_zoom_scale, camera_limits.max_zoom_scale)
+}
+
+/// Ground-plane camera shift keeping `anchor` under the cursor while the
+/// orthographic scale changes by `scale_ratio`
+///
+/// `cursor_offset` is how far the cursor's ray starts from the camera's center
+/// ray before zooming. Orthographic rays are parallel, so that offset scales
+/// with the projection; the shift moves the scaled ray back onto `anchor`.
+pub fn zoom_to_cursor_shift(
+    transform: &Transform,
+    cursor_offset: Vec3,
+    anchor: Vec3,
+    scale_ratio: f32,
+) -> Vec3 {
+    let direction = transform.forward();
+    if direction.y.abs() < 0.001 {
+        return Vec3::ZERO;
+    }
+
+    let zoomed_origin = transform.translation + cursor_offset * scale_ratio;
+    let t = (anchor.y - zoomed_origin.y) / direction.y;
+    let zoomed_hit = zoomed_origin + direction * t;
+    Vec3::new(anchor.x - zoomed_hit.x, 0.0, anchor.z - zoomed_hit.z)
+}
+
 /// Calculate optimal camera scale based on level diagonal, viewport size and camera orientation
 pub fn calculate_optimal_scale(level_diagonal: f32, viewport_size: f32) -> f32 {
     let padding = 3.0;
@@ -217,6 +474,30 @@ pub fn calculate_optimal_camera_position(center_pos: Vec3, camera_forward: Dir3)
     Vec3::new(camera_x, camera_height, camera_z)
 }
 
+/// Default camera orientation: looking down at 45° from the south-east
+pub fn default_camera_rotation() -> Quat {
+    Quat::from_rotation_y(-45.0_f32.to_radians()) * Quat::from_rotation_x(-45.0_f32.to_radians())
+}
+
+/// Framing that fits the visible ground of `level` under `void` into
+/// `window` for a camera with `rotation`
+pub fn optimal_framing(
+    level: &crate::level::Level,
+    void: Option<VoidRendering>,
+    rotation: Quat,
+    window: &Window,
+) -> CameraFraming {
+    let orientation = Transform::from_rotation(rotation);
+    let translation =
+        calculate_optimal_camera_position(level.geometry().center, orientation.forward());
+    let viewport_size = get_viewport_size_for_orientation(&orientation, window);
+    CameraFraming {
+        translation,
+        rotation,
+        scale: calculate_optimal_scale(visible_diagonal_extent(level, void), viewport_size),
+    }
+}
+
 /// Determine viewport size based on camera orientation and window dimensions
 pub fn get_viewport_size_for_orientation(transform: &Transform, window: &Window) -> f32 {
     // Determine viewport dimension based on camera Y rotation
@@ -246,8 +527,7 @@ pub fn get_viewport_size_for_orientation(transform: &Transform, window: &Window)
 pub fn setup_camera(mut commands: Commands) {
     let camera_pos = Vec3::new(4.5, 20.0, -4.5); // Above grid center
 
-    let rotation = Quat::from_rotation_y(-45.0_f32.to_radians())
-        * Quat::from_rotation_x(-45.0_f32.to_radians());
+    let rotation = default_camera_rotation();
 
     debug!("Spawning isometric camera at position {camera_pos} with rotation {rotation:?}");
 
@@ -306,22 +586,114 @@ pub fn camera_rotation_animation_system(
                     camera_limits.rotation_processed = false; // Mark as needing processing
                 }
             }
-            RotationMode::Stable => {
-                // No rotation needed - no calculations performed
+            RotationMode::Stable | RotationMode::Framing(_) => {
+                // No rotation needed - framing moves run in camera_framing_animation_system
             }
         }
     }
 }
 
+/// System animating [`RotationMode::Framing`] moves
+///
+/// Once the move finishes the camera is stable again and, as after a Q/E
+/// rotation, limits are recalculated for its orientation.
+pub fn camera_framing_animation_system(
+    time: Res<Time>,
+    mut rotation_state: ResMut<CameraRotationState>,
+    mut camera_limits: ResMut<CameraLimits>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<TacticalCamera>>,
+) {
+    if !matches!(rotation_state.rotation_mode, RotationMode::Framing(_)) {
+        return;
+    }
+    let Ok((mut transform, mut projection)) = camera_query.single_mut() else {
+        return;
+    };
+    let RotationMode::Framing(tween) = &mut rotation_state.rotation_mode else {
+        return;
+    };
+
+    let framing = tween.advance(time.delta_secs());
+    framing.apply(&mut transform, &mut projection);
+    if tween.is_finished() {
+        rotation_state.rotation_mode = RotationMode::Stable;
+        camera_limits.rotation_processed = false; // Mark as needing processing
+    }
+}
+
+/// System starting an animated return to the current level's optimal framing
+/// from the default orientation
+///
+/// Bound to the Home key; other triggers such as UI buttons can run it with
+/// `Commands::run_system_cached`. Ignored while a rotation or another framing
+/// move is in flight.
+pub fn reset_camera_framing(
+    levels_resource: Res<LevelsResource>,
+    render_config: Res<HexGridRenderConfig>,
+    mut rotation_state: ResMut<CameraRotationState>,
+    mut zoom_state: ResMut<CameraZoomState>,
+    camera_query: Query<(&Transform, &Projection), With<TacticalCamera>>,
+    windows: Query<&Window>,
+) {
+    if !matches!(rotation_state.rotation_mode, RotationMode::Stable) {
+        return;
+    }
+    let Some(from) = camera_query
+        .single()
+        .ok()
+        .and_then(|(transform, projection)| CameraFraming::of_camera(transform, projection))
+    else {
+        return;
+    };
+    let Some(window) = windows.iter().next() else {
+        warn!("No window available for camera reset");
+        return;
+    };
+
+    let level = levels_resource.current_level();
+    let to = optimal_framing(
+        level,
+        render_config.active_void(),
+        default_camera_rotation(),
+        window,
+    );
+    zoom_state.target = None;
+    rotation_state.rotation_mode = RotationMode::Framing(CameraTween::new(from, to));
+    info!(
+        "Camera reset: framing '{level_name}' from {position:?} at scale {scale:.4}",
+        level_name = level.name,
+        position = to.translation,
+        scale = to.scale
+    );
+}
+
+/// Current level and void rendering the camera frames, plus the level index,
+/// geometry, and visible diagonal it last framed
+#[derive(SystemParam)]
+pub struct FramedLevel<'w, 's> {
+    levels_resource: Res<'w, LevelsResource>,
+    render_config: Res<'w, HexGridRenderConfig>,
+    last_framed: Local<'s, Option<(usize, LevelGeometry, f32)>>,
+}
+
 /// System that handles all camera updates when level changes
-/// Calculates diagonal, optimal position, updates limits, sets position + zoom, and movement radius
+/// Calculates diagonal, optimal position, updates limits, glides to position + zoom, and movement radius
+///
+/// The first level is framed instantly; later switches start a
+/// [`RotationMode::Framing`] move, which blocks camera input until it ends.
+/// Switching again mid-move restarts it from wherever the camera is. Changes
+/// that keep the current level and its bounds, such as saving or adding
+/// another level, only update the limits.
 pub fn on_level_change_system(
-    levels_resource: Res<LevelsResource>,
+    mut framed_level: FramedLevel,
     mut camera_limits: ResMut<CameraLimits>,
+    mut zoom_state: ResMut<CameraZoomState>,
+    mut rotation_state: ResMut<CameraRotationState>,
     mut camera_query: Query<(&mut Transform, &mut Projection), With<TacticalCamera>>,
     windows: Query<&Window>,
 ) {
     // Only trigger when LevelsResource has actually changed
+    let levels_resource = &framed_level.levels_resource;
     if !levels_resource.is_changed() {
         return;
     }
@@ -337,33 +709,60 @@ pub fn on_level_change_system(
 
     let level = levels_resource.current_level();
 
-    // 1. Calculate and cache level diagonal
-    let level_diagonal = level.get_level_diagonal_extent();
+    // 1. Look up the diagonal of the visible ground and store it
+    let void = framed_level.render_config.active_void();
+    let level_diagonal = visible_diagonal_extent(level, void);
     camera_limits.level_diagonal = level_diagonal;
 
-    // 2. Calculate and cache optimal camera position
-    let center_pos = level.get_center_world_pos();
-    let camera_forward = transform.forward();
-    let optimal_position = calculate_optimal_camera_position(center_pos, camera_forward);
+    // 2. Calculate and cache optimal camera position and, based on the new
+    // level and the orientation once any rotation settles, the max zoom scale
+    let rotation = rotation_state
+        .rotation_mode
+        .settled_rotation(transform.rotation);
+    let framing = optimal_framing(level, void, rotation, window);
+    let optimal_position = framing.translation;
     camera_limits.optimal_camera_position = optimal_position;
-
-    // 3. Update camera limits (max zoom scale) based on new level and current orientation
-    let viewport_size = get_viewport_size_for_orientation(&transform, window);
-    let optimal_scale = calculate_optimal_scale(level_diagonal, viewport_size);
+    let optimal_scale = framing.scale;
     camera_limits.max_zoom_scale = optimal_scale;
 
-    // 4. Set optimal position and zoom
-    transform.translation = optimal_position;
-    if let Projection::Orthographic(ortho) = projection.as_mut() {
-        ortho.scale = optimal_scale;
+    // 3. Without a switch or new bounds, keep the camera where it is and
+    // update the movement radius for its current zoom
+    let framed = (
+        levels_resource.current_level_index,
+        level.geometry(),
+        level_diagonal,
+    );
+    let is_added = levels_resource.is_added();
+    if framed_level.last_framed.replace(framed) == Some(framed) {
+        if let Projection::Orthographic(ortho) = projection.as_ref() {
+            camera_limits.current_movement_radius =
+                calculate_movement_radius(&camera_limits, ortho.scale);
+        }
+        debug!(
+            "Level change: Updated camera limits for '{level_name}' without moving",
+            level_name = level.name
+        );
+        return;
     }
 
-    // 5. Update movement radius based on new zoom level
-    if let Projection::Orthographic(ortho) = projection.as_ref() {
-        camera_limits.current_movement_radius =
-            calculate_movement_radius(&camera_limits, ortho.scale);
+    // 4. Move to optimal position and zoom, ending any zoom animation or
+    // rotation in flight
+    zoom_state.target = None;
+    match CameraFraming::of_camera(&transform, &projection) {
+        Some(current) if !is_added => {
+            rotation_state.rotation_mode =
+                RotationMode::Framing(CameraTween::new(current, framing));
+        }
+        _ => {
+            framing.apply(&mut transform, &mut projection);
+            rotation_state.rotation_mode = RotationMode::Stable;
+        }
     }
 
+    // 5. Update movement radius based on the zoom level being moved to
+    camera_limits.current_movement_radius =
+        calculate_movement_radius(&camera_limits, optimal_scale);
+
     camera_limits.needs_recalculation = false;
 
     info!(
@@ -427,8 +826,7 @@ pub fn on_rotation_complete_system(
     };
 
     // Recalculate optimal camera position because camera forward vector changed
-    let level = levels_resource.current_level();
-    let center_pos = level.get_center_world_pos();
+    let center_pos = levels_resource.current_level().geometry().center;
     let updated_position = calculate_optimal_camera_position(center_pos, transform.forward());
     camera_limits.optimal_camera_position = updated_position;
 
@@ -491,3 +889,535 @@ pub fn on_window_resize_system(
         radius = camera_limits.current_movement_radius
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::HexOrientation;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::overlays::OverlayHex;
+    use hexx::Hex;
+
+    #[test]
+    fn test_pan_is_free_inside_radius_and_slows_at_edge() {
+        let radius = 8.0;
+        let step = Vec2::new(0.5, 0.0);
+        assert_eq!(soft_clamp_pan(Vec2::ZERO, step, radius), step);
+        assert_eq!(soft_clamp_pan(Vec2::new(3.0, 0.0), step, radius), step);
+
+        // Inside the soft edge outward steps shrink, inward ones do not
+        let slowed = soft_clamp_pan(Vec2::new(7.0, 0.0), step, radius);
+        assert!(slowed.x > 0.0 && slowed.x < step.x);
+        assert_eq!(soft_clamp_pan(Vec2::new(7.0, 0.0), -step, radius), -step);
+        assert_eq!(
+            soft_clamp_pan(Vec2::new(8.0, 0.0), step, radius),
+            Vec2::ZERO
+        );
+
+        // Repeated pushes approach the edge without crossing it
+        let mut offset = Vec2::new(5.0, 0.0);
+        for _ in 0..1000 {
+            offset += soft_clamp_pan(offset, step, radius);
+        }
+        assert!(offset.x <= radius && offset.x > radius - 0.1);
+
+        // Sliding along the edge keeps the camera on the circle
+        let along = soft_clamp_pan(Vec2::new(0.0, radius), Vec2::new(1.0, 0.0), radius);
+        assert!(((Vec2::new(0.0, radius) + along).length() - radius).abs() < 1e-4);
+
+        assert_eq!(soft_clamp_pan(Vec2::ZERO, step, 0.0), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_reset_returns_to_fresh_level_framing() {
+        let level = LevelBuilder::new()
+            .dimensions(12, 8)
+            .flat(1.0)
+            .build()
+            .unwrap();
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<CameraLimits>()
+            .init_resource::<CameraZoomState>()
+            .init_resource::<CameraRotationState>()
+            .init_resource::<HexGridRenderConfig>()
+            .insert_resource(LevelsResource::new(vec![level]))
+            .add_systems(Startup, setup_camera)
+            .add_systems(
+                Update,
+                (on_level_change_system, camera_framing_animation_system),
+            );
+        app.world_mut().spawn(Window::default());
+        let framing = |app: &mut App| {
+            let world = app.world_mut();
+            let (transform, projection) = world
+                .query_filtered::<(&Transform, &Projection), With<TacticalCamera>>()
+                .single(world)
+                .expect("Camera should exist");
+            CameraFraming::of_camera(transform, projection).unwrap()
+        };
+        app.update();
+        let fresh = framing(&mut app);
+
+        // Pan, rotate, and zoom away from the level framing
+        let world = app.world_mut();
+        let (mut transform, mut projection) = world
+            .query_filtered::<(&mut Transform, &mut Projection), With<TacticalCamera>>()
+            .single_mut(world)
+            .unwrap();
+        transform.translation += Vec3::new(3.0, 0.0, -2.0);
+        transform.rotate_y(90.0_f32.to_radians());
+        if let Projection::Orthographic(ortho) = projection.as_mut() {
+            ortho.scale *= 0.3;
+        }
+
+        // No reset while a Q/E rotation is in flight
+        app.world_mut()
+            .resource_mut::<CameraRotationState>()
+            .rotation_mode = RotationMode::Clockwise(1.0);
+        app.world_mut()
+            .run_system_cached(reset_camera_framing)
+            .unwrap();
+        assert!(matches!(
+            app.world().resource::<CameraRotationState>().rotation_mode,
+            RotationMode::Clockwise(_)
+        ));
+
+        app.world_mut()
+            .resource_mut::<CameraRotationState>()
+            .rotation_mode = RotationMode::Stable;
+        app.world_mut()
+            .run_system_cached(reset_camera_framing)
+            .unwrap();
+        let RotationMode::Framing(tween) =
+            app.world().resource::<CameraRotationState>().rotation_mode
+        else {
+            panic!("Reset should start a framing move");
+        };
+        assert_eq!(tween.to, fresh);
+
+        for _ in 0..3 {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(0.25));
+            app.update();
+        }
+        assert_eq!(framing(&mut app), fresh);
+        assert!(matches!(
+            app.world().resource::<CameraRotationState>().rotation_mode,
+            RotationMode::Stable
+        ));
+        assert!(!app.world().resource::<CameraLimits>().rotation_processed);
+    }
+
+    #[test]
+    fn test_level_switch_glides_and_restarts_when_cycled_again() {
+        let small = LevelBuilder::new().dimensions(6, 6).build().unwrap();
+        let large = LevelBuilder::new().dimensions(30, 20).build().unwrap();
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<CameraLimits>()
+            .init_resource::<CameraZoomState>()
+            .init_resource::<CameraRotationState>()
+            .init_resource::<HexGridRenderConfig>()
+            .insert_resource(LevelsResource::new(vec![small.clone(), large.clone()]))
+            .add_systems(Startup, setup_camera)
+            .add_systems(
+                Update,
+                (on_level_change_system, camera_framing_animation_system).chain(),
+            );
+        let window = Window::default();
+        app.world_mut().spawn(window.clone());
+        let rotation = default_camera_rotation();
+        let small_framing = optimal_framing(&small, None, rotation, &window);
+        let large_framing = optimal_framing(&large, None, rotation, &window);
+        let framing = |app: &mut App| {
+            let world = app.world_mut();
+            let (transform, projection) = world
+                .query_filtered::<(&Transform, &Projection), With<TacticalCamera>>()
+                .single(world)
+                .expect("Camera should exist");
+            CameraFraming::of_camera(transform, projection).unwrap()
+        };
+        let tween = |app: &App| match app.world().resource::<CameraRotationState>().rotation_mode {
+            RotationMode::Framing(tween) => Some(tween),
+            _ => None,
+        };
+        let step = |app: &mut App, secs: f32| {
+            app.world_mut()
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_secs_f32(secs));
+            app.update();
+        };
+
+        // The first level is framed without a transition
+        app.update();
+        assert_eq!(framing(&mut app), small_framing);
+        assert!(tween(&app).is_none());
+
+        // Start: switching levels glides toward the new framing
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .set_current_index(1);
+        step(&mut app, 0.1);
+        let started = tween(&app).expect("Switching should start a transition");
+        assert_eq!(started.to, large_framing);
+        let midway = framing(&mut app);
+        assert_ne!(midway, small_framing);
+        assert_ne!(midway, large_framing);
+        assert_eq!(
+            app.world().resource::<CameraLimits>().max_zoom_scale,
+            large_framing.scale,
+            "Limits describe the level being moved to"
+        );
+
+        // Cancel: cycling back restarts from the current framing
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .set_current_index(0);
+        step(&mut app, 0.0);
+        let restarted = tween(&app).expect("Cycling should restart the transition");
+        assert_eq!(restarted.from, midway);
+        assert_eq!(restarted.to, small_framing);
+        assert_eq!(restarted.elapsed, 0.0);
+
+        // Complete: the camera lands exactly on the new framing
+        for _ in 0..3 {
+            step(&mut app, 0.2);
+        }
+        assert!(tween(&app).is_none());
+        assert_eq!(framing(&mut app), small_framing);
+    }
+
+    #[test]
+    fn test_only_switches_and_new_bounds_move_the_camera() {
+        let level = LevelBuilder::new().dimensions(8, 6).build().unwrap();
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<CameraLimits>()
+            .init_resource::<CameraZoomState>()
+            .init_resource::<CameraRotationState>()
+            .init_resource::<HexGridRenderConfig>()
+            .insert_resource(LevelsResource::new(vec![level]))
+            .add_systems(Startup, setup_camera)
+            .add_systems(Update, on_level_change_system);
+        app.world_mut().spawn(Window::default());
+        app.update();
+        let is_framing = |app: &App| {
+            matches!(
+                app.world().resource::<CameraRotationState>().rotation_mode,
+                RotationMode::Framing(_)
+            )
+        };
+
+        // Adding a level or editing one that is not shown keeps the camera
+        let index = app
+            .world_mut()
+            .resource_mut::<LevelsResource>()
+            .add_level(LevelBuilder::new().dimensions(20, 20).build().unwrap());
+        app.update();
+        assert!(!is_framing(&app));
+        app.world_mut().resource_mut::<LevelsResource>().levels[index]
+            .set_height(Hex::new(3, 3), 9.0)
+            .unwrap();
+        app.update();
+        assert!(!is_framing(&app));
+
+        // An edit that leaves the bounds alone keeps it too
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .current_level_mut()
+            .set_walkable(Hex::new(1, 1), false)
+            .unwrap();
+        app.update();
+        assert!(!is_framing(&app));
+
+        // New bounds of the current level reframe it
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .current_level_mut()
+            .set_height(Hex::new(2, 2), 6.0)
+            .unwrap();
+        app.update();
+        assert!(is_framing(&app));
+    }
+
+    #[test]
+    fn test_camera_tween_eases_and_lands_on_target() {
+        let from = CameraFraming {
+            translation: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            scale: 0.1,
+        };
+        let to = CameraFraming {
+            translation: Vec3::new(4.0, 20.0, -4.0),
+            rotation: default_camera_rotation(),
+            scale: 0.05,
+        };
+        let mut tween = CameraTween::new(from, to);
+        let early = tween.advance(CAMERA_FRAMING_DURATION * 0.1);
+        // Eases in: a tenth of the time covers less than a tenth of the way
+        assert!(early.translation.x > 0.0 && early.translation.x < 0.4);
+        let middle = tween.advance(CAMERA_FRAMING_DURATION * 0.4);
+        assert!((middle.translation - Vec3::new(2.0, 10.0, -2.0)).length() < 1e-4);
+        assert!(!tween.is_finished());
+        assert_eq!(tween.advance(1.0), to);
+        assert!(tween.is_finished());
+    }
+
+    #[test]
+    fn test_zoom_easing_reaches_target_exactly() {
+        let speed = CameraZoomState::default().speed;
+        let first = ease_zoom_scale(0.1, 0.05, speed, 1.0 / 60.0);
+        assert!(first < 0.1 && first > 0.05, "Eases without overshooting");
+        // Faster easing covers more of the gap per frame
+        assert!(ease_zoom_scale(0.1, 0.05, speed * 2.0, 1.0 / 60.0) < first);
+        assert_eq!(ease_zoom_scale(0.05, 0.05, speed, 1.0 / 60.0), 0.05);
+
+        for (start, target) in [(0.1, 0.05), (0.01, 0.2)] {
+            let mut scale = start;
+            let mut frames = 0;
+            while scale != target {
+                scale = ease_zoom_scale(scale, target, speed, 1.0 / 60.0);
+                frames += 1;
+                assert!(frames < 120, "Easing from {start} never settled");
+            }
+        }
+        // A long frame lands directly on the target
+        assert_eq!(ease_zoom_scale(0.1, 0.05, speed, 2.0), 0.05);
+    }
+
+    #[test]
+    fn test_zoom_steps_are_proportional_and_clamped() {
+        let limits = CameraLimits {
+            min_zoom_scale: 0.005,
+            max_zoom_scale: 0.2,
+            ..default()
+        };
+        // The same notch changes near and far scales by the same ratio
+        let near = scroll_zoom_scale(0.01, MouseScrollUnit::Line, 1.0, &limits);
+        let far = scroll_zoom_scale(0.1, MouseScrollUnit::Line, 1.0, &limits);
+        assert!(near < 0.01 && (near / 0.01 - far / 0.1).abs() < 1e-5);
+        let back = scroll_zoom_scale(near, MouseScrollUnit::Line, -1.0, &limits);
+        assert!((back - 0.01).abs() < 1e-6, "Opposite notches cancel out");
+
+        // A notch is worth many trackpad pixels
+        let pixel = scroll_zoom_scale(0.1, MouseScrollUnit::Pixel, 1.0, &limits);
+        assert!(pixel < 0.1 && pixel > far);
+
+        // Limits come from the level rather than fixed constants
+        assert_eq!(
+            scroll_zoom_scale(0.19, MouseScrollUnit::Line, -10.0, &limits),
+            0.2
+        );
+        assert_eq!(
+            scroll_zoom_scale(0.006, MouseScrollUnit::Pixel, 500.0, &limits),
+            0.005
+        );
+    }
+
+    #[test]
+    fn test_zoom_shift_keeps_anchor_under_cursor() {
+        // Looking straight down, the cursor ray starts 2 units right of center
+        let top_down = Transform::from_xyz(0.0, 20.0, 0.0).looking_to(Vec3::NEG_Y, Vec3::Z);
+        let offset = top_down.right() * 2.0;
+        let anchor = Vec3::new(offset.x, 1.0, offset.z);
+        let shift = zoom_to_cursor_shift(&top_down, offset, anchor, 0.5);
+        assert!((shift - offset * 0.5).length() < 1e-5, "{shift}");
+
+        // Tilted camera: after the shift the scaled cursor ray passes the anchor
+        let tilted = Transform::from_xyz(4.5, 20.0, -4.5).with_rotation(
+            Quat::from_rotation_y(-45.0_f32.to_radians())
+                * Quat::from_rotation_x(-45.0_f32.to_radians()),
+        );
+        let offset = tilted.right() * 1.5 + tilted.up() * -0.8;
+        let anchor = calculate_camera_focus_point(
+            &tilted.with_translation(tilted.translation + offset),
+            &crate::level::Level::new("Flat".to_string(), 3, 3),
+            None,
+        );
+        for ratio in [0.5, 2.0] {
+            let shift = zoom_to_cursor_shift(&tilted, offset, anchor, ratio);
+            assert_eq!(shift.y, 0.0, "Zooming keeps the camera height");
+            let origin = tilted.translation + shift + offset * ratio;
+            let ray = Vec3::from(tilted.forward());
+            let closest = origin + ray * (anchor - origin).dot(ray);
+            assert!((closest - anchor).length() < 1e-4, "Missed by {closest}");
+        }
+        assert_eq!(
+            zoom_to_cursor_shift(&tilted, offset, anchor, 1.0),
+            Vec3::ZERO
+        );
+    }
+
+    #[test]
+    fn test_camera_outside_radius_is_pulled_back() {
+        let offset = Vec2::new(0.0, 10.0);
+        assert_eq!(pull_within_radius(offset, 12.0, 0.1), offset);
+
+        let pulled = pull_within_radius(offset, 4.0, 0.05);
+        assert!(pulled.y < 10.0 && pulled.y > 4.0, "{pulled} should ease in");
+        assert_eq!(pulled.x, 0.0);
+        assert_eq!(pull_within_radius(offset, 4.0, 5.0), Vec2::new(0.0, 4.0));
+
+        // At maximum zoom the radius is zero and the camera returns home
+        let mut offset = Vec2::new(3.0, -4.0);
+        for _ in 0..120 {
+            offset = pull_within_radius(offset, 0.0, 1.0 / 60.0);
+        }
+        assert_eq!(offset, Vec2::ZERO);
+        // Still outside the radius, it may only move back in
+        assert_eq!(
+            soft_clamp_pan(Vec2::new(5.0, 0.0), Vec2::new(1.0, 0.0), 2.0),
+            Vec2::ZERO
+        );
+    }
+
+    #[test]
+    fn test_raycast_hits_hex_top_for_each_orientation() {
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            let level = LevelBuilder::new()
+                .dimensions(5, 5)
+                .flat(1.0)
+                .with_height(Hex::new(2, 2), 3.0)
+                .orientation(orientation)
+                .hex_scale(2.0)
+                .build()
+                .unwrap();
+            let target = level.hex_layout().hex_to_world_pos(Hex::new(2, 2));
+
+            // Straight down onto the raised hex center
+            let hit = raycast_hex_surfaces(
+                Vec3::new(target.x, 20.0, target.y),
+                Vec3::NEG_Y,
+                &level,
+                None,
+            )
+            .expect("Ray should hit the raised hex");
+            assert!((hit - Vec3::new(target.x, 3.0, target.y)).length() < 0.0001);
+
+            // Off the map entirely
+            assert!(
+                raycast_hex_surfaces(Vec3::new(-100.0, 20.0, -100.0), Vec3::NEG_Y, &level, None)
+                    .is_none()
+            );
+        }
+    }
+
+    #[test]
+    fn test_raycast_along_ray_matches_testing_every_hex() {
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            let mut builder = LevelBuilder::new()
+                .dimensions(16, 12)
+                .orientation(orientation);
+            for q in 0..16 {
+                for r in 0..12 {
+                    let height = ((q * 7 + r * 13) % 9) as f32 * 0.75 - 1.0;
+                    builder = builder.with_height(Hex::new(q, r), height);
+                }
+            }
+            let level = builder.build().unwrap();
+            let layout = level.hex_layout();
+
+            // Highest top hit by the ray, found by testing every hex
+            let every_hex = |camera_pos: Vec3, direction: Vec3| {
+                level
+                    .hex_iter()
+                    .filter_map(|hex| {
+                        let height = level.get_height(hex);
+                        let t = (height - camera_pos.y) / direction.y;
+                        let point = camera_pos + direction * t;
+                        (t >= 0.0 && level.world_to_hex(Vec2::new(point.x, point.z)) == Some(hex))
+                            .then_some(Vec3::new(point.x, height, point.z))
+                    })
+                    .max_by(|a, b| a.y.total_cmp(&b.y))
+            };
+
+            let mut hits = 0;
+            for step in 0..48 {
+                let yaw = step as f32 * 0.4;
+                let pitch = 0.3 + (step % 5) as f32 * 0.25;
+                let direction =
+                    Quat::from_rotation_y(yaw) * Quat::from_rotation_x(-pitch) * Vec3::NEG_Z;
+                let target = layout.hex_to_world_pos(Hex::new(step % 16, (step * 5) % 12));
+                let camera_pos = Vec3::new(target.x, 2.0, target.y) - direction * 30.0;
+                let hit = raycast_hex_surfaces(camera_pos, direction, &level, None);
+                assert_eq!(
+                    hit,
+                    every_hex(camera_pos, direction),
+                    "{orientation:?} ray {step}"
+                );
+                hits += usize::from(hit.is_some());
+            }
+            assert!(hits > 24, "Only {hits} rays hit the level");
+        }
+    }
+
+    #[test]
+    fn test_raycast_picks_overlay_above_ground() {
+        let mut level = LevelBuilder::new()
+            .dimensions(5, 5)
+            .flat(1.0)
+            .build()
+            .unwrap();
+        level.overlays = vec![OverlayHex::new(Hex::new(2, 2), 3.0, 0.5)];
+        let layout = level.hex_layout();
+
+        let bridge = layout.hex_to_world_pos(Hex::new(2, 2));
+        let hit = raycast_hex_surfaces(
+            Vec3::new(bridge.x, 20.0, bridge.y),
+            Vec3::NEG_Y,
+            &level,
+            None,
+        )
+        .expect("Ray should hit the bridge");
+        assert!((hit.y - 3.5).abs() < 0.0001, "Hit {hit:?}");
+
+        // Neighboring hexes without an overlay still hit the ground
+        let ground = layout.hex_to_world_pos(Hex::new(3, 2));
+        let hit = raycast_hex_surfaces(
+            Vec3::new(ground.x, 20.0, ground.y),
+            Vec3::NEG_Y,
+            &level,
+            None,
+        )
+        .expect("Ray should hit the ground");
+        assert!((hit.y - 1.0).abs() < 0.0001, "Hit {hit:?}");
+    }
+
+    #[test]
+    fn test_raycast_skips_void_holes() {
+        use crate::level::render_config::VoidStyle;
+
+        let level = LevelBuilder::new()
+            .dimensions(5, 5)
+            .flat(1.0)
+            .with_height(Hex::new(2, 2), -3.0)
+            .build()
+            .unwrap();
+        let pit = level.hex_layout().hex_to_world_pos(Hex::new(2, 2));
+        let camera_pos = Vec3::new(pit.x, 20.0, pit.y);
+        let hole = VoidRendering {
+            threshold: 0.0,
+            style: VoidStyle::Hole,
+        };
+
+        let hit = raycast_hex_surfaces(camera_pos, Vec3::NEG_Y, &level, None)
+            .expect("Without void the pit floor is hit");
+        assert!((hit.y + 3.0).abs() < 0.0001);
+        assert!(raycast_hex_surfaces(camera_pos, Vec3::NEG_Y, &level, Some(hole)).is_none());
+
+        let tile = VoidRendering {
+            style: VoidStyle::Tile,
+            ..hole
+        };
+        let hit = raycast_hex_surfaces(camera_pos, Vec3::NEG_Y, &level, Some(tile))
+            .expect("Void tiles are hit at the threshold");
+        assert!(hit.y.abs() < 0.0001);
+
+        // The focus point falls back to the ground plane through the hole
+        let transform = Transform::from_translation(camera_pos).looking_to(Vec3::NEG_Y, Vec3::Z);
+        let focus = calculate_camera_focus_point(&transform, &level, Some(hole));
+        assert!(focus.abs_diff_eq(Vec3::new(pit.x, 0.0, pit.y), 0.0001));
+    }
+}
@@ -4,13 +4,35 @@
 //! All debug aids can be toggled with F1 key.
 
 use bevy::prelude::*;
+use hexx::Hex;
 use tracing::debug;
 
 use crate::{
-    colors::YELLOW_ACCENT,
+    colors::{HEX_EDGE_GREEN, YELLOW_ACCENT},
+    level::Level,
+    level::render_config::HexGridRenderConfig,
     rendering::camera::{TacticalCamera, calculate_camera_focus_point},
+    rendering::highlight::hex_top_height,
 };
 
+/// Radius in hexes around the camera focus that gets coordinate and height
+/// labels, keeping huge levels to at most 91 labels
+pub const HEX_LABEL_RADIUS: u32 = 5;
+
+/// Height of hex labels above the surface they describe
+const HEX_LABEL_LIFT: f32 = 0.3;
+
+/// Levels with more hexes only get grid lines within [`GRID_OVERLAY_RADIUS`]
+/// of the camera focus
+pub const GRID_OVERLAY_MAX_HEXES: usize = 64 * 64;
+
+/// Radius in hexes of the grid lines drawn on levels above
+/// [`GRID_OVERLAY_MAX_HEXES`]
+pub const GRID_OVERLAY_RADIUS: u32 = 24;
+
+/// Height of grid lines above the hex tops, below the region outlines
+const GRID_OVERLAY_LIFT: f32 = 0.01;
+
 /// Component marker for debug crosshair UI elements
 #[derive(Component)]
 pub struct DebugCrosshair;
@@ -27,6 +49,14 @@ pub struct DebugFocusText;
 #[derive(Component)]
 pub struct DebugDistanceText;
 
+/// Debug label showing a hex's coordinates and height, kept over `anchor`
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HexLabel {
+    pub hex: Hex,
+    /// World position the label is projected from
+    pub anchor: Vec3,
+}
+
 /// Resource to track whether debug aids should be visible
 #[derive(Resource, Default)]
 pub struct DebugAidVisibility {
@@ -39,6 +69,7 @@ pub fn camera_intersection_debug_system(
     debug_visibility: Res<DebugAidVisibility>,
     camera_query: Query<&Transform, With<TacticalCamera>>,
     levels_resource: Res<crate::level::LevelsResource>,
+    render_config: Res<HexGridRenderConfig>,
 ) {
     // Only render if debug aids are visible
     if !debug_visibility.visible {
@@ -48,13 +79,109 @@ pub fn camera_intersection_debug_system(
     if let Ok(transform) = camera_query.single() {
         let level = levels_resource.current_level();
         // Calculate focus point intersection with hex surfaces
-        let focus_point = calculate_camera_focus_point(transform, level);
+        let focus_point =
+            calculate_camera_focus_point(transform, level, render_config.active_void());
 
         // Draw sphere at intersection point - this shows where the ray hits the hex surface
         gizmos.sphere(focus_point, 0.15, Color::srgba(1.0, 0.0, 0.0, 0.9)); // Red sphere
     }
 }
 
+/// Distinct debug color for the region at `index`
+///
+/// Hues are spaced by the golden angle so neighboring indices never look alike.
+fn region_debug_color(index: usize) -> Color {
+    Color::hsla((index as f32 * 137.5) % 360.0, 0.85, 0.55, 0.9)
+}
+
+/// System to outline region hexes in per-region colors so designers can verify zones
+pub fn region_debug_system(
+    mut gizmos: Gizmos,
+    debug_visibility: Res<DebugAidVisibility>,
+    levels_resource: Res<crate::level::LevelsResource>,
+) {
+    if !debug_visibility.visible {
+        return;
+    }
+
+    let level = levels_resource.current_level();
+    let hex_layout = level.hex_layout();
+
+    for (index, region) in level.regions.iter().enumerate() {
+        let color = region_debug_color(index);
+        for hex in region.iter_hexes() {
+            let Some(height) = level.try_get_height(hex) else {
+                continue;
+            };
+            // Lift slightly above the surface to avoid z-fighting with the column top
+            let y = height + 0.02;
+            let corners = hex_layout
+                .hex_corners(hex)
+                .map(|corner| Vec3::new(corner.x, y, corner.y));
+            gizmos.linestrip(corners.into_iter().chain([corners[0]]), color);
+        }
+    }
+}
+
+/// Hexes of `level` that get grid lines: all of them on small levels, those
+/// near `focus` on levels above [`GRID_OVERLAY_MAX_HEXES`]
+pub fn grid_overlay_hexes(level: &Level, focus: Hex) -> impl Iterator<Item = Hex> + '_ {
+    let hex_count = (level.width * level.height) as usize;
+    let (all, near) = if hex_count > GRID_OVERLAY_MAX_HEXES {
+        (None, Some(focus.range(GRID_OVERLAY_RADIUS)))
+    } else {
+        (Some(level.hex_iter()), None)
+    };
+    all.into_iter()
+        .flatten()
+        .chain(near.into_iter().flatten())
+        .filter(|&hex| level.contains(hex))
+}
+
+/// System to outline every hex top with gizmos while debug aids are visible
+///
+/// Heights come straight from the current level, so the grid shows even where
+/// no column meshes are spawned. The focus hex is only raycast again once the
+/// camera, level, or void settings change.
+pub fn hex_grid_overlay_system(
+    mut gizmos: Gizmos,
+    debug_visibility: Res<DebugAidVisibility>,
+    levels_resource: Res<crate::level::LevelsResource>,
+    render_config: Res<HexGridRenderConfig>,
+    camera_query: Query<Ref<Transform>, With<TacticalCamera>>,
+    mut focus_cache: Local<Option<Hex>>,
+) {
+    if !debug_visibility.visible {
+        return;
+    }
+
+    let level = levels_resource.current_level();
+    let hex_layout = level.hex_layout();
+    if let Ok(transform) = camera_query.single()
+        && (focus_cache.is_none()
+            || transform.is_changed()
+            || debug_visibility.is_changed()
+            || levels_resource.is_changed()
+            || render_config.is_changed())
+    {
+        let focus_point =
+            calculate_camera_focus_point(&transform, level, render_config.active_void());
+        *focus_cache = Some(level.world_to_hex_clamped(Vec2::new(focus_point.x, focus_point.z)));
+    }
+    let focus = focus_cache.unwrap_or_default();
+
+    for hex in grid_overlay_hexes(level, focus) {
+        let Some(top) = hex_top_height(level, hex) else {
+            continue;
+        };
+        let y = top + GRID_OVERLAY_LIFT;
+        let corners = hex_layout
+            .hex_corners(hex)
+            .map(|corner| Vec3::new(corner.x, y, corner.y));
+        gizmos.linestrip(corners.into_iter().chain([corners[0]]), HEX_EDGE_GREEN);
+    }
+}
+
 /// System to spawn/despawn crosshair UI elements
 pub fn debug_crosshair_system(
     mut commands: Commands,
@@ -224,12 +351,14 @@ pub fn debug_text_update_system(
     mut focus_text_query: Query<&mut Text, (With<DebugFocusText>, Without<DebugCameraText>)>,
     mut distance_text_query: DistanceTextQuery,
     levels_resource: Res<crate::level::LevelsResource>,
+    render_config: Res<HexGridRenderConfig>,
 ) {
     // Only update when camera transform has changed
     if let Ok(transform) = camera_query.single() {
         let camera_pos = transform.translation;
         let level = levels_resource.current_level();
-        let focus_point = calculate_camera_focus_point(transform, level);
+        let focus_point =
+            calculate_camera_focus_point(transform, level, render_config.active_void());
         let distance = camera_pos.distance(focus_point);
 
         // Update camera position text
@@ -258,3 +387,182 @@ pub fn debug_text_update_system(
         }
     }
 }
+
+/// Hexes of `level` within [`HEX_LABEL_RADIUS`] of `center`
+pub fn labeled_hexes(level: &Level, center: Hex) -> Vec<Hex> {
+    center
+        .range(HEX_LABEL_RADIUS)
+        .filter(|&hex| level.contains(hex))
+        .collect()
+}
+
+/// Label text for `hex`: axial coordinates over the column height
+pub fn hex_label_text(hex: Hex, height: f32) -> String {
+    format!("({q}, {r})\n{height:.2}", q = hex.x, r = hex.y)
+}
+
+/// System to spawn hex labels around the camera focus while debug aids are
+/// visible
+///
+/// Labels are respawned when the focused hex or the level changes, and all of
+/// them are despawned when debug aids are hidden.
+pub fn hex_label_spawn_system(
+    mut commands: Commands,
+    debug_visibility: Res<DebugAidVisibility>,
+    levels_resource: Res<crate::level::LevelsResource>,
+    render_config: Res<HexGridRenderConfig>,
+    camera_query: Query<&Transform, With<TacticalCamera>>,
+    label_query: Query<Entity, With<HexLabel>>,
+    mut labeled_center: Local<Option<Hex>>,
+) {
+    let center = camera_query
+        .single()
+        .ok()
+        .filter(|_| debug_visibility.visible)
+        .map(|transform| {
+            let level = levels_resource.current_level();
+            let focus_point =
+                calculate_camera_focus_point(transform, level, render_config.active_void());
+            level.world_to_hex_clamped(Vec2::new(focus_point.x, focus_point.z))
+        });
+    if center == *labeled_center && !levels_resource.is_changed() {
+        return;
+    }
+    *labeled_center = center;
+
+    for entity in &label_query {
+        commands.entity(entity).despawn();
+    }
+    let Some(center) = center else {
+        debug!("Despawned hex labels");
+        return;
+    };
+
+    let level = levels_resource.current_level();
+    let hex_layout = level.hex_layout();
+    let hexes = labeled_hexes(level, center);
+    for &hex in &hexes {
+        let Some(top) = hex_top_height(level, hex) else {
+            continue;
+        };
+        let world_pos = hex_layout.hex_to_world_pos(hex);
+        commands.spawn((
+            Text::new(hex_label_text(hex, level.get_height(hex))),
+            TextFont {
+                font_size: 11.0,
+                ..default()
+            },
+            TextColor(YELLOW_ACCENT),
+            TextLayout::new_with_justify(JustifyText::Center),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            // Shown once positioned over the hex
+            Visibility::Hidden,
+            HexLabel {
+                hex,
+                anchor: Vec3::new(world_pos.x, top + HEX_LABEL_LIFT, world_pos.y),
+            },
+        ));
+    }
+    debug!(
+        "Spawned {count} hex labels around {center:?}",
+        count = hexes.len()
+    );
+}
+
+/// System to project hex labels onto the screen above their hexes, hiding
+/// labels that fall outside the view
+pub fn hex_label_position_system(
+    camera_query: Query<(&Camera, &GlobalTransform), With<TacticalCamera>>,
+    mut label_query: Query<(&HexLabel, &mut Node, &mut Visibility)>,
+) {
+    let Ok((camera, camera_transform)) = camera_query.single() else {
+        return;
+    };
+
+    for (label, mut node, mut visibility) in &mut label_query {
+        match camera.world_to_viewport(camera_transform, label.anchor) {
+            Ok(position) => {
+                node.left = Val::Px(position.x);
+                node.top = Val::Px(position.y);
+                *visibility = Visibility::Inherited;
+            }
+            Err(_) => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::LevelsResource;
+
+    #[test]
+    fn test_hex_labels_are_capped_around_focus() {
+        let level = Level::new("Huge".to_string(), 200, 200);
+        assert_eq!(labeled_hexes(&level, Hex::new(100, 100)).len(), 91);
+        // Corners only label the hexes inside the level
+        let corner = labeled_hexes(&level, Hex::new(0, 0));
+        assert!(corner.len() < 91 && corner.iter().all(|&hex| level.contains(hex)));
+
+        assert_eq!(hex_label_text(Hex::new(3, -1), 2.5), "(3, -1)\n2.50");
+    }
+
+    #[test]
+    fn test_grid_overlay_covers_small_levels_and_caps_huge_ones() {
+        let small = Level::new("Small".to_string(), 10, 8);
+        assert_eq!(grid_overlay_hexes(&small, Hex::new(50, 50)).count(), 80);
+
+        let huge = Level::new("Huge".to_string(), 200, 200);
+        let near: Vec<Hex> = grid_overlay_hexes(&huge, Hex::new(100, 100)).collect();
+        assert_eq!(near.len(), 1801, "Radius 24 covers 1801 hexes");
+        assert!(
+            near.iter()
+                .all(|hex| hex.unsigned_distance_to(Hex::new(100, 100)) <= GRID_OVERLAY_RADIUS)
+        );
+        let corner = grid_overlay_hexes(&huge, Hex::ZERO).count();
+        assert!(corner < 1801 && corner > 0);
+    }
+
+    #[test]
+    fn test_hex_labels_follow_debug_visibility_and_level() {
+        let mut app = App::new();
+        app.init_resource::<DebugAidVisibility>()
+            .init_resource::<HexGridRenderConfig>()
+            .insert_resource(LevelsResource::new(vec![
+                Level::new("Small".to_string(), 3, 3),
+                Level::new("Large".to_string(), 30, 30),
+            ]))
+            .add_systems(Update, hex_label_spawn_system);
+        app.world_mut().spawn((
+            Transform::from_xyz(1.0, 20.0, 1.0).looking_to(Vec3::NEG_Y, Vec3::Z),
+            TacticalCamera,
+        ));
+        let label_count = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<(), With<HexLabel>>()
+                .iter(app.world())
+                .count()
+        };
+
+        app.update();
+        assert_eq!(label_count(&mut app), 0, "Hidden until debug aids are on");
+
+        app.world_mut().resource_mut::<DebugAidVisibility>().visible = true;
+        app.update();
+        assert_eq!(label_count(&mut app), 9);
+
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .set_current_index(1);
+        app.update();
+        let large = label_count(&mut app);
+        assert!(large > 9 && large <= 91, "{large} labels");
+
+        app.world_mut().resource_mut::<DebugAidVisibility>().visible = false;
+        app.update();
+        assert_eq!(label_count(&mut app), 0);
+    }
+}
@@ -0,0 +1,536 @@
+//! Hex Highlights
+//!
+//! Resolves the hex under the mouse cursor by casting the cursor's viewport ray
+//! from the [`TacticalCamera`] against the level's surfaces, and tints that
+//! hex's top face so players and designers can see what they point at.
+//!
+//! Left-clicking selects the hovered hex, outlined by a ring until clicking
+//! empty space, pressing Escape, or switching levels. Selection changes are
+//! announced as [`HexSelected`] and [`HexDeselected`] events.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::render::{
+    mesh::{Indices, PrimitiveTopology},
+    render_asset::RenderAssetUsages,
+};
+use bevy::window::PrimaryWindow;
+use hexx::{Hex, HexLayout, InsetOptions, InsetScaleMode, PlaneMeshBuilder};
+
+use crate::colors::{GREEN_HOVER, UNIT_SELECTED};
+use crate::level::render_config::{HexGridRenderConfig, VoidRendering};
+use crate::level::{Level, LevelsResource};
+use crate::rendering::camera::{TacticalCamera, raycast_hex_surfaces};
+
+/// Height of highlights above the face they tint, avoiding z-fighting
+const HIGHLIGHT_LIFT: f32 = 0.01;
+
+/// Height of the selection ring, above the hover tint so both stay visible
+const SELECTION_LIFT: f32 = 0.02;
+
+/// Share of the hex radius covered by the selection ring
+const SELECTION_RING_WIDTH: f32 = 0.2;
+
+/// Hex currently under the mouse cursor, if any
+///
+/// `None` while the cursor is off the grid, outside the window, or the window
+/// is unfocused.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HoveredHex(pub Option<Hex>);
+
+/// Marker for the tinted face following [`HoveredHex`]
+#[derive(Component)]
+pub struct HoverHighlight;
+
+/// Hex selected by the last click, if any
+///
+/// Shared by the editor's tools and the game's unit commands.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SelectedHex(pub Option<Hex>);
+
+/// Marker for the ring drawn around [`SelectedHex`]
+#[derive(Component)]
+pub struct SelectionHighlight;
+
+/// A hex became the selection
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexSelected {
+    pub hex: Hex,
+}
+
+/// A hex stopped being the selection, replaced by another or cleared
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexDeselected {
+    pub hex: Hex,
+}
+
+/// Writes selection changes to [`SelectedHex`] together with their events
+#[derive(SystemParam)]
+pub struct SelectionWriter<'w> {
+    selected: ResMut<'w, SelectedHex>,
+    selected_events: EventWriter<'w, HexSelected>,
+    deselected_events: EventWriter<'w, HexDeselected>,
+}
+
+impl SelectionWriter<'_> {
+    /// Currently selected hex
+    pub fn get(&self) -> Option<Hex> {
+        self.selected.0
+    }
+
+    /// Select `hex`, or clear the selection with `None`; no-op if unchanged
+    pub fn set(&mut self, hex: Option<Hex>) {
+        let previous = self.selected.0;
+        if previous == hex {
+            return;
+        }
+        self.selected.0 = hex;
+        if let Some(previous) = previous {
+            self.deselected_events
+                .write(HexDeselected { hex: previous });
+        }
+        if let Some(hex) = hex {
+            self.selected_events.write(HexSelected { hex });
+        }
+    }
+}
+
+/// Hex whose top surface `ray` sees first, including overlay tops
+pub fn hex_under_ray(ray: Ray3d, level: &Level, void: Option<VoidRendering>) -> Option<Hex> {
+    let hit = raycast_hex_surfaces(ray.origin, *ray.direction, level, void)?;
+    level.world_to_hex(Vec2::new(hit.x, hit.z))
+}
+
+/// Height of the highest walkable surface at `hex`, the column or an overlay
+pub fn hex_top_height(level: &Level, hex: Hex) -> Option<f32> {
+    let ground = level.try_get_height(hex)?;
+    // Scans the overlays in place; this runs for every hex of the debug grid
+    Some(
+        level
+            .overlays
+            .iter()
+            .filter(|overlay| overlay.coord() == hex)
+            .map(|overlay| overlay.top())
+            .fold(ground, f32::max),
+    )
+}
+
+/// Flat hexagon mesh matching a top face of `layout`, centered on the origin
+pub fn create_hex_face_mesh(layout: &HexLayout) -> Mesh {
+    let mesh_info = PlaneMeshBuilder::new(layout).center_aligned().build();
+
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, mesh_info.vertices)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, mesh_info.normals)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, mesh_info.uvs)
+    .with_inserted_indices(Indices::U16(mesh_info.indices))
+}
+
+/// Hexagonal ring along the border of a top face of `layout`
+pub fn create_hex_ring_mesh(layout: &HexLayout) -> Mesh {
+    let mesh_info = PlaneMeshBuilder::new(layout)
+        .with_inset_options(InsetOptions {
+            keep_inner_face: false,
+            scale: SELECTION_RING_WIDTH,
+            mode: InsetScaleMode::Centroid,
+        })
+        .center_aligned()
+        .build();
+
+    Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, mesh_info.vertices)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, mesh_info.normals)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, mesh_info.uvs)
+    .with_inserted_indices(Indices::U16(mesh_info.indices))
+}
+
+/// Place `transform` and `visibility` `lift` above the top face of `hex`, or
+/// hide the highlight if there is no such hex
+fn place_highlight(
+    level: &Level,
+    hex: Option<Hex>,
+    lift: f32,
+    transform: &mut Transform,
+    visibility: &mut Visibility,
+) {
+    match hex.and_then(|hex| Some((hex, hex_top_height(level, hex)?))) {
+        Some((hex, top)) => {
+            let world_pos = level.hex_layout().hex_to_world_pos(hex);
+            transform.translation = Vec3::new(world_pos.x, top + lift, world_pos.y);
+            *visibility = Visibility::Visible;
+        }
+        None => *visibility = Visibility::Hidden,
+    }
+}
+
+/// System to spawn the hidden hover highlight
+pub fn spawn_hover_highlight(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    levels_resource: Res<LevelsResource>,
+) {
+    commands.spawn((
+        Mesh3d(meshes.add(create_hex_face_mesh(
+            &levels_resource.current_level().hex_layout(),
+        ))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: GREEN_HOVER.with_alpha(0.5),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        })),
+        Transform::IDENTITY,
+        Visibility::Hidden,
+        HoverHighlight,
+    ));
+}
+
+/// System resolving [`HoveredHex`] from the cursor position each frame
+pub fn hovered_hex_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<TacticalCamera>>,
+    levels_resource: Res<LevelsResource>,
+    render_config: Res<HexGridRenderConfig>,
+    mut hovered: ResMut<HoveredHex>,
+) {
+    let cursor = windows
+        .single()
+        .ok()
+        .filter(|window| window.focused)
+        .and_then(Window::cursor_position);
+    let hex = cursor
+        .zip(camera_query.single().ok())
+        .and_then(|(cursor, (camera, transform))| camera.viewport_to_world(transform, cursor).ok())
+        .and_then(|ray| {
+            hex_under_ray(
+                ray,
+                levels_resource.current_level(),
+                render_config.active_void(),
+            )
+        });
+
+    // Only write on change so the highlight system can skip idle frames
+    hovered.set_if_neq(HoveredHex(hex));
+}
+
+/// System moving the hover highlight onto [`HoveredHex`], reshaping it when the
+/// level's hex layout may have changed
+pub fn hover_highlight_system(
+    hovered: Res<HoveredHex>,
+    levels_resource: Res<LevelsResource>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut highlight_query: Query<
+        (&mut Mesh3d, &mut Transform, &mut Visibility),
+        With<HoverHighlight>,
+    >,
+) {
+    if !hovered.is_changed() && !levels_resource.is_changed() {
+        return;
+    }
+    let Ok((mut mesh, mut transform, mut visibility)) = highlight_query.single_mut() else {
+        return;
+    };
+
+    let level = levels_resource.current_level();
+    if levels_resource.is_changed() {
+        mesh.0 = meshes.add(create_hex_face_mesh(&level.hex_layout()));
+    }
+    place_highlight(
+        level,
+        hovered.0,
+        HIGHLIGHT_LIFT,
+        &mut transform,
+        &mut visibility,
+    );
+}
+
+/// System to spawn the hidden selection ring
+pub fn spawn_selection_highlight(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    levels_resource: Res<LevelsResource>,
+) {
+    commands.spawn((
+        Mesh3d(meshes.add(create_hex_ring_mesh(
+            &levels_resource.current_level().hex_layout(),
+        ))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: UNIT_SELECTED,
+            unlit: true,
+            ..default()
+        })),
+        Transform::IDENTITY,
+        Visibility::Hidden,
+        SelectionHighlight,
+    ));
+}
+
+/// System updating [`SelectedHex`] from clicks and Escape
+///
+/// A left click selects the hovered hex, or clears the selection over empty
+/// space. Switching levels clears it too, as do edits that remove the hex.
+pub fn hex_selection_system(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    hovered: Res<HoveredHex>,
+    levels_resource: Res<LevelsResource>,
+    mut selection: SelectionWriter,
+    mut last_level_index: Local<Option<usize>>,
+) {
+    let level_index = levels_resource.current_level_index;
+    let level_switched = last_level_index.replace(level_index) != Some(level_index);
+    if level_switched
+        || selection
+            .get()
+            .is_some_and(|hex| !levels_resource.current_level().contains(hex))
+    {
+        selection.set(None);
+    }
+
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        selection.set(None);
+    } else if mouse_input.just_pressed(MouseButton::Left) {
+        selection.set(hovered.0);
+    }
+}
+
+/// System moving the selection ring onto [`SelectedHex`], reshaping it when
+/// the level's hex layout may have changed
+pub fn selection_highlight_system(
+    selected: Res<SelectedHex>,
+    levels_resource: Res<LevelsResource>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut highlight_query: Query<
+        (&mut Mesh3d, &mut Transform, &mut Visibility),
+        With<SelectionHighlight>,
+    >,
+) {
+    if !selected.is_changed() && !levels_resource.is_changed() {
+        return;
+    }
+    let Ok((mut mesh, mut transform, mut visibility)) = highlight_query.single_mut() else {
+        return;
+    };
+
+    let level = levels_resource.current_level();
+    if levels_resource.is_changed() {
+        mesh.0 = meshes.add(create_hex_ring_mesh(&level.hex_layout()));
+    }
+    place_highlight(
+        level,
+        selected.0,
+        SELECTION_LIFT,
+        &mut transform,
+        &mut visibility,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::builder::LevelBuilder;
+    use crate::level::overlays::OverlayHex;
+
+    /// Isometric orientation the tactical camera spawns with
+    fn camera_transform(target: Vec3) -> Transform {
+        let rotation = Quat::from_rotation_y(-45.0_f32.to_radians())
+            * Quat::from_rotation_x(-45.0_f32.to_radians());
+        let transform = Transform::from_rotation(rotation);
+        transform.with_translation(target - *transform.forward() * 30.0)
+    }
+
+    /// Orthographic cursor ray through `target`, shifted by `offset` in view space
+    fn cursor_ray(target: Vec3, offset: Vec2) -> Ray3d {
+        let transform = camera_transform(target);
+        let origin =
+            transform.translation + *transform.right() * offset.x + *transform.up() * offset.y;
+        Ray3d::new(origin, transform.forward())
+    }
+
+    #[test]
+    fn test_ray_through_hex_center_picks_that_hex() {
+        let level = LevelBuilder::new()
+            .dimensions(6, 6)
+            .flat(1.0)
+            .build()
+            .unwrap();
+        let layout = level.hex_layout();
+
+        for hex in [Hex::new(0, 0), Hex::new(2, 3), Hex::new(5, 5)] {
+            let center = layout.hex_to_world_pos(hex);
+            let ray = cursor_ray(Vec3::new(center.x, 1.0, center.y), Vec2::ZERO);
+            assert_eq!(hex_under_ray(ray, &level, None), Some(hex));
+        }
+
+        // A cursor far to the side of the grid hovers nothing
+        let ray = cursor_ray(Vec3::new(2.0, 1.0, 2.0), Vec2::new(100.0, 0.0));
+        assert_eq!(hex_under_ray(ray, &level, None), None);
+    }
+
+    #[test]
+    fn test_tall_hex_occludes_hex_behind_it() {
+        let mut level = LevelBuilder::new()
+            .dimensions(6, 6)
+            .flat(1.0)
+            .build()
+            .unwrap();
+        let layout = level.hex_layout();
+        let behind = Hex::new(3, 3);
+        let target = layout.hex_to_world_pos(behind);
+        let ray = cursor_ray(Vec3::new(target.x, 1.0, target.y), Vec2::ZERO);
+
+        // Raise the hex where the ray crosses height 4 on its way down
+        let t = (4.0 - ray.origin.y) / ray.direction.y;
+        let crossing = ray.origin + *ray.direction * t;
+        let blocker = layout.world_pos_to_hex(Vec2::new(crossing.x, crossing.z));
+        assert_ne!(blocker, behind);
+        level.set_height(blocker, 4.0).unwrap();
+
+        assert_eq!(hex_under_ray(ray, &level, None), Some(blocker));
+    }
+
+    #[test]
+    fn test_highlight_follows_hovered_hex() {
+        let mut level = LevelBuilder::new()
+            .dimensions(4, 4)
+            .flat(1.0)
+            .build()
+            .unwrap();
+        level.overlays = vec![OverlayHex::new(Hex::new(1, 2), 3.0, 0.5)];
+        let layout = level.hex_layout();
+
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<HoveredHex>()
+            .insert_resource(LevelsResource::new(vec![level]))
+            .add_systems(Startup, spawn_hover_highlight)
+            .add_systems(Update, hover_highlight_system);
+        let highlight = |app: &mut App| {
+            let mut query = app
+                .world_mut()
+                .query_filtered::<(&Transform, &Visibility), With<HoverHighlight>>();
+            let (transform, visibility) = query.single(app.world()).unwrap();
+            (transform.translation, *visibility)
+        };
+
+        app.update();
+        assert_eq!(highlight(&mut app).1, Visibility::Hidden);
+
+        for (hex, top) in [(Hex::new(2, 1), 1.0), (Hex::new(1, 2), 3.5)] {
+            app.world_mut().resource_mut::<HoveredHex>().0 = Some(hex);
+            app.update();
+            let (translation, visibility) = highlight(&mut app);
+            let center = layout.hex_to_world_pos(hex);
+            assert_eq!(visibility, Visibility::Visible);
+            assert!(
+                translation.abs_diff_eq(Vec3::new(center.x, top + HIGHLIGHT_LIFT, center.y), 1e-5)
+            );
+        }
+
+        app.world_mut().resource_mut::<HoveredHex>().0 = None;
+        app.update();
+        assert_eq!(highlight(&mut app).1, Visibility::Hidden);
+    }
+
+    #[test]
+    fn test_clicks_and_escape_drive_selection() {
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<HoveredHex>()
+            .init_resource::<SelectedHex>()
+            .add_event::<HexSelected>()
+            .add_event::<HexDeselected>()
+            .insert_resource(LevelsResource::new(vec![
+                Level::new("First".to_string(), 4, 4),
+                Level::new("Second".to_string(), 4, 4),
+            ]))
+            .add_systems(Startup, spawn_selection_highlight)
+            .add_systems(
+                Update,
+                (hex_selection_system, selection_highlight_system).chain(),
+            );
+        app.update();
+
+        // Press for one frame, then release as the input plugin would
+        let click = |app: &mut App, hovered: Option<Hex>| {
+            app.world_mut().resource_mut::<HoveredHex>().0 = hovered;
+            app.world_mut()
+                .resource_mut::<ButtonInput<MouseButton>>()
+                .press(MouseButton::Left);
+            app.update();
+            let mut mouse = app.world_mut().resource_mut::<ButtonInput<MouseButton>>();
+            mouse.release(MouseButton::Left);
+            mouse.clear();
+        };
+        let events = |app: &mut App| {
+            let selected: Vec<Hex> = app
+                .world_mut()
+                .resource_mut::<Events<HexSelected>>()
+                .drain()
+                .map(|event| event.hex)
+                .collect();
+            let deselected: Vec<Hex> = app
+                .world_mut()
+                .resource_mut::<Events<HexDeselected>>()
+                .drain()
+                .map(|event| event.hex)
+                .collect();
+            (selected, deselected)
+        };
+        let selected = |app: &App| app.world().resource::<SelectedHex>().0;
+        let ring_visibility = |app: &mut App| {
+            let mut query = app
+                .world_mut()
+                .query_filtered::<&Visibility, With<SelectionHighlight>>();
+            *query.single(app.world()).unwrap()
+        };
+
+        click(&mut app, Some(Hex::new(1, 1)));
+        assert_eq!(selected(&app), Some(Hex::new(1, 1)));
+        assert_eq!(events(&mut app), (vec![Hex::new(1, 1)], vec![]));
+        assert_eq!(ring_visibility(&mut app), Visibility::Visible);
+
+        click(&mut app, Some(Hex::new(2, 3)));
+        assert_eq!(
+            events(&mut app),
+            (vec![Hex::new(2, 3)], vec![Hex::new(1, 1)])
+        );
+
+        // Clicking empty space clears the selection
+        click(&mut app, None);
+        assert_eq!(selected(&app), None);
+        assert_eq!(events(&mut app), (vec![], vec![Hex::new(2, 3)]));
+        assert_eq!(ring_visibility(&mut app), Visibility::Hidden);
+
+        click(&mut app, Some(Hex::new(0, 2)));
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Escape);
+        app.update();
+        assert_eq!(selected(&app), None);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .reset_all();
+
+        click(&mut app, Some(Hex::new(3, 0)));
+        events(&mut app);
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .set_current_index(1);
+        app.update();
+        assert_eq!(selected(&app), None, "Level switches clear the selection");
+        assert_eq!(events(&mut app), (vec![], vec![Hex::new(3, 0)]));
+    }
+}
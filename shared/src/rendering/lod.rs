@@ -0,0 +1,313 @@
+//! Grid Level of Detail
+//!
+//! Zoomed out on a big level, hex columns shrink below a pixel while still
+//! costing full geometry. Past a zoom threshold the detailed chunk meshes are
+//! hidden behind a single heightfield with one vertex per hex, and shown again
+//! when zooming back in. Entering and leaving use different thresholds so the
+//! swap does not flicker at the boundary.
+
+use bevy::prelude::*;
+use bevy::render::{mesh::PrimitiveTopology, render_asset::RenderAssetUsages};
+use hexx::Hex;
+use tracing::info;
+
+use crate::colors::terrain_color;
+use crate::level::chunks::HexChunk;
+use crate::level::mesh::{GridSpawnAssets, TerrainColoring, mesh_indices};
+use crate::level::{Level, LevelsResource};
+use crate::rendering::camera::{CameraLimits, TacticalCamera};
+
+/// When the low-poly terrain replaces the hex columns
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct GridLod {
+    /// Fraction of [`CameraLimits::max_zoom_scale`] at which the LOD turns on
+    pub enter_fraction: f32,
+    /// Fraction of [`CameraLimits::max_zoom_scale`] at which it turns back off;
+    /// below `enter_fraction` to keep a dead band between the two
+    pub exit_fraction: f32,
+    /// Levels with fewer hexes always show their columns
+    pub min_hexes: usize,
+    /// Whether the low-poly terrain is currently shown
+    pub active: bool,
+}
+
+impl Default for GridLod {
+    fn default() -> Self {
+        Self {
+            enter_fraction: 0.85,
+            exit_fraction: 0.7,
+            min_hexes: 64 * 64,
+            active: false,
+        }
+    }
+}
+
+impl GridLod {
+    /// Whether the LOD should be shown at orthographic `scale` for a level of
+    /// `hex_count` hexes, given whether it is shown now
+    pub fn wanted(&self, scale: f32, max_zoom_scale: f32, hex_count: usize) -> bool {
+        if hex_count < self.min_hexes {
+            return false;
+        }
+        if self.active {
+            scale > self.exit_fraction * max_zoom_scale
+        } else {
+            scale >= self.enter_fraction * max_zoom_scale
+        }
+    }
+}
+
+/// Marker for the low-poly terrain entity
+#[derive(Component)]
+pub struct LodTerrain;
+
+/// Heightfield through the hex centers at their column heights
+///
+/// Neighboring centers form a triangle lattice of two triangles per hex, so
+/// the mesh has one vertex per hex. With [`TerrainColoring::HeightGradient`]
+/// vertices carry the same colors as the columns.
+pub fn create_lod_terrain_mesh(level: &Level, coloring: TerrainColoring) -> Mesh {
+    let layout = level.hex_layout();
+    let stats = level.stats();
+    let vertex_index = |hex: Hex| (hex.x * level.height + hex.y) as u32;
+
+    let positions: Vec<[f32; 3]> = level
+        .hex_iter()
+        .map(|hex| {
+            let world_pos = layout.hex_to_world_pos(hex);
+            [world_pos.x, level.get_height(hex), world_pos.y]
+        })
+        .collect();
+    let uvs: Vec<[f32; 2]> = positions
+        .iter()
+        .map(|position| [position[0], position[2]])
+        .collect();
+
+    // Each lattice rhombus splits along its short diagonal, between the two
+    // hexes that are neighbors of each other
+    let mut indices = Vec::new();
+    for q in 0..level.width - 1 {
+        for r in 0..level.height - 1 {
+            let hex = Hex::new(q, r);
+            let (right, down) = (hex + Hex::new(1, 0), hex + Hex::new(0, 1));
+            let corner = hex + Hex::new(1, 1);
+            for triangle in [[hex, down, right], [right, down, corner]] {
+                indices.extend(triangle.map(vertex_index));
+            }
+        }
+    }
+    let vertex_count = positions.len();
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD,
+    )
+    .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+    .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+    .with_inserted_indices(mesh_indices(indices, vertex_count));
+    if coloring == TerrainColoring::HeightGradient {
+        let colors: Vec<[f32; 4]> = level
+            .hex_iter()
+            .map(|hex| {
+                terrain_color(level.get_height(hex), stats.min_height, stats.max_height)
+                    .to_linear()
+                    .to_f32_array()
+            })
+            .collect();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    }
+    mesh.compute_smooth_normals();
+    mesh
+}
+
+/// Triangles in a triangle-list mesh; other topologies count as none
+fn triangle_count(mesh: &Mesh) -> usize {
+    match (mesh.primitive_topology(), mesh.indices()) {
+        (PrimitiveTopology::TriangleList, Some(indices)) => indices.len() / 3,
+        (PrimitiveTopology::TriangleList, None) => mesh.count_vertices() / 3,
+        _ => 0,
+    }
+}
+
+/// System turning [`GridLod`] on and off as the camera zooms
+pub fn grid_lod_system(
+    mut lod: ResMut<GridLod>,
+    camera_limits: Res<CameraLimits>,
+    camera_query: Query<&Projection, With<TacticalCamera>>,
+    levels_resource: Res<LevelsResource>,
+) {
+    let Ok(Projection::Orthographic(ortho)) = camera_query.single() else {
+        return;
+    };
+
+    let level = levels_resource.current_level();
+    let hex_count = (level.width * level.height) as usize;
+    let wanted = lod.wanted(ortho.scale, camera_limits.max_zoom_scale, hex_count);
+    if wanted != lod.active {
+        lod.active = wanted;
+    }
+}
+
+/// System swapping the hex chunks for the low-poly terrain while [`GridLod`]
+/// is active
+///
+/// The terrain is rebuilt whenever the level or the terrain coloring changes.
+/// Chunks spawned while the LOD is shown start out hidden.
+pub fn lod_terrain_system(
+    mut commands: Commands,
+    lod: Res<GridLod>,
+    levels_resource: Res<LevelsResource>,
+    mut assets: GridSpawnAssets,
+    mut chunk_query: Query<(&Mesh3d, &mut Visibility), With<HexChunk>>,
+    terrain_query: Query<Entity, With<LodTerrain>>,
+) {
+    let chunk_visibility = if lod.active {
+        Visibility::Hidden
+    } else {
+        Visibility::Inherited
+    };
+    for (_, mut visibility) in &mut chunk_query {
+        visibility.set_if_neq(chunk_visibility);
+    }
+
+    let rebuild = lod.active && (levels_resource.is_changed() || assets.coloring.is_changed());
+    if !lod.is_changed() && !rebuild {
+        return;
+    }
+
+    for entity in &terrain_query {
+        commands.entity(entity).despawn();
+    }
+    if !lod.active {
+        if lod.is_changed() && !lod.is_added() {
+            info!("Grid LOD off: showing hex columns");
+        }
+        return;
+    }
+
+    let level = levels_resource.current_level();
+    let coloring = *assets.coloring;
+    let mesh = create_lod_terrain_mesh(level, coloring);
+    let detailed_triangles: usize = chunk_query
+        .iter()
+        .filter_map(|(mesh, _)| assets.meshes.get(&mesh.0))
+        .map(triangle_count)
+        .sum();
+    info!(
+        "Grid LOD on for '{level_name}': {lod_triangles} triangles instead of {detailed_triangles}",
+        level_name = level.name,
+        lod_triangles = triangle_count(&mesh)
+    );
+
+    let material = match coloring {
+        TerrainColoring::HeightGradient => assets.hex_materials.vertex_colored.clone(),
+        TerrainColoring::Flat => assets.hex_materials.surface.clone(),
+    };
+    commands.spawn((
+        Mesh3d(assets.meshes.add(mesh)),
+        MeshMaterial3d(material),
+        Transform::IDENTITY,
+        LodTerrain,
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::management::level_switching_system;
+    use crate::level::mesh::{HexEdgeStyle, HexMaterials, HexMeshOptions};
+    use crate::level::render_config::HexGridRenderConfig;
+
+    #[test]
+    fn test_lod_switch_has_hysteresis() {
+        let mut lod = GridLod::default();
+        let hexes = 100 * 100;
+        assert!(!lod.wanted(0.8, 1.0, hexes));
+        assert!(lod.wanted(0.85, 1.0, hexes));
+        assert!(!lod.wanted(1.0, 1.0, 10 * 10), "Small levels keep columns");
+
+        lod.active = true;
+        assert!(lod.wanted(0.8, 1.0, hexes), "Dead band keeps the LOD on");
+        assert!(!lod.wanted(0.7, 1.0, hexes));
+    }
+
+    #[test]
+    fn test_lod_terrain_has_one_vertex_per_hex() {
+        let mut level = Level::new("Lod".to_string(), 4, 3);
+        level.set_height(Hex::new(2, 1), 3.0).unwrap();
+        let mesh = create_lod_terrain_mesh(&level, TerrainColoring::HeightGradient);
+
+        assert_eq!(mesh.count_vertices(), 12);
+        assert_eq!(triangle_count(&mesh), 2 * 3 * 2);
+        assert!(mesh.attribute(Mesh::ATTRIBUTE_COLOR).is_some());
+        assert!(
+            create_lod_terrain_mesh(&level, TerrainColoring::Flat)
+                .attribute(Mesh::ATTRIBUTE_COLOR)
+                .is_none()
+        );
+        // Every triangle faces up
+        let positions = mesh
+            .attribute(Mesh::ATTRIBUTE_POSITION)
+            .and_then(|values| values.as_float3())
+            .unwrap();
+        let indices: Vec<usize> = mesh.indices().unwrap().iter().collect();
+        for triangle in indices.chunks(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| Vec3::from(positions[triangle[i]]));
+            assert!((b - a).cross(c - a).y > 0.0, "{triangle:?} faces down");
+        }
+    }
+
+    #[test]
+    fn test_lod_terrain_replaces_chunks_while_active() {
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        world.init_resource::<HexMaterials>();
+        world.init_resource::<TerrainColoring>();
+        world.init_resource::<HexMeshOptions>();
+        world.init_resource::<HexGridRenderConfig>();
+        world.init_resource::<HexEdgeStyle>();
+        world.init_resource::<GridLod>();
+        world.insert_resource(LevelsResource::new(vec![
+            Level::new("Wide".to_string(), 20, 20),
+            Level::new("Small".to_string(), 5, 5),
+        ]));
+        let mut schedule = Schedule::default();
+        schedule.add_systems((level_switching_system, lod_terrain_system).chain());
+        schedule.run(&mut world);
+
+        let state = |world: &mut World| {
+            let hidden = world
+                .query_filtered::<&Visibility, With<HexChunk>>()
+                .iter(world)
+                .all(|visibility| *visibility == Visibility::Hidden);
+            let terrain: Vec<AssetId<Mesh>> = world
+                .query_filtered::<&Mesh3d, With<LodTerrain>>()
+                .iter(world)
+                .map(|mesh| mesh.0.id())
+                .collect();
+            (hidden, terrain)
+        };
+        let (hidden, terrain) = state(&mut world);
+        assert!(!hidden && terrain.is_empty());
+
+        world.resource_mut::<GridLod>().active = true;
+        schedule.run(&mut world);
+        let (hidden, terrain) = state(&mut world);
+        assert!(hidden);
+        assert_eq!(terrain.len(), 1);
+
+        // Switching levels rebuilds the terrain and hides the new chunks
+        world.resource_mut::<LevelsResource>().set_current_index(1);
+        schedule.run(&mut world);
+        let (hidden, switched) = state(&mut world);
+        assert!(hidden);
+        assert_eq!(switched.len(), 1);
+        assert_ne!(switched, terrain);
+
+        world.resource_mut::<GridLod>().active = false;
+        schedule.run(&mut world);
+        let (hidden, terrain) = state(&mut world);
+        assert!(!hidden && terrain.is_empty());
+    }
+}
@@ -0,0 +1,262 @@
+//! Render Quality
+//!
+//! Shadow and anti-aliasing settings for the tactical view. Changes to
+//! [`RenderQualityConfig`] are applied to the sun and camera at runtime;
+//! [`SHADOW_TOGGLE_KEY`] and [`MSAA_CYCLE_KEY`] adjust them while playing.
+
+use bevy::pbr::DirectionalLightShadowMap;
+use bevy::prelude::*;
+use tracing::{info, warn};
+
+use crate::rendering::SunLight;
+use crate::rendering::camera::TacticalCamera;
+
+/// Key toggling sun shadows
+pub const SHADOW_TOGGLE_KEY: KeyCode = KeyCode::F5;
+
+/// Key cycling the MSAA sample count: off, 2, 4, then 8 up to [`MAX_MSAA_SAMPLES`]
+pub const MSAA_CYCLE_KEY: KeyCode = KeyCode::F6;
+
+/// Most MSAA samples per pixel applied
+#[cfg(not(target_arch = "wasm32"))]
+pub const MAX_MSAA_SAMPLES: u32 = 8;
+/// Most MSAA samples per pixel applied; WebGL2 only guarantees 4
+#[cfg(target_arch = "wasm32")]
+pub const MAX_MSAA_SAMPLES: u32 = 4;
+
+/// Smallest shadow map resolution applied
+pub const MIN_SHADOW_MAP_SIZE: usize = 512;
+/// Largest shadow map resolution applied
+pub const MAX_SHADOW_MAP_SIZE: usize = 8192;
+
+/// Shadow and MSAA settings applied to the sun and the tactical camera
+///
+/// Values are clamped to what the renderer supports when applied; see
+/// [`RenderQualityConfig::clamped`].
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderQualityConfig {
+    /// Whether the sun casts shadows
+    pub shadows: bool,
+    /// Resolution of the sun's shadow map, a power of two
+    pub shadow_map_size: usize,
+    /// MSAA samples per pixel: 1 (off), 2, 4, or 8 up to [`MAX_MSAA_SAMPLES`]
+    pub msaa_samples: u32,
+}
+
+impl Default for RenderQualityConfig {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn default() -> Self {
+        Self {
+            shadows: true,
+            shadow_map_size: 2048,
+            msaa_samples: 4,
+        }
+    }
+
+    /// Browsers get no shadows and no MSAA to keep WebGL frame times low
+    #[cfg(target_arch = "wasm32")]
+    fn default() -> Self {
+        Self {
+            shadows: false,
+            shadow_map_size: 1024,
+            msaa_samples: 1,
+        }
+    }
+}
+
+impl RenderQualityConfig {
+    /// Copy with the shadow map size rounded up to a power of two within
+    /// [`MIN_SHADOW_MAP_SIZE`]..=[`MAX_SHADOW_MAP_SIZE`] and the MSAA samples
+    /// rounded up to a supported count no higher than [`MAX_MSAA_SAMPLES`]
+    pub fn clamped(self) -> Self {
+        Self {
+            shadow_map_size: self
+                .shadow_map_size
+                .clamp(MIN_SHADOW_MAP_SIZE, MAX_SHADOW_MAP_SIZE)
+                .next_power_of_two(),
+            msaa_samples: self.msaa().samples(),
+            ..self
+        }
+    }
+
+    /// MSAA setting for the configured sample count
+    pub fn msaa(self) -> Msaa {
+        match self.msaa_samples.min(MAX_MSAA_SAMPLES) {
+            0 | 1 => Msaa::Off,
+            2 => Msaa::Sample2,
+            3 | 4 => Msaa::Sample4,
+            _ => Msaa::Sample8,
+        }
+    }
+}
+
+/// System applying [`RenderQualityConfig`] to the sun, its shadow map, and the
+/// tactical camera whenever the config changes
+pub fn apply_render_quality_system(
+    mut commands: Commands,
+    config: Res<RenderQualityConfig>,
+    mut sun_query: Query<&mut DirectionalLight, With<SunLight>>,
+    mut camera_query: Query<&mut Msaa, With<TacticalCamera>>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+
+    let applied = config.clamped();
+    if applied != *config {
+        warn!(
+            "Render quality {requested:?} is unsupported; applying {applied:?}",
+            requested = *config
+        );
+    }
+
+    for mut light in &mut sun_query {
+        light.shadows_enabled = applied.shadows;
+    }
+    commands.insert_resource(DirectionalLightShadowMap {
+        size: applied.shadow_map_size,
+    });
+    for mut msaa in &mut camera_query {
+        *msaa = applied.msaa();
+    }
+
+    info!(
+        "Render quality: shadows {shadows} ({size}px), MSAA {msaa:?}",
+        shadows = if applied.shadows { "ON" } else { "OFF" },
+        size = applied.shadow_map_size,
+        msaa = applied.msaa()
+    );
+}
+
+/// System toggling shadows on [`SHADOW_TOGGLE_KEY`] and cycling MSAA on
+/// [`MSAA_CYCLE_KEY`]
+pub fn render_quality_keys_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut config: ResMut<RenderQualityConfig>,
+) {
+    if keyboard_input.just_pressed(SHADOW_TOGGLE_KEY) {
+        config.shadows = !config.shadows;
+    }
+    if keyboard_input.just_pressed(MSAA_CYCLE_KEY) {
+        config.msaa_samples = match config.msaa() {
+            Msaa::Off => 2,
+            Msaa::Sample2 => 4,
+            Msaa::Sample4 if MAX_MSAA_SAMPLES >= 8 => 8,
+            Msaa::Sample4 | Msaa::Sample8 => 1,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_is_clamped_to_supported_values() {
+        let config = RenderQualityConfig {
+            shadows: true,
+            shadow_map_size: 3000,
+            msaa_samples: 3,
+        }
+        .clamped();
+        assert_eq!(config.shadow_map_size, 4096);
+        assert_eq!(config.msaa(), Msaa::Sample4);
+
+        let tiny = RenderQualityConfig {
+            shadow_map_size: 16,
+            msaa_samples: 0,
+            ..config
+        }
+        .clamped();
+        assert_eq!(tiny.shadow_map_size, MIN_SHADOW_MAP_SIZE);
+        assert_eq!(tiny.msaa_samples, 1);
+
+        let huge = RenderQualityConfig {
+            shadow_map_size: 100_000,
+            msaa_samples: 64,
+            ..config
+        }
+        .clamped();
+        assert_eq!(huge.shadow_map_size, MAX_SHADOW_MAP_SIZE);
+        assert_eq!(huge.msaa_samples, MAX_MSAA_SAMPLES);
+
+        let default = RenderQualityConfig::default();
+        assert_eq!(default.clamped(), default);
+    }
+
+    #[test]
+    fn test_msaa_cycle_stops_at_max_samples() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .insert_resource(RenderQualityConfig {
+                msaa_samples: 1,
+                ..default()
+            })
+            .add_systems(Update, render_quality_keys_system);
+
+        let mut cycle = Vec::new();
+        for _ in 0..4 {
+            let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+            keys.release(MSAA_CYCLE_KEY);
+            keys.clear();
+            keys.press(MSAA_CYCLE_KEY);
+            app.update();
+            cycle.push(app.world().resource::<RenderQualityConfig>().msaa_samples);
+        }
+        let expected: Vec<u32> = [2, 4, 8, 1, 2]
+            .into_iter()
+            .filter(|&samples| samples <= MAX_MSAA_SAMPLES)
+            .take(4)
+            .collect();
+        assert_eq!(cycle, expected);
+    }
+
+    #[test]
+    fn test_config_changes_reconfigure_sun_and_camera() {
+        let mut app = App::new();
+        app.init_resource::<RenderQualityConfig>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(
+                Update,
+                (render_quality_keys_system, apply_render_quality_system).chain(),
+            );
+        app.world_mut()
+            .spawn((DirectionalLight::default(), SunLight));
+        app.world_mut().spawn((Msaa::default(), TacticalCamera));
+        let applied = |app: &mut App| {
+            let world = app.world_mut();
+            let shadows = world
+                .query_filtered::<&DirectionalLight, With<SunLight>>()
+                .single(world)
+                .expect("Sun should exist")
+                .shadows_enabled;
+            let msaa = *world
+                .query_filtered::<&Msaa, With<TacticalCamera>>()
+                .single(world)
+                .expect("Camera should exist");
+            (shadows, msaa)
+        };
+
+        app.update();
+        let default = RenderQualityConfig::default();
+        assert_eq!(applied(&mut app), (default.shadows, default.msaa()));
+
+        *app.world_mut().resource_mut::<RenderQualityConfig>() = RenderQualityConfig {
+            shadows: true,
+            shadow_map_size: 1000,
+            msaa_samples: 2,
+        };
+        app.update();
+        assert_eq!(applied(&mut app), (true, Msaa::Sample2));
+        assert_eq!(
+            app.world().resource::<DirectionalLightShadowMap>().size,
+            1024
+        );
+
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(SHADOW_TOGGLE_KEY);
+        keyboard.press(MSAA_CYCLE_KEY);
+        app.update();
+        assert_eq!(applied(&mut app), (false, Msaa::Sample4));
+    }
+}
@@ -0,0 +1,280 @@
+//! Range Overlays
+//!
+//! Translucent hex tiles marking movement or attack range, hovering just above
+//! the top faces of the hexes in [`RangeOverlay`]. Until gameplay fills the
+//! overlay, [`RANGE_OVERLAY_DEBUG_KEY`] cycles a radius-3 range around the
+//! selected hex.
+
+use bevy::prelude::*;
+use hexx::Hex;
+use tracing::info;
+
+use crate::colors::{ATTACK_RANGE, MOVEMENT_RANGE};
+use crate::level::LevelsResource;
+use crate::rendering::highlight::{SelectedHex, create_hex_face_mesh, hex_top_height};
+
+/// Key cycling the debug range around the selected hex: movement, attack, off
+pub const RANGE_OVERLAY_DEBUG_KEY: KeyCode = KeyCode::F4;
+
+/// Radius of the debug range, in hexes
+const DEBUG_RANGE_RADIUS: u32 = 3;
+
+/// Height of range tiles above the top faces, below the hover and selection
+/// highlights
+const RANGE_TILE_LIFT: f32 = 0.005;
+
+/// What a [`RangeOverlay`] shows, choosing its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RangeOverlayKind {
+    #[default]
+    Movement,
+    Attack,
+}
+
+impl RangeOverlayKind {
+    pub fn color(self) -> Color {
+        match self {
+            Self::Movement => MOVEMENT_RANGE,
+            Self::Attack => ATTACK_RANGE,
+        }
+    }
+}
+
+/// Hexes to mark with range tiles; empty hides the overlay
+///
+/// Cleared when the current level switches, since its hexes may not exist in
+/// the new level.
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub struct RangeOverlay {
+    pub hexes: Vec<Hex>,
+    pub kind: RangeOverlayKind,
+}
+
+/// Marker for the tiles spawned from [`RangeOverlay`]
+#[derive(Component)]
+pub struct RangeOverlayTile;
+
+/// Translucent tile materials, one per [`RangeOverlayKind`]
+#[derive(Resource, Debug, Clone)]
+pub struct RangeOverlayMaterials {
+    pub movement: Handle<StandardMaterial>,
+    pub attack: Handle<StandardMaterial>,
+}
+
+impl RangeOverlayMaterials {
+    pub fn get(&self, kind: RangeOverlayKind) -> &Handle<StandardMaterial> {
+        match kind {
+            RangeOverlayKind::Movement => &self.movement,
+            RangeOverlayKind::Attack => &self.attack,
+        }
+    }
+}
+
+impl FromWorld for RangeOverlayMaterials {
+    fn from_world(world: &mut World) -> Self {
+        let mut materials = world.resource_mut::<Assets<StandardMaterial>>();
+        let mut tile_material = |kind: RangeOverlayKind| {
+            materials.add(StandardMaterial {
+                base_color: kind.color().with_alpha(0.4),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            })
+        };
+
+        Self {
+            movement: tile_material(RangeOverlayKind::Movement),
+            attack: tile_material(RangeOverlayKind::Attack),
+        }
+    }
+}
+
+/// System respawning the range tiles whenever [`RangeOverlay`] or the level
+/// changes, clearing the overlay on level switches
+pub fn range_overlay_system(
+    mut commands: Commands,
+    mut overlay: ResMut<RangeOverlay>,
+    levels_resource: Res<LevelsResource>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    materials: Res<RangeOverlayMaterials>,
+    tile_query: Query<Entity, With<RangeOverlayTile>>,
+    mut last_level_index: Local<Option<usize>>,
+) {
+    let level_index = levels_resource.current_level_index;
+    if last_level_index
+        .replace(level_index)
+        .is_some_and(|last| last != level_index)
+        && !overlay.hexes.is_empty()
+    {
+        overlay.hexes.clear();
+    }
+    if !overlay.is_changed() && !levels_resource.is_changed() {
+        return;
+    }
+
+    for entity in &tile_query {
+        commands.entity(entity).despawn();
+    }
+    if overlay.hexes.is_empty() {
+        return;
+    }
+
+    let level = levels_resource.current_level();
+    let hex_layout = level.hex_layout();
+    let tile_mesh = meshes.add(create_hex_face_mesh(&hex_layout));
+    let material = materials.get(overlay.kind);
+    for &hex in &overlay.hexes {
+        let Some(top) = hex_top_height(level, hex) else {
+            continue;
+        };
+        let world_pos = hex_layout.hex_to_world_pos(hex);
+        commands.spawn((
+            Mesh3d(tile_mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            Transform::from_xyz(world_pos.x, top + RANGE_TILE_LIFT, world_pos.y),
+            RangeOverlayTile,
+        ));
+    }
+}
+
+/// System cycling a debug range around the selected hex on
+/// [`RANGE_OVERLAY_DEBUG_KEY`]
+pub fn range_overlay_debug_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    selected: Res<SelectedHex>,
+    mut overlay: ResMut<RangeOverlay>,
+) {
+    if !keyboard_input.just_pressed(RANGE_OVERLAY_DEBUG_KEY) {
+        return;
+    }
+
+    let next_kind = match (overlay.hexes.is_empty(), overlay.kind) {
+        (true, _) => Some(RangeOverlayKind::Movement),
+        (false, RangeOverlayKind::Movement) => Some(RangeOverlayKind::Attack),
+        (false, RangeOverlayKind::Attack) => None,
+    };
+    match (selected.0, next_kind) {
+        (Some(center), Some(kind)) => {
+            *overlay = RangeOverlay {
+                hexes: center.range(DEBUG_RANGE_RADIUS).collect(),
+                kind,
+            };
+            info!("Debug {kind:?} range around {center:?}");
+        }
+        (None, Some(_)) => info!("Select a hex to show a debug range around it"),
+        (_, None) => overlay.hexes.clear(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::level::Level;
+
+    fn overlay_app() -> App {
+        let mut raised = Level::new("Raised".to_string(), 5, 5);
+        for (hex, height) in [
+            (Hex::new(0, 0), 1.5),
+            (Hex::new(1, 1), 1.5),
+            (Hex::new(2, 2), 3.0),
+        ] {
+            raised.set_height(hex, height).unwrap();
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<RangeOverlayMaterials>()
+            .init_resource::<RangeOverlay>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<SelectedHex>()
+            .insert_resource(LevelsResource::new(vec![
+                raised,
+                Level::new("Other".to_string(), 5, 5),
+            ]))
+            .add_systems(
+                Update,
+                (range_overlay_debug_system, range_overlay_system).chain(),
+            );
+        app
+    }
+
+    fn tile_heights(app: &mut App) -> Vec<f32> {
+        let mut query = app
+            .world_mut()
+            .query_filtered::<&Transform, With<RangeOverlayTile>>();
+        let mut heights: Vec<f32> = query
+            .iter(app.world())
+            .map(|transform| transform.translation.y)
+            .collect();
+        heights.sort_by(f32::total_cmp);
+        heights
+    }
+
+    #[test]
+    fn test_tiles_follow_overlay_and_clear_on_level_switch() {
+        let mut app = overlay_app();
+        app.update();
+        assert!(tile_heights(&mut app).is_empty());
+
+        // Hexes outside the level get no tile
+        app.world_mut().resource_mut::<RangeOverlay>().hexes =
+            vec![Hex::new(1, 1), Hex::new(2, 2), Hex::new(9, 9)];
+        app.update();
+        let low = 1.5 + RANGE_TILE_LIFT;
+        assert_eq!(tile_heights(&mut app), vec![low, 3.0 + RANGE_TILE_LIFT]);
+
+        app.world_mut().resource_mut::<RangeOverlay>().hexes = vec![Hex::new(0, 0)];
+        app.update();
+        assert_eq!(tile_heights(&mut app), vec![low]);
+
+        app.world_mut()
+            .resource_mut::<LevelsResource>()
+            .set_current_index(1);
+        app.update();
+        assert!(tile_heights(&mut app).is_empty());
+        assert!(app.world().resource::<RangeOverlay>().hexes.is_empty());
+    }
+
+    #[test]
+    fn test_debug_key_cycles_range_around_selection() {
+        let mut app = overlay_app();
+        app.world_mut().resource_mut::<SelectedHex>().0 = Some(Hex::new(2, 2));
+        let press = |app: &mut App| {
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .press(RANGE_OVERLAY_DEBUG_KEY);
+            app.update();
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .reset_all();
+        };
+
+        press(&mut app);
+        let overlay = app.world().resource::<RangeOverlay>().clone();
+        assert_eq!(overlay.kind, RangeOverlayKind::Movement);
+        assert_eq!(overlay.hexes.len(), 37, "Radius 3 covers 37 hexes");
+        // Only the hexes inside the 5x5 level get tiles
+        let inside = overlay
+            .hexes
+            .iter()
+            .filter(|&&hex| {
+                app.world()
+                    .resource::<LevelsResource>()
+                    .current_level()
+                    .contains(hex)
+            })
+            .count();
+        assert_eq!(tile_heights(&mut app).len(), inside);
+
+        press(&mut app);
+        assert_eq!(
+            app.world().resource::<RangeOverlay>().kind,
+            RangeOverlayKind::Attack
+        );
+
+        press(&mut app);
+        assert!(app.world().resource::<RangeOverlay>().hexes.is_empty());
+        assert!(tile_heights(&mut app).is_empty());
+    }
+}
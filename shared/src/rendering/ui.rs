@@ -1,13 +1,14 @@
 //! User Interface Systems
 //!
-//! UI components and systems for level name display, FPS counter,
-//! and other tactical RPG interface elements.
+//! UI components and systems for level name display, FPS counter, level
+//! warnings, and other tactical RPG interface elements.
 
 use bevy::prelude::*;
 use tracing::info;
 
-use crate::colors::YELLOW_ACCENT;
+use crate::colors::{HEX_IMPASSABLE_RED, YELLOW_ACCENT};
 use crate::level::LevelsResource;
+use crate::level::drop::LevelFileRejected;
 
 /// Component to mark the level name display text
 #[derive(Component)]
@@ -17,6 +18,13 @@ pub struct LevelNameDisplay;
 #[derive(Component)]
 pub struct FpsDisplay;
 
+/// Component to mark the level warning text
+#[derive(Component)]
+pub struct LevelWarningDisplay;
+
+/// Seconds a level warning stays on screen
+pub const LEVEL_WARNING_SECONDS: f32 = 5.0;
+
 /// System to spawn the level name UI text in the bottom-right corner
 pub fn spawn_level_name_ui(mut commands: Commands, levels_resource: Res<LevelsResource>) {
     let level = levels_resource.current_level();
@@ -97,10 +105,58 @@ pub fn update_fps_display(
     for mut text in text_query.iter_mut() {
         if let Some(fps_diagnostic) =
             diagnostics.get(&bevy::diagnostic::FrameTimeDiagnosticsPlugin::FPS)
+            && let Some(fps_smoothed) = fps_diagnostic.smoothed()
         {
-            if let Some(fps_smoothed) = fps_diagnostic.smoothed() {
-                **text = format!("FPS: {fps_smoothed:.1}");
-            }
+            **text = format!("FPS: {fps_smoothed:.1}");
+        }
+    }
+}
+
+/// System to spawn the (initially empty) level warning text in the top-right corner
+pub fn spawn_level_warning_ui(mut commands: Commands) {
+    let entity = commands
+        .spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(HEX_IMPASSABLE_RED),
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                right: Val::Px(20.0),
+                ..default()
+            },
+            LevelWarningDisplay,
+        ))
+        .id();
+
+    info!("Level warning UI entity spawned: {entity:?} at top-right corner");
+}
+
+/// System to show rejected level files for [`LEVEL_WARNING_SECONDS`]
+pub fn update_level_warning_display(
+    time: Res<Time>,
+    mut rejected: EventReader<LevelFileRejected>,
+    mut hide_timer: Local<Option<Timer>>,
+    mut text_query: Query<&mut Text, With<LevelWarningDisplay>>,
+) {
+    if let Some(LevelFileRejected(failure)) = rejected.read().last() {
+        for mut text in text_query.iter_mut() {
+            **text = format!("Could not load {failure}");
+        }
+        *hide_timer = Some(Timer::from_seconds(LEVEL_WARNING_SECONDS, TimerMode::Once));
+        return;
+    }
+
+    let Some(timer) = hide_timer.as_mut() else {
+        return;
+    };
+    if timer.tick(time.delta()).just_finished() {
+        for mut text in text_query.iter_mut() {
+            text.clear();
         }
+        *hide_timer = None;
     }
 }
@@ -0,0 +1,75 @@
+//! SystemTactics Level CLI
+//!
+//! Validates and inspects level files outside the game, so CI can catch broken
+//! levels before they ship.
+//!
+//! ```text
+//! level-cli validate [DIR]   Check every level file in DIR (default: assets/levels)
+//! level-cli stats FILE       Print the statistics summary of one level file
+//! ```
+
+use std::path::Path;
+use std::process::ExitCode;
+
+use anyhow::Result;
+use shared::level::LEVELS_DIRECTORY;
+use shared::level::check::{check_level_directory, load_level_file};
+
+const USAGE: &str = "Usage:
+  level-cli validate [DIR]   Check every level file in DIR (default: assets/levels)
+  level-cli stats FILE       Print the statistics summary of one level file";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["validate"] => validate(Path::new(LEVELS_DIRECTORY)),
+        ["validate", levels_dir] => validate(Path::new(levels_dir)),
+        ["stats", file] => stats(Path::new(file)),
+        ["help" | "--help" | "-h"] => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        _ => {
+            eprintln!("{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Print a pass/fail line per level file; returns whether every file passed
+fn validate(levels_dir: &Path) -> Result<bool> {
+    let checks = check_level_directory(levels_dir)?;
+    for check in &checks {
+        println!("{check}");
+    }
+
+    let failed = checks.iter().filter(|check| !check.is_passed()).count();
+    println!(
+        "{passed} passed, {failed} failed in {path}",
+        passed = checks.len() - failed,
+        path = levels_dir.display()
+    );
+    Ok(failed == 0)
+}
+
+/// Print the statistics summary of one level file
+fn stats(file: &Path) -> Result<bool> {
+    let level = load_level_file(file)?;
+    println!(
+        "'{name}' {width}x{height}: {stats}",
+        name = level.name,
+        width = level.width,
+        height = level.height,
+        stats = level.stats()
+    );
+    Ok(true)
+}